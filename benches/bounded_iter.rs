@@ -0,0 +1,46 @@
+//! compares `random_iter_bound()` and `random_iter_bound32()`, which work out
+//! their rejection threshold once, against calling `random_u64_bound()` and
+//! `random_u32_bound()` for every value.
+//! 
+//! run with `cargo bench --bench bounded_iter`.
+
+use prrng::{Random, XorShift64};
+use std::hint::black_box;
+use std::time::Instant;
+
+const N: usize = 10_000_000;
+
+fn time(name: &str, f: impl FnOnce() -> u64) {
+	let start = Instant::now();
+	black_box(f());
+	let elapsed = start.elapsed();
+	println!("{name:<32} {:>8.2} ns/value", elapsed.as_nanos() as f64 / N as f64);
+}
+
+fn main() {
+	// none of these are powers of two, which would need no threshold at all
+	for bound in [6, 1_000_003, (1 << 31) + 1] {
+		println!("bound {bound}:");
+
+		time("  random_u64_bound", || {
+			let mut rng = XorShift64::new(1);
+			let bound = black_box(bound);
+			(0..N).fold(0, |a, _| a ^ rng.random_u64_bound(bound))
+		});
+		time("  random_iter_bound", || {
+			let mut rng = XorShift64::new(1);
+			rng.random_iter_bound(black_box(bound)).take(N).fold(0, |a, x| a ^ x)
+		});
+
+		let bound = bound as u32;
+		time("  random_u32_bound", || {
+			let mut rng = XorShift64::new(1);
+			let bound = black_box(bound);
+			(0..N).fold(0, |a, _| a ^ rng.random_u32_bound(bound) as u64)
+		});
+		time("  random_iter_bound32", || {
+			let mut rng = XorShift64::new(1);
+			rng.random_iter_bound32(black_box(bound)).take(N).fold(0, |a, x| a ^ x as u64)
+		});
+	}
+}
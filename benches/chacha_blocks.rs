@@ -0,0 +1,59 @@
+//! compares `ChaCha::run_blocks()` and `ChaCha::fill_keystream()` against
+//! `run()` followed by `get()` for every word.
+//! 
+//! run with `cargo bench --bench chacha_blocks`.
+
+use prrng::ChaCha;
+use std::hint::black_box;
+use std::time::Instant;
+
+// 16 MiB, in 64 KiB pieces
+const LEN: usize = 1 << 16;
+const ROUNDS: usize = 256;
+
+fn time(name: &str, f: impl FnOnce() -> u64) {
+	let start = Instant::now();
+	black_box(f());
+	let elapsed = start.elapsed();
+	let bytes = (LEN * ROUNDS) as f64;
+	println!("{name:<32} {:>8.3} ns/byte", elapsed.as_nanos() as f64 / bytes);
+}
+
+fn main() {
+	let mut buf = vec![0u8; LEN];
+
+	time("get() per word", || {
+		let mut rng = ChaCha::new([1; 8], [2; 3], 0);
+		let mut sum = 0;
+		for _ in 0..ROUNDS {
+			for word in black_box(&mut buf).as_chunks_mut::<4>().0 {
+				*word = rng.get().to_le_bytes();
+			}
+			sum += buf[LEN - 1] as u64;
+		}
+		sum
+	});
+
+	time("fill_keystream()", || {
+		let mut rng = ChaCha::new([1; 8], [2; 3], 0);
+		let mut sum = 0;
+		for _ in 0..ROUNDS {
+			rng.fill_keystream(black_box(&mut buf));
+			sum += buf[LEN - 1] as u64;
+		}
+		sum
+	});
+
+	time("run_blocks::<8>()", || {
+		let mut rng = ChaCha::new([1; 8], [2; 3], 0);
+		let mut sum = 0;
+		for _ in 0..ROUNDS {
+			let (blocks, _) = black_box(&mut buf).as_chunks_mut::<64>();
+			for group in blocks.as_chunks_mut::<8>().0 {
+				rng.run_blocks(group);
+			}
+			sum += buf[LEN - 1] as u64;
+		}
+		sum
+	});
+}
@@ -0,0 +1,63 @@
+//! compares `random_fill_f64()` and `random_fill_f32()` against
+//! `random_fill::<f64>()` and `random_fill::<f32>()`.
+//! 
+//! run with `cargo bench --bench fill_float`.
+
+use prrng::{Random, XorShift64};
+use std::hint::black_box;
+use std::time::Instant;
+
+// 64M values, in 64K pieces
+const LEN: usize = 1 << 16;
+const ROUNDS: usize = 1024;
+
+fn time(name: &str, f: impl FnOnce() -> f64) {
+	let start = Instant::now();
+	black_box(f());
+	let elapsed = start.elapsed();
+	println!("{name:<32} {:>8.3} ns/value", elapsed.as_nanos() as f64 / (LEN * ROUNDS) as f64);
+}
+
+fn main() {
+	let mut buf = vec![0.0f64; LEN];
+
+	time("random_fill::<f64>", || {
+		let mut rng = XorShift64::new(1);
+		let mut sum = 0.0;
+		for _ in 0..ROUNDS {
+			rng.random_fill(black_box(&mut buf[..]));
+			sum += buf[LEN - 1];
+		}
+		sum
+	});
+	time("random_fill_f64", || {
+		let mut rng = XorShift64::new(1);
+		let mut sum = 0.0;
+		for _ in 0..ROUNDS {
+			rng.random_fill_f64(black_box(&mut buf));
+			sum += buf[LEN - 1];
+		}
+		sum
+	});
+
+	let mut buf = vec![0.0f32; LEN];
+
+	time("random_fill::<f32>", || {
+		let mut rng = XorShift64::new(1);
+		let mut sum = 0.0;
+		for _ in 0..ROUNDS {
+			rng.random_fill(black_box(&mut buf[..]));
+			sum += buf[LEN - 1] as f64;
+		}
+		sum
+	});
+	time("random_fill_f32", || {
+		let mut rng = XorShift64::new(1);
+		let mut sum = 0.0;
+		for _ in 0..ROUNDS {
+			rng.random_fill_f32(black_box(&mut buf));
+			sum += buf[LEN - 1] as f64;
+		}
+		sum
+	});
+}
@@ -0,0 +1,58 @@
+//! compares `MTwister::fill()` and `MTwister::fill_block()` against calling
+//! `get()` for every value, over 1M words.
+//! 
+//! run with `cargo bench --bench mtwister_fill`.
+
+use prrng::MTwister;
+use std::hint::black_box;
+use std::time::Instant;
+
+// a multiple of the 624 word block, a bit over 1M words
+const BLOCKS: usize = 1700;
+const N: usize = BLOCKS * 624;
+const ROUNDS: usize = 20;
+
+fn time(name: &str, f: impl FnOnce() -> u64) {
+	let start = Instant::now();
+	black_box(f());
+	let elapsed = start.elapsed();
+	println!("{name:<32} {:>8.3} ns/value", elapsed.as_nanos() as f64 / (N * ROUNDS) as f64);
+}
+
+fn main() {
+	let mut buf = vec![0u32; N];
+
+	time("get()", || {
+		let mut rng = MTwister::new(5489);
+		let mut sum = 0;
+		for _ in 0..ROUNDS {
+			for x in black_box(&mut buf).iter_mut() {
+				*x = rng.get();
+			}
+			sum += buf[N - 1] as u64;
+		}
+		sum
+	});
+
+	time("fill()", || {
+		let mut rng = MTwister::new(5489);
+		let mut sum = 0;
+		for _ in 0..ROUNDS {
+			rng.fill(black_box(&mut buf));
+			sum += buf[N - 1] as u64;
+		}
+		sum
+	});
+
+	time("fill_block()", || {
+		let mut rng = MTwister::new(5489);
+		let mut sum = 0;
+		for _ in 0..ROUNDS {
+			for block in black_box(&mut buf).as_chunks_mut::<624>().0 {
+				rng.fill_block(block);
+			}
+			sum += buf[N - 1] as u64;
+		}
+		sum
+	});
+}
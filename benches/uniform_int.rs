@@ -0,0 +1,49 @@
+//! compares `UniformInt32` and `UniformInt64` against the per-call
+//! `random_u32_bound()` and `random_u64_bound()`.
+//! 
+//! run with `cargo bench --bench uniform_int`.
+
+use prrng::{Random, UniformInt32, UniformInt64, XorShift64};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+const N: u32 = 10_000_000;
+
+fn time(name: &str, f: impl FnOnce() -> u64) -> Duration {
+	let start = Instant::now();
+	black_box(f());
+	let elapsed = start.elapsed();
+	println!("{name:<32} {:>8.2} ns/value", elapsed.as_nanos() as f64 / N as f64);
+	elapsed
+}
+
+fn main() {
+	// a deck of cards, a bound where rejection is rare, and one where it
+	// happens for almost half of all draws
+	for bound in [52, 1_000_003, (1 << 31) + 1] {
+		println!("bound {bound}:");
+
+		time("  random_u32_bound", || {
+			let mut rng = XorShift64::new(1);
+			let bound = black_box(bound);
+			(0..N).map(|_| rng.random_u32_bound(bound) as u64).sum()
+		});
+		time("  UniformInt32", || {
+			let mut rng = XorShift64::new(1);
+			let u = UniformInt32::new(0, black_box(bound));
+			(0..N).map(|_| u.sample(&mut rng) as u64).sum()
+		});
+
+		let bound = bound as u64;
+		time("  random_u64_bound", || {
+			let mut rng = XorShift64::new(1);
+			let bound = black_box(bound);
+			(0..N).fold(0, |a, _| a ^ rng.random_u64_bound(bound))
+		});
+		time("  UniformInt64", || {
+			let mut rng = XorShift64::new(1);
+			let u = UniformInt64::new(0, black_box(bound));
+			(0..N).fold(0, |a, _| a ^ u.sample(&mut rng))
+		});
+	}
+}
@@ -0,0 +1,156 @@
+//! derive macros for [prrng](https://docs.rs/prrng). use these through
+//! prrng's `derive` feature, rather than depending on this crate directly.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// derive `prrng::FromRandom`.
+/// 
+/// structs fill every field with its own `FromRandom`, in order. enums
+/// first pick a variant, then fill its fields the same way. variants are
+/// picked uniformly, unless given a `#[from_random(weight = n)]`, which
+/// makes a variant `n` times as likely as one with the default weight of
+/// `1`. the weights are summed when expanding, and the variant is picked
+/// with a single unbiased draw below that sum.
+/// 
+/// every type parameter is required to implement `FromRandom`.
+/// 
+/// ```ignore
+/// #[derive(FromRandom)]
+/// enum Loot {
+///     #[from_random(weight = 90)]
+///     Gold(u8),
+///     #[from_random(weight = 9)]
+///     Weapon { damage: u16, cursed: bool },
+///     Relic,
+/// }
+/// ```
+#[proc_macro_derive(FromRandom, attributes(from_random))]
+pub fn derive_from_random(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	let input = syn::parse_macro_input!(input as syn::DeriveInput);
+	match from_random(input) {
+		Ok(x) => x.into(),
+		Err(e) => e.to_compile_error().into(),
+	}
+}
+
+fn from_random(mut input: syn::DeriveInput) -> syn::Result<TokenStream> {
+	if let Some(attr) = input.attrs.iter().find(|x| x.path().is_ident("from_random")) {
+		return Err(syn::Error::new_spanned(attr, "`from_random` attributes are only allowed on enum variants"));
+	}
+
+	let body = match &input.data {
+		syn::Data::Struct(data) => {
+			reject_field_attrs(&data.fields)?;
+			let fields = construct(&data.fields);
+			quote! { Self #fields }
+		}
+		syn::Data::Enum(data) => from_random_enum(&input.ident, data)?,
+		syn::Data::Union(data) => {
+			return Err(syn::Error::new_spanned(data.union_token, "`FromRandom` cannot be derived for unions"));
+		}
+	};
+
+	for param in input.generics.type_params_mut() {
+		param.bounds.push(syn::parse_quote!(::prrng::FromRandom));
+	}
+
+	let name = &input.ident;
+	let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+
+	Ok(quote! {
+		impl #impl_generics ::prrng::FromRandom for #name #type_generics #where_clause {
+			// unit structs and variants don't use `random`
+			#[allow(unused_variables)]
+			fn from_random(random: &mut impl ::prrng::Random) -> Self {
+				#body
+			}
+		}
+	})
+}
+
+fn from_random_enum(name: &syn::Ident, data: &syn::DataEnum) -> syn::Result<TokenStream> {
+	if data.variants.is_empty() {
+		return Err(syn::Error::new_spanned(
+			name,
+			"`FromRandom` cannot be derived for an enum with no variants, since there is no value to return",
+		));
+	}
+
+	let mut total: u64 = 0;
+	let mut arms = Vec::new();
+	for variant in &data.variants {
+		reject_field_attrs(&variant.fields)?;
+		let weight = weight(variant)?;
+		total = total.checked_add(weight).ok_or_else(|| {
+			syn::Error::new_spanned(&variant.ident, "the sum of every `weight` must fit in a `u64`")
+		})?;
+
+		let ident = &variant.ident;
+		let fields = construct(&variant.fields);
+		arms.push((total, quote! { Self::#ident #fields }));
+	}
+
+	// a single variant needs no draw at all
+	if let [(_, only)] = arms.as_slice() {
+		return Ok(only.clone());
+	}
+
+	// `x < end` for every variant but the last, which takes the rest
+	let (_, last) = arms.pop().unwrap();
+	let ends = arms.iter().map(|(end, _)| end);
+	let values = arms.iter().map(|(_, value)| value);
+	Ok(quote! {
+		let x = ::prrng::Random::random_u64_bound(random, #total);
+		#(if x < #ends { #values } else)* { #last }
+	})
+}
+
+// the weight of `variant`, from `#[from_random(weight = n)]`, or `1`
+fn weight(variant: &syn::Variant) -> syn::Result<u64> {
+	let mut weight = None;
+	for attr in variant.attrs.iter().filter(|x| x.path().is_ident("from_random")) {
+		attr.parse_nested_meta(|meta| {
+			if !meta.path.is_ident("weight") {
+				return Err(meta.error("unknown `from_random` attribute, expected `weight`"));
+			}
+			if weight.is_some() {
+				return Err(meta.error("`weight` is given more than once"));
+			}
+			let lit: syn::LitInt = meta.value()?.parse()?;
+			let n = lit.base10_parse::<u64>()?;
+			if n == 0 {
+				return Err(syn::Error::new_spanned(lit, "`weight` must be more than 0"));
+			}
+			weight = Some(n);
+			Ok(())
+		})?;
+	}
+	Ok(weight.unwrap_or(1))
+}
+
+fn reject_field_attrs(fields: &syn::Fields) -> syn::Result<()> {
+	for field in fields {
+		if let Some(attr) = field.attrs.iter().find(|x| x.path().is_ident("from_random")) {
+			return Err(syn::Error::new_spanned(attr, "`from_random` attributes are only allowed on enum variants"));
+		}
+	}
+	Ok(())
+}
+
+// the fields of a struct or variant, each filled with its own `FromRandom`
+fn construct(fields: &syn::Fields) -> TokenStream {
+	let values = fields.iter().map(|field| {
+		let ty = &field.ty;
+		let value = quote! { <#ty as ::prrng::FromRandom>::from_random(random) };
+		match &field.ident {
+			Some(ident) => quote! { #ident: #value },
+			None => value,
+		}
+	});
+	match fields {
+		syn::Fields::Named(_) => quote! { { #(#values),* } },
+		syn::Fields::Unnamed(_) => quote! { ( #(#values),* ) },
+		syn::Fields::Unit => quote! {},
+	}
+}
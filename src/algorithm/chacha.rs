@@ -13,6 +13,13 @@
 /// | count  | nonce  | nonce  | nonce  |
 /// ```
 /// 
+/// this is the layout from rfc 8439, [`ChaChaLayout::Ietf`]. the original
+/// layout, [`ChaChaLayout::Djb`], instead has a 64 bit count over the first
+/// two of those words, and a 64 bit nonce over the last two. the 32 bit count
+/// runs out after 256 GiB of keystream, the 64 bit one practically never.
+/// use [`Self::new_djb()`] for it, which matches libsodium's
+/// `crypto_stream_chacha20`.
+/// 
 /// the top row, ascii bytes "expand 32-byte k", is
 /// [for transparency](https://en.wikipedia.org/wiki/Nothing-up-my-sleeve_number).
 /// for encryption, `key` is intended to be a constant shared secret between
@@ -21,7 +28,7 @@
 /// the intention here is, for every 64 bytes, a new `ChaCha` instance is instantiated
 /// with an incremented `count` value. one should then call [`Self::run()`] to
 /// complete `N` rounds of the algorithm, then use the values of [`Self::inner()`]
-/// for encryption.
+/// for encryption. [`Self::keystream_block()`] does both at once.
 /// 
 /// ```
 /// # use prrng::SplitMix64;
@@ -31,14 +38,11 @@
 /// # use std::prelude::rust_2024::*; 
 /// fn encrypt_block(key: [u32; 8], nonce: [u32; 3], block: u32, bytes: &mut [u8]) {
 ///     // new ChaCha12 instance for every block
-///     let mut rng = ChaCha::new(key, nonce, block);
-/// 
-///     // run 12 rounds
-///     rng.run();
+///     let rng = ChaCha::new(key, nonce, block);
 /// 
-///     // xor every byte
+///     // xor every byte with the result of 12 rounds
 ///     // (this example assumes `bytes` is at most 64 bytes)
-///     for (i, o) in bytes.iter_mut().zip(rng.inner_bytes().iter()) {
+///     for (i, o) in bytes.iter_mut().zip(rng.keystream_block().iter()) {
 ///         *i ^= *o;
 ///     }
 /// }
@@ -69,10 +73,25 @@
 /// ```
 /// 
 /// of course, this generator can also just be used as a rather good prng.
-#[derive(Clone)]
+#[derive(Clone, PartialEq, Eq)]
 pub struct ChaCha<const N: u8 = 12> {
 	seed: [u32; 16],
 	serialized: u8,
+	keystream: u8,
+	layout: ChaChaLayout,
+}
+
+/// which words of a [`ChaCha`] state hold the block count, and which hold
+/// the nonce.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ChaChaLayout {
+	/// a 32 bit count in word 12, and a 96 bit nonce in words 13 to 15, from
+	/// [rfc 8439](https://www.rfc-editor.org/rfc/rfc8439).
+	Ietf,
+	/// a 64 bit count in words 12 and 13, and a 64 bit nonce in words 14
+	/// and 15, both least significant word first. this is the original
+	/// layout, from [djb's paper](https://cr.yp.to/chacha/chacha-20080128.pdf).
+	Djb,
 }
 
 impl ChaCha {
@@ -81,9 +100,33 @@ impl ChaCha {
 	/// 
 	/// see [`ChaCha`]'s documentation for how initialization should work.
 	#[inline]
-	pub fn new(key: [u32; 8], nonce: [u32; 3], block: u32) -> Self {
+	pub const fn new(key: [u32; 8], nonce: [u32; 3], block: u32) -> Self {
 		Self::new_n(key, nonce, block)
 	}
+
+	/// construct a new `ChaCha12`, with the [`ChaChaLayout::Djb`] layout.
+	/// see [`Self::new_djb_n()`] for a generic constructor method.
+	/// 
+	/// see [`ChaCha`]'s documentation for how initialization should work.
+	#[inline]
+	pub const fn new_djb(key: [u32; 8], nonce: u64, block: u64) -> Self {
+		Self::new_djb_n(key, nonce, block)
+	}
+
+	/// construct a new `ChaCha12` from a key and nonce as bytes.
+	/// see [`Self::from_key_bytes_n()`] for a generic constructor method.
+	#[inline]
+	pub const fn from_key_bytes(key: &[u8; 32], nonce: &[u8; 12], block: u32) -> Self {
+		Self::from_key_bytes_n(key, nonce, block)
+	}
+
+	/// construct a new `ChaCha12` from a key and nonce as bytes, with the
+	/// [`ChaChaLayout::Djb`] layout.
+	/// see [`Self::from_key_bytes_djb_n()`] for a generic constructor method.
+	#[inline]
+	pub const fn from_key_bytes_djb(key: &[u8; 32], nonce: &[u8; 8], block: u64) -> Self {
+		Self::from_key_bytes_djb_n(key, nonce, block)
+	}
 }
 
 impl<const N: u8> ChaCha<N> {
@@ -91,11 +134,25 @@ impl<const N: u8> ChaCha<N> {
 	/// unlike [`Self::new_n()`], this method does not have organized arguments.
 	/// 
 	/// see [`ChaCha`]'s documentation for how initialization should work.
+	/// 
+	/// the block count is incremented as [`ChaChaLayout::Ietf`] describes.
+	/// see [`Self::new_raw_layout()`] to pick another.
 	#[inline]
 	pub const fn new_raw(seed: [u32; 16]) -> Self {
+		Self::new_raw_layout(seed, ChaChaLayout::Ietf)
+	}
+
+	/// construct a new `ChaCha` instance, whose block count is incremented
+	/// as `layout` describes.
+	/// 
+	/// see [`ChaCha`]'s documentation for how initialization should work.
+	#[inline]
+	pub const fn new_raw_layout(seed: [u32; 16], layout: ChaChaLayout) -> Self {
 		Self {
 			seed,
 			serialized: 16,
+			keystream: 0,
+			layout,
 		}
 	}
 
@@ -124,31 +181,235 @@ impl<const N: u8> ChaCha<N> {
 		])
 	}
 
+	/// construct a new `ChaCha`, with the [`ChaChaLayout::Djb`] layout.
+	/// 
+	/// `nonce` is the 8 nonce bytes read as a little endian `u64`, and
+	/// `block` is the initial block count, which libsodium calls `ic`.
+	/// 
+	/// see [`ChaCha`]'s documentation for how initialization should work.
+	#[inline]
+	pub const fn new_djb_n(key: [u32; 8], nonce: u64, block: u64) -> Self {
+		Self::new_raw_layout([
+			0x61707865,
+			0x3320646e,
+			0x79622d32,
+			0x6b206574,
+			key[0],
+			key[1],
+			key[2],
+			key[3],
+			key[4],
+			key[5],
+			key[6],
+			key[7],
+			block as u32,
+			(block >> 32) as u32,
+			nonce as u32,
+			(nonce >> 32) as u32,
+		], ChaChaLayout::Djb)
+	}
+
+	/// construct a new `ChaCha` from a key and nonce as bytes.
+	/// 
+	/// every 4 bytes are read as a little endian word, as rfc 8439
+	/// specifies, so these are the same bytes other implementations take.
+	/// 
+	/// ```
+	/// # use prrng::ChaCha;
+	/// let key = [7; 32];
+	/// let nonce = [3; 12];
+	/// 
+	/// let a = ChaCha::from_key_bytes(&key, &nonce, 0);
+	/// let b = ChaCha::new([0x07070707; 8], [0x03030303; 3], 0);
+	/// assert!(a == b);
+	/// ```
+	#[inline]
+	pub const fn from_key_bytes_n(key: &[u8; 32], nonce: &[u8; 12], block: u32) -> Self {
+		Self::new_n(le_words(key), le_words(nonce), block)
+	}
+
+	/// construct a new `ChaCha` from a key and nonce as bytes, with the
+	/// [`ChaChaLayout::Djb`] layout.
+	/// 
+	/// every 4 bytes are read as a little endian word, and `nonce` as a
+	/// little endian `u64`, so these are the same bytes that libsodium's
+	/// `crypto_stream_chacha20` takes.
+	#[inline]
+	pub const fn from_key_bytes_djb_n(key: &[u8; 32], nonce: &[u8; 8], block: u64) -> Self {
+		Self::new_djb_n(le_words(key), u64::from_le_bytes(*nonce), block)
+	}
+
+	/// which words of the state hold the block count.
+	#[inline]
+	pub const fn layout(&self) -> ChaChaLayout {
+		self.layout
+	}
+
+	/// get the block count that the keystream methods, like
+	/// [`Self::apply_keystream()`], will use next.
+	#[inline]
+	pub const fn block_count(&self) -> u64 {
+		match self.layout {
+			ChaChaLayout::Ietf => self.seed[12] as u64,
+			ChaChaLayout::Djb => self.seed[12] as u64 | (self.seed[13] as u64) << 32,
+		}
+	}
+
+	/// seek the keystream methods, like [`Self::apply_keystream()`], to the
+	/// start of block `block`, at byte `block * 64` of the keystream.
+	/// 
+	/// ## panics
+	/// 
+	/// panics if the layout is [`ChaChaLayout::Ietf`], and `block` doesn't
+	/// fit in a `u32`.
+	#[inline]
+	#[track_caller]
+	pub const fn set_block_count(&mut self, block: u64) {
+		if let ChaChaLayout::Ietf = self.layout {
+			assert!(block <= u32::MAX as u64, "`block` must fit in 32 bits");
+		}
+		self.store_block_count(block);
+		self.keystream = 0;
+	}
+
+	// truncates `block` to 32 bits with the ietf layout
+	#[inline]
+	const fn store_block_count(&mut self, block: u64) {
+		self.seed[12] = block as u32;
+		if let ChaChaLayout::Djb = self.layout {
+			self.seed[13] = (block >> 32) as u32;
+		}
+	}
+
 	/// get the internal state, which is also this algorithm's output
 	/// following a call to [`Self::run()`].
 	#[inline]
-	pub fn inner(&self) -> [u32; 16] {
+	pub const fn inner(&self) -> [u32; 16] {
 		self.seed
 	}
 
 	/// get the internal state as bytes, which is also this algorithm's output
 	/// following a call to [`Self::run()`].
 	#[inline]
-	pub fn inner_bytes(&self) -> [u8; 64] {
-		// todo: surely there's a better way of doing this :(
-		let mut ret = [0; 64];
-		let (iter, _) = ret.as_chunks_mut::<4>();
+	pub const fn inner_bytes(&self) -> [u8; 64] {
+		Self::to_bytes(&self.seed)
+	}
 
-		for (o, i) in iter.iter_mut().zip(self.seed.iter()) {
-			*o = i.to_le_bytes();
-		}
+	/// compute the output of [`Self::run()`] as bytes, without modifying
+	/// this instance.
+	/// 
+	/// ```
+	/// # use prrng::ChaCha;
+	/// let mut rng = ChaCha::new([0; 8], [0; 3], 0);
+	/// let block = rng.keystream_block();
+	/// 
+	/// rng.run();
+	/// assert_eq!(block, rng.inner_bytes());
+	/// ```
+	#[inline]
+	pub const fn keystream_block(&self) -> [u8; 64] {
+		Self::to_bytes(&Self::block(&self.seed))
+	}
 
-		ret
+	/// xor the keystream into `data`, starting from the current state and
+	/// incrementing the block count every 64 bytes.
+	/// 
+	/// this can be called repeatedly to process a message in pieces: a
+	/// partially used block is resumed from where it left off, so the result
+	/// does not depend on how `data` is split up.
+	/// 
+	/// this treats the current state as the input to the block function, and
+	/// should not be mixed with [`Self::run()`] or [`Self::get()`].
+	/// 
+	/// ```
+	/// # use prrng::ChaCha;
+	/// let key = [1, 2, 3, 4, 5, 6, 7, 8];
+	/// let nonce = [9, 10, 11];
+	/// 
+	/// let mut message = *b"meow meow meow meow meow meow";
+	/// 
+	/// let mut rng = ChaCha::new(key, nonce, 0);
+	/// rng.apply_keystream(&mut message[..5]);
+	/// rng.apply_keystream(&mut message[5..]);
+	/// assert_ne!(&message, b"meow meow meow meow meow meow");
+	/// 
+	/// let mut rng = ChaCha::new(key, nonce, 0);
+	/// rng.apply_keystream(&mut message);
+	/// assert_eq!(&message, b"meow meow meow meow meow meow");
+	/// ```
+	pub fn apply_keystream(&mut self, data: &mut [u8]) {
+		self.keystream_with(data, |i, o| *i ^= o);
+	}
+
+	/// fill `dst` with the keystream, starting from the current state and
+	/// incrementing the block count every 64 bytes.
+	/// 
+	/// this is the same as [`Self::apply_keystream()`] over zeroed bytes, and
+	/// can also be resumed.
+	/// 
+	/// ```
+	/// # use prrng::ChaCha;
+	/// let mut rng = ChaCha::new([0; 8], [0; 3], 0);
+	/// 
+	/// let mut bytes = [0; 100];
+	/// rng.fill_keystream(&mut bytes);
+	/// assert_eq!(bytes[..64], ChaCha::new([0; 8], [0; 3], 0).keystream_block());
+	/// assert_eq!(bytes[64..], ChaCha::new([0; 8], [0; 3], 1).keystream_block()[..36]);
+	/// ```
+	pub fn fill_keystream(&mut self, dst: &mut [u8]) {
+		self.keystream_with(dst, |i, o| *i = o);
+	}
+
+	/// fill `out` with the next `K` keystream blocks, incrementing the block
+	/// count after each one.
+	/// 
+	/// this is the same as [`Self::fill_keystream()`] over `K * 64` bytes.
+	/// 
+	/// ```
+	/// # use prrng::ChaCha;
+	/// let mut rng = ChaCha::new([0; 8], [0; 3], 0);
+	/// 
+	/// let mut blocks = [[0; 64]; 4];
+	/// rng.run_blocks(&mut blocks);
+	/// assert_eq!(blocks[3], ChaCha::new([0; 8], [0; 3], 3).keystream_block());
+	/// ```
+	#[inline]
+	pub fn run_blocks<const K: usize>(&mut self, out: &mut [[u8; 64]; K]) {
+		self.fill_keystream(out.as_flattened_mut());
+	}
+
+	fn keystream_with(&mut self, mut data: &mut [u8], mut f: impl FnMut(&mut u8, u8)) {
+		while !data.is_empty() {
+			let block = Self::to_bytes(&Self::block(&self.seed));
+			let start = self.keystream as usize;
+			let len = (64 - start).min(data.len());
+
+			let (head, tail) = data.split_at_mut(len);
+			for (i, o) in head.iter_mut().zip(block[start..].iter()) {
+				f(i, *o);
+			}
+			data = tail;
+
+			if start + len == 64 {
+				// wraps around, like other implementations
+				self.store_block_count(self.block_count().wrapping_add(1));
+				self.keystream = 0;
+			} else {
+				self.keystream = (start + len) as u8;
+			}
+		}
 	}
 
 	/// complete `N` rounds of the `ChaCha` algorithm.
-	pub fn run(&mut self) {
-		let mut x = self.seed;
+	pub const fn run(&mut self) {
+		self.seed = Self::block(&self.seed);
+		self.serialized = 0;
+	}
+
+	// the block function: `N` rounds over `seed`, added back onto `seed`
+	#[inline]
+	const fn block(seed: &[u32; 16]) -> [u32; 16] {
+		let mut x = *seed;
 
 		macro_rules! qr {
 			($a:expr, $b:expr, $c:expr, $d:expr) => {
@@ -170,7 +431,8 @@ impl<const N: u8> ChaCha<N> {
 			};
 		}
 
-		for _ in 0..N / 2 {
+		let mut round = 0;
+		while round < N / 2 {
 			qr!(x[0], x[4], x[8], x[12]);
 			qr!(x[1], x[5], x[9], x[13]);
 			qr!(x[2], x[6], x[10], x[14]);
@@ -180,14 +442,34 @@ impl<const N: u8> ChaCha<N> {
 			qr!(x[1], x[6], x[11], x[12]);
 			qr!(x[2], x[7], x[8], x[13]);
 			qr!(x[3], x[4], x[9], x[14]);
+
+			round += 1;
 		}
 
-		#[expect(clippy::needless_range_loop, reason = "resulting code-gen is good like this")]
-		for i in 0..self.seed.len() {
-			self.seed[i] = self.seed[i].wrapping_add(x[i]);
+		let mut i = 0;
+		while i < x.len() {
+			x[i] = x[i].wrapping_add(seed[i]);
+			i += 1;
 		}
 
-		self.serialized = 0;
+		x
+	}
+
+	#[inline]
+	const fn to_bytes(words: &[u32; 16]) -> [u8; 64] {
+		let mut ret = [0; 64];
+
+		let mut i = 0;
+		while i < 64 {
+			let bytes = words[i / 4].to_le_bytes();
+			ret[i] = bytes[0];
+			ret[i + 1] = bytes[1];
+			ret[i + 2] = bytes[2];
+			ret[i + 3] = bytes[3];
+			i += 4;
+		}
+
+		ret
 	}
 
 	/// returns the next value of this generator, returning `None` if the
@@ -195,7 +477,7 @@ impl<const N: u8> ChaCha<N> {
 	/// consumed status.
 	/// see [`Self::get()`] for a version that automatically calls `run()`.
 	#[inline]
-	pub fn get_checked(&mut self) -> Option<u32> {
+	pub const fn get_checked(&mut self) -> Option<u32> {
 		if self.serialized >= 16 {
 			None
 		} else {
@@ -209,7 +491,7 @@ impl<const N: u8> ChaCha<N> {
 	/// "consumed", this method calls [`Self::run()`].
 	/// see [`Self::get_checked()`] for a version that returns `None` instead.
 	#[inline]
-	pub fn get(&mut self) -> u32 {
+	pub const fn get(&mut self) -> u32 {
 		if self.serialized >= 16 {
 			self.run();
 		}
@@ -220,6 +502,18 @@ impl<const N: u8> ChaCha<N> {
 	}
 }
 
+// `bytes`, as little endian words
+#[inline]
+const fn le_words<const W: usize>(bytes: &[u8]) -> [u32; W] {
+	let mut words = [0; W];
+	let mut i = 0;
+	while i < W {
+		words[i] = u32::from_le_bytes([bytes[i * 4], bytes[i * 4 + 1], bytes[i * 4 + 2], bytes[i * 4 + 3]]);
+		i += 1;
+	}
+	words
+}
+
 impl<const N: u8> crate::RandomImpl for ChaCha<N> {
 	#[inline]
 	fn random_u64(&mut self) -> u64 {
@@ -232,13 +526,286 @@ impl<const N: u8> crate::RandomImpl for ChaCha<N> {
 	}
 
 	fn random_bytes(&mut self, dst: &mut [u8]) {
+		// finish off the current block, a word at a time
+		let words = (16 - self.serialized as usize).min(dst.len() / 4);
+		let (head, dst) = dst.split_at_mut(words * 4);
+		let start = self.serialized as usize * 4;
+		head.copy_from_slice(&self.inner_bytes()[start..start + words * 4]);
+		self.serialized += words as u8;
+
+		// whole blocks
+		let (blocks, dst) = dst.as_chunks_mut::<64>();
+		for block in blocks {
+			self.run();
+			*block = self.inner_bytes();
+			self.serialized = 16;
+		}
+
+		// stragglers
 		crate::common::bytes_from_u32(self, dst);
 	}
 }
 
+/// randomizes the key and nonce, with the block count starting at `0`.
+impl<const N: u8> crate::FromRandom for ChaCha<N> {
+	fn from_random(random: &mut impl crate::Random) -> Self {
+		Self::new_n(random.random(), random.random(), 0)
+	}
+}
+
 impl<const N: u8> core::fmt::Debug for ChaCha<N> {
 	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
 		write!(f, "ChaCha{}", N)
 	}
 }
 
+
+
+#[cfg(test)]
+mod test {
+	use crate::{ChaCha, ChaChaLayout, RandomImpl};
+
+	// https://www.rfc-editor.org/rfc/rfc8439#section-2.3.2
+	#[test]
+	fn test_rfc8439_block() {
+		let key = [
+			0x03020100, 0x07060504, 0x0b0a0908, 0x0f0e0d0c,
+			0x13121110, 0x17161514, 0x1b1a1918, 0x1f1e1d1c,
+		];
+		let nonce = [0x09000000, 0x4a000000, 0x00000000];
+
+		let rng = ChaCha::<20>::new_n(key, nonce, 1);
+
+		let expect = [
+			0x10, 0xf1, 0xe7, 0xe4, 0xd1, 0x3b, 0x59, 0x15, 0x50, 0x0f, 0xdd, 0x1f, 0xa3, 0x20, 0x71, 0xc4,
+			0xc7, 0xd1, 0xf4, 0xc7, 0x33, 0xc0, 0x68, 0x03, 0x04, 0x22, 0xaa, 0x9a, 0xc3, 0xd4, 0x6c, 0x4e,
+			0xd2, 0x82, 0x64, 0x46, 0x07, 0x9f, 0xaa, 0x09, 0x14, 0xc2, 0xd7, 0x05, 0xd9, 0x8b, 0x02, 0xa2,
+			0xb5, 0x12, 0x9c, 0xd1, 0xde, 0x16, 0x4e, 0xb9, 0xcb, 0xd0, 0x83, 0xe8, 0xa2, 0x50, 0x3c, 0x4e,
+		];
+		assert_eq!(rng.keystream_block(), expect);
+	}
+
+	// the same vector, with the key and nonce as they are written there
+	#[test]
+	fn test_rfc8439_bytes() {
+		let key = [
+			0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f,
+			0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1a, 0x1b, 0x1c, 0x1d, 0x1e, 0x1f,
+		];
+		let nonce = [0x00, 0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x4a, 0x00, 0x00, 0x00, 0x00];
+
+		let rng = ChaCha::<20>::from_key_bytes_n(&key, &nonce, 1);
+		assert_eq!(&rng.keystream_block()[..8], &[0x10, 0xf1, 0xe7, 0xe4, 0xd1, 0x3b, 0x59, 0x15]);
+		assert!(rng == ChaCha::<20>::new_n(
+			[
+				0x03020100, 0x07060504, 0x0b0a0908, 0x0f0e0d0c,
+				0x13121110, 0x17161514, 0x1b1a1918, 0x1f1e1d1c,
+			],
+			[0x09000000, 0x4a000000, 0x00000000],
+			1,
+		));
+
+		// and the djb vector
+		let nonce = [0x00, 0x00, 0x00, 0x4a, 0x00, 0x00, 0x00, 0x09];
+		let rng = ChaCha::<20>::from_key_bytes_djb_n(&key, &nonce, 0xffffffff);
+		assert_eq!(&rng.keystream_block()[..8], &[0x10, 0x63, 0xe0, 0xf3, 0x0e, 0x55, 0xed, 0xdf]);
+	}
+
+	// https://www.rfc-editor.org/rfc/rfc8439#section-2.4.2
+	#[test]
+	fn test_rfc8439_encrypt() {
+		let key = [
+			0x03020100, 0x07060504, 0x0b0a0908, 0x0f0e0d0c,
+			0x13121110, 0x17161514, 0x1b1a1918, 0x1f1e1d1c,
+		];
+		let nonce = [0x00000000, 0x4a000000, 0x00000000];
+
+		let mut message = *b"Ladies and Gentlemen of the class of '99: If I could offer you only one tip for the future, sunscreen would be it.";
+
+		let mut other = message;
+
+		for (block, bytes) in message.chunks_mut(64).enumerate() {
+			let rng = ChaCha::<20>::new_n(key, nonce, 1 + block as u32);
+			for (i, o) in bytes.iter_mut().zip(rng.keystream_block().iter()) {
+				*i ^= *o;
+			}
+		}
+
+		ChaCha::<20>::new_n(key, nonce, 1).apply_keystream(&mut other);
+		assert_eq!(message, other);
+
+		let expect = [
+			0x6e, 0x2e, 0x35, 0x9a, 0x25, 0x68, 0xf9, 0x80, 0x41, 0xba, 0x07, 0x28, 0xdd, 0x0d, 0x69, 0x81,
+			0xe9, 0x7e, 0x7a, 0xec, 0x1d, 0x43, 0x60, 0xc2, 0x0a, 0x27, 0xaf, 0xcc, 0xfd, 0x9f, 0xae, 0x0b,
+			0xf9, 0x1b, 0x65, 0xc5, 0x52, 0x47, 0x33, 0xab, 0x8f, 0x59, 0x3d, 0xab, 0xcd, 0x62, 0xb3, 0x57,
+			0x16, 0x39, 0xd6, 0x24, 0xe6, 0x51, 0x52, 0xab, 0x8f, 0x53, 0x0c, 0x35, 0x9f, 0x08, 0x61, 0xd8,
+			0x07, 0xca, 0x0d, 0xbf, 0x50, 0x0d, 0x6a, 0x61, 0x56, 0xa3, 0x8e, 0x08, 0x8a, 0x22, 0xb6, 0x5e,
+			0x52, 0xbc, 0x51, 0x4d, 0x16, 0xcc, 0xf8, 0x06, 0x81, 0x8c, 0xe9, 0x1a, 0xb7, 0x79, 0x37, 0x36,
+			0x5a, 0xf9, 0x0b, 0xbf, 0x74, 0xa3, 0x5b, 0xe6, 0xb4, 0x0b, 0x8e, 0xed, 0xf2, 0x78, 0x5e, 0x42,
+			0x87, 0x4d,
+		];
+		assert_eq!(message, expect);
+	}
+
+	// generated with libsodium's `crypto_stream_chacha20_xor_ic()`, which
+	// uses the djb layout
+	#[test]
+	fn test_djb() {
+		let key = [
+			0x03020100, 0x07060504, 0x0b0a0908, 0x0f0e0d0c,
+			0x13121110, 0x17161514, 0x1b1a1918, 0x1f1e1d1c,
+		];
+		// nonce bytes `00 00 00 4a 00 00 00 09`
+		let nonce = 0x09000000_4a000000;
+
+		// the second block carries into the high word of the count
+		let mut rng = ChaCha::<20>::new_djb_n(key, nonce, 0xffffffff);
+		let mut bytes = [0; 128];
+		rng.fill_keystream(&mut bytes);
+
+		let expect = [
+			0x10, 0x63, 0xe0, 0xf3, 0x0e, 0x55, 0xed, 0xdf, 0x0e, 0x6d, 0xa9, 0x78, 0x9e, 0x9d, 0x78, 0x06,
+			0x64, 0x33, 0x0b, 0x3e, 0x87, 0x0e, 0x6b, 0x83, 0x6b, 0x8b, 0xbc, 0xd4, 0xcf, 0x42, 0xd6, 0x1c,
+			0xff, 0x18, 0xa2, 0x75, 0x9f, 0xc3, 0x6b, 0x8e, 0x75, 0x44, 0xc9, 0x45, 0x07, 0x18, 0x26, 0x23,
+			0x5b, 0xc0, 0x20, 0xc1, 0xc1, 0x46, 0x27, 0x55, 0xa9, 0xec, 0x74, 0xe8, 0x30, 0x43, 0x1c, 0xb9,
+			0x30, 0xff, 0xda, 0x77, 0xf1, 0x4b, 0x2e, 0xd8, 0xe3, 0xea, 0x63, 0x9d, 0x95, 0x25, 0xbf, 0xf6,
+			0x20, 0x60, 0xf8, 0x69, 0xaf, 0x47, 0x88, 0x01, 0xb3, 0x16, 0xfd, 0x2c, 0x19, 0x92, 0xb9, 0x6b,
+			0x60, 0x20, 0xc9, 0x65, 0x06, 0x18, 0xba, 0x6f, 0x8d, 0x91, 0x82, 0x4a, 0x3b, 0x33, 0x3e, 0xed,
+			0xf9, 0x66, 0xde, 0x04, 0x2c, 0x0e, 0x2c, 0x2b, 0xcb, 0x67, 0xcf, 0x1e, 0x37, 0x31, 0x04, 0x76,
+		];
+		assert_eq!(bytes, expect);
+		assert_eq!(rng.block_count(), 0x1_0000_0001);
+
+		// seeking lands on the same blocks
+		rng.set_block_count(0x1_0000_0000);
+		assert_eq!(rng.keystream_block(), expect[64..]);
+		assert_eq!(rng.inner()[12..14], [0, 1]);
+
+		// the ietf layout only carries within word 12
+		let mut rng = ChaCha::<20>::new_n(key, [0, 0, 0], u32::MAX);
+		rng.fill_keystream(&mut bytes);
+		assert_eq!(rng.layout(), ChaChaLayout::Ietf);
+		assert_eq!(rng.block_count(), 1);
+		assert_eq!(rng.inner()[12..14], [1, 0]);
+	}
+
+	#[test]
+	#[should_panic = "`block` must fit in 32 bits"]
+	fn test_ietf_seek() {
+		ChaCha::new([0; 8], [0; 3], 0).set_block_count(1 << 32);
+	}
+
+	#[test]
+	fn test_keystream_chunked() {
+		let key = [1, 2, 3, 4, 5, 6, 7, 8];
+		let nonce = [9, 10, 11];
+
+		let mut expect = [0u8; 300];
+		for (i, x) in expect.iter_mut().enumerate() {
+			*x = i as u8;
+		}
+		let original = expect;
+		ChaCha::new(key, nonce, 0).apply_keystream(&mut expect);
+		assert_ne!(expect, original);
+
+		for split in [[0, 0], [1, 2], [63, 64], [64, 128], [65, 250], [100, 300]] {
+			let mut data = original;
+			let mut rng = ChaCha::new(key, nonce, 0);
+			let (a, rest) = data.split_at_mut(split[0]);
+			let (b, c) = rest.split_at_mut(split[1] - split[0]);
+			rng.apply_keystream(a);
+			rng.apply_keystream(b);
+			rng.apply_keystream(c);
+			assert_eq!(data, expect);
+
+			// round trip
+			let mut rng = ChaCha::new(key, nonce, 0);
+			rng.apply_keystream(&mut data);
+			assert_eq!(data, original);
+		}
+	}
+
+	#[test]
+	fn test_run_blocks() {
+		let key = [1, 2, 3, 4, 5, 6, 7, 8];
+		let nonce = [9, 10, 11];
+
+		let mut rng = ChaCha::new(key, nonce, 5);
+		let mut blocks = [[0; 64]; 8];
+		rng.run_blocks(&mut blocks);
+		for (i, block) in blocks.iter().enumerate() {
+			assert_eq!(*block, ChaCha::new(key, nonce, 5 + i as u32).keystream_block());
+		}
+		assert_eq!(rng.inner()[12], 13);
+
+		rng.run_blocks(&mut [[0; 64]; 0]);
+		assert_eq!(rng.inner()[12], 13);
+
+		// the counter wraps
+		let mut rng = ChaCha::new(key, nonce, u32::MAX);
+		let mut blocks = [[0; 64]; 2];
+		rng.run_blocks(&mut blocks);
+		assert_eq!(blocks[1], ChaCha::new(key, nonce, 0).keystream_block());
+	}
+
+	#[test]
+	fn test_fill_keystream() {
+		let key = [1, 2, 3, 4, 5, 6, 7, 8];
+		let nonce = [9, 10, 11];
+
+		let mut expect = [0u8; 300];
+		ChaCha::new(key, nonce, 0).apply_keystream(&mut expect);
+
+		let mut data = [0u8; 300];
+		let mut rng = ChaCha::new(key, nonce, 0);
+		rng.fill_keystream(&mut data[..10]);
+		rng.fill_keystream(&mut data[10..64]);
+		let mut blocks = [[0; 64]; 2];
+		rng.run_blocks(&mut blocks);
+		data[64..192].copy_from_slice(blocks.as_flattened());
+		rng.fill_keystream(&mut data[192..]);
+		assert_eq!(data, expect);
+	}
+
+	#[test]
+	fn test_bytes() {
+		let mut a = ChaCha::new([1, 2, 3, 4, 5, 6, 7, 8], [9, 10, 11], 0);
+		let mut b = a.clone();
+
+		let mut bytes = [0; 200];
+		a.random_bytes(&mut bytes);
+
+		let mut expect = [0; 256];
+		for block in expect.as_chunks_mut::<64>().0 {
+			b.run();
+			*block = b.inner_bytes();
+		}
+		assert_eq!(bytes, expect[..200]);
+
+		// the rest of the last block is still available
+		assert_eq!(a.random_u32(), u32::from_le_bytes(expect[200..204].try_into().unwrap()));
+	}
+
+	#[test]
+	fn test_bytes_stream() {
+		// `random_bytes` agrees with serializing `random_u32`
+		for offset in 0..20 {
+			for len in [0, 1, 3, 4, 7, 64, 65, 127, 200] {
+				let mut a = ChaCha::new([1, 2, 3, 4, 5, 6, 7, 8], [9, 10, 11], 0);
+				let mut b = a.clone();
+
+				for _ in 0..offset {
+					assert_eq!(a.random_u32(), b.random_u32());
+				}
+
+				let mut x = [0; 200];
+				let mut y = [0; 200];
+				a.random_bytes(&mut x[..len]);
+				crate::common::bytes_from_u32(&mut b, &mut y[..len]);
+				assert_eq!(x, y);
+
+				assert_eq!(a.random_u32(), b.random_u32());
+			}
+		}
+	}
+}
@@ -0,0 +1,364 @@
+use crate::ChaCha;
+
+/// [`ChaCha`] as a general purpose cryptographically secure psuedo-rng.
+/// 
+/// [`ChaCha`] on its own is the block function, and leaves the block count
+/// and nonce up to the caller. this instead owns them, and reads the
+/// keystream a block at a time, incrementing the block count after each one.
+/// the keystream is the same as [`ChaCha::fill_keystream()`] would write,
+/// and [`Self::seek_bytes()`] can jump anywhere in it.
+/// 
+/// this uses the [`crate::ChaChaLayout::Djb`] layout, so the 64 bit count
+/// practically never runs out. with an all zero nonce, as [`Self::new()`]
+/// uses, the keystream also matches the [`crate::ChaChaLayout::Ietf`] layout
+/// for its first 256 GiB.
+/// 
+/// this yields u32 values, the little endian words of the keystream.
+/// 
+/// ```
+/// use prrng::{ChaChaRng, RandomImpl};
+/// 
+/// // please use a good source of entropy for this, like
+/// // `ChaChaRng::new_from_entropy()` with the `getrandom` feature.
+/// let mut rng = ChaChaRng::new([7; 32]);
+/// let x = rng.random_u64();
+/// 
+/// let mut bytes = [0; 100];
+/// rng.random_bytes(&mut bytes);
+/// 
+/// // go back, and read it all again
+/// rng.seek_bytes(0);
+/// assert_eq!(rng.random_u64(), x);
+/// ```
+#[derive(Clone)]
+pub struct ChaChaRng<const N: u8 = 12> {
+	// the block count is of the block after `buffer`
+	core: ChaCha<N>,
+	buffer: [u8; 64],
+	// how much of `buffer` has been read, which is `64` when it needs a refill
+	index: u8,
+}
+
+impl ChaChaRng {
+	/// construct a new `ChaChaRng12`, with an all zero nonce.
+	/// see [`Self::new_n()`] for a generic constructor method.
+	#[inline]
+	pub const fn new(key: [u8; 32]) -> Self {
+		Self::new_n(key)
+	}
+
+	/// construct a new `ChaChaRng12`.
+	/// see [`Self::new_nonce_n()`] for a generic constructor method.
+	#[inline]
+	pub const fn new_nonce(key: [u8; 32], nonce: [u8; 8]) -> Self {
+		Self::new_nonce_n(key, nonce)
+	}
+}
+
+impl<const N: u8> ChaChaRng<N> {
+	/// construct a new `ChaChaRng`, with an all zero nonce.
+	/// 
+	/// a different key gives a different keystream, so for use as a prng,
+	/// the nonce can be left alone.
+	#[inline]
+	pub const fn new_n(key: [u8; 32]) -> Self {
+		Self::new_nonce_n(key, [0; 8])
+	}
+
+	/// construct a new `ChaChaRng`.
+	/// 
+	/// `key` and `nonce` are the same bytes that
+	/// [`ChaCha::from_key_bytes_djb_n()`] takes.
+	#[inline]
+	pub const fn new_nonce_n(key: [u8; 32], nonce: [u8; 8]) -> Self {
+		Self {
+			core: ChaCha::from_key_bytes_djb_n(&key, &nonce, 0),
+			buffer: [0; 64],
+			index: 64,
+		}
+	}
+
+	/// construct a new `ChaChaRng`, with a key from the operating system's
+	/// entropy source, through [`getrandom`](https://docs.rs/getrandom).
+	/// 
+	/// this requires the `getrandom` feature.
+	#[cfg(feature = "getrandom")]
+	pub fn new_from_entropy() -> Result<Self, getrandom::Error> {
+		let mut key = [0; 32];
+		getrandom::fill(&mut key)?;
+		Ok(Self::new_n(key))
+	}
+
+	/// how many bytes of the keystream have been read.
+	#[inline]
+	pub const fn byte_position(&self) -> u64 {
+		self.core.block_count().wrapping_sub(1).wrapping_mul(64).wrapping_add(self.index as u64)
+	}
+
+	/// seek to byte `offset` of the keystream, so that is the next byte read.
+	/// 
+	/// ```
+	/// use prrng::{ChaChaRng, RandomImpl};
+	/// 
+	/// let mut a = ChaChaRng::new([7; 32]);
+	/// let mut b = a.clone();
+	/// 
+	/// let mut bytes = [0; 1000];
+	/// a.random_bytes(&mut bytes);
+	/// 
+	/// b.seek_bytes(996);
+	/// assert_eq!(b.random_u32().to_le_bytes(), bytes[996..]);
+	/// assert!(a == b);
+	/// ```
+	#[inline]
+	pub const fn seek_bytes(&mut self, offset: u64) {
+		self.core.set_block_count(offset / 64);
+		self.index = 64;
+		if !offset.is_multiple_of(64) {
+			self.refill();
+			self.index = (offset % 64) as u8;
+		}
+	}
+
+	#[inline]
+	const fn refill(&mut self) {
+		self.buffer = self.core.keystream_block();
+		self.core.set_block_count(self.core.block_count().wrapping_add(1));
+		self.index = 0;
+	}
+
+	#[inline]
+	const fn next_byte(&mut self) -> u8 {
+		if self.index == 64 {
+			self.refill();
+		}
+		let ret = self.buffer[self.index as usize];
+		self.index += 1;
+		ret
+	}
+
+	/// returns the next value of this generator, the next 4 bytes of the
+	/// keystream as a little endian word.
+	#[inline]
+	pub const fn get(&mut self) -> u32 {
+		let i = self.index as usize;
+		if i + 4 <= 64 {
+			self.index += 4;
+			u32::from_le_bytes([self.buffer[i], self.buffer[i + 1], self.buffer[i + 2], self.buffer[i + 3]])
+		} else {
+			// the end of a block, or a word split across two after seeking
+			u32::from_le_bytes([self.next_byte(), self.next_byte(), self.next_byte(), self.next_byte()])
+		}
+	}
+}
+
+impl<const N: u8> crate::RandomImpl for ChaChaRng<N> {
+	#[inline]
+	fn random_u64(&mut self) -> u64 {
+		crate::common::u32_compose_u64(self.get(), self.get())
+	}
+
+	#[inline]
+	fn random_u32(&mut self) -> u32 {
+		self.get()
+	}
+
+	fn random_bytes(&mut self, dst: &mut [u8]) {
+		// the rest of the last word is thrown away, like other generators
+		let extra = dst.len().next_multiple_of(4) - dst.len();
+
+		// the rest of the current block
+		let start = self.index as usize;
+		let len = (64 - start).min(dst.len());
+		let (head, dst) = dst.split_at_mut(len);
+		head.copy_from_slice(&self.buffer[start..start + len]);
+		self.index += len as u8;
+
+		// whole blocks, straight from the keystream
+		let (blocks, dst) = dst.split_at_mut(dst.len() / 64 * 64);
+		self.core.fill_keystream(blocks);
+
+		// the start of another block
+		if !dst.is_empty() {
+			self.refill();
+			dst.copy_from_slice(&self.buffer[..dst.len()]);
+			self.index = dst.len() as u8;
+		}
+
+		for _ in 0..extra {
+			self.next_byte();
+		}
+	}
+
+	fn random_bytes_uninit<'a>(&mut self, dst: &'a mut [core::mem::MaybeUninit<u8>]) -> &'a mut [u8] {
+		let extra = dst.len().next_multiple_of(4) - dst.len();
+
+		// straight out of `buffer`, a block at a time
+		let mut done = 0;
+		while done < dst.len() {
+			if self.index == 64 {
+				self.refill();
+			}
+			let start = self.index as usize;
+			let len = (64 - start).min(dst.len() - done);
+			for (o, i) in dst[done..done + len].iter_mut().zip(&self.buffer[start..]) {
+				o.write(*i);
+			}
+			self.index += len as u8;
+			done += len;
+		}
+
+		for _ in 0..extra {
+			self.next_byte();
+		}
+		unsafe {
+			// safety:
+			// every byte of `dst` was written above, and `MaybeUninit<u8>`
+			// has the same layout as `u8`.
+			&mut *(dst as *mut [core::mem::MaybeUninit<u8>] as *mut [u8])
+		}
+	}
+}
+
+impl<const N: u8> PartialEq for ChaChaRng<N> {
+	fn eq(&self, other: &Self) -> bool {
+		// `buffer` follows from the block count, whenever it is read
+		self.core == other.core && self.index == other.index
+	}
+}
+
+impl<const N: u8> Eq for ChaChaRng<N> {}
+
+/// randomizes the key, with an all zero nonce.
+impl<const N: u8> crate::FromRandom for ChaChaRng<N> {
+	fn from_random(random: &mut impl crate::Random) -> Self {
+		Self::new_n(random.random())
+	}
+}
+
+impl<const N: u8> core::fmt::Debug for ChaChaRng<N> {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		write!(f, "ChaChaRng{}", N)
+	}
+}
+
+
+#[cfg(test)]
+mod test {
+	use crate::{ChaCha, ChaChaRng, RandomImpl};
+
+	// https://www.rfc-editor.org/rfc/rfc8439#appendix-A.1, test vectors 1
+	// and 2, which are blocks 0 and 1 of the zero key and nonce
+	const RFC8439_A1: [u8; 128] = [
+		0x76, 0xb8, 0xe0, 0xad, 0xa0, 0xf1, 0x3d, 0x90, 0x40, 0x5d, 0x6a, 0xe5, 0x53, 0x86, 0xbd, 0x28,
+		0xbd, 0xd2, 0x19, 0xb8, 0xa0, 0x8d, 0xed, 0x1a, 0xa8, 0x36, 0xef, 0xcc, 0x8b, 0x77, 0x0d, 0xc7,
+		0xda, 0x41, 0x59, 0x7c, 0x51, 0x57, 0x48, 0x8d, 0x77, 0x24, 0xe0, 0x3f, 0xb8, 0xd8, 0x4a, 0x37,
+		0x6a, 0x43, 0xb8, 0xf4, 0x15, 0x18, 0xa1, 0x1c, 0xc3, 0x87, 0xb6, 0x69, 0xb2, 0xee, 0x65, 0x86,
+		0x9f, 0x07, 0xe7, 0xbe, 0x55, 0x51, 0x38, 0x7a, 0x98, 0xba, 0x97, 0x7c, 0x73, 0x2d, 0x08, 0x0d,
+		0xcb, 0x0f, 0x29, 0xa0, 0x48, 0xe3, 0x65, 0x69, 0x12, 0xc6, 0x53, 0x3e, 0x32, 0xee, 0x7a, 0xed,
+		0x29, 0xb7, 0x21, 0x76, 0x9c, 0xe6, 0x4e, 0x43, 0xd5, 0x71, 0x33, 0xb0, 0x74, 0xd8, 0x39, 0xd5,
+		0x31, 0xed, 0x1f, 0x28, 0x51, 0x0a, 0xfb, 0x45, 0xac, 0xe1, 0x0a, 0x1f, 0x4b, 0x79, 0x4d, 0x6f,
+	];
+
+	#[test]
+	fn test_rfc8439() {
+		let mut rng = ChaChaRng::<20>::new_n([0; 32]);
+		let mut bytes = [0; 128];
+		rng.random_bytes(&mut bytes);
+		assert_eq!(bytes, RFC8439_A1);
+		assert_eq!(rng.byte_position(), 128);
+
+		// the same, a word at a time
+		let mut rng = ChaChaRng::<20>::new_n([0; 32]);
+		for word in RFC8439_A1.as_chunks::<4>().0 {
+			assert_eq!(rng.random_u32(), u32::from_le_bytes(*word));
+		}
+
+		// and the same as the block function, with the ietf layout
+		let mut rng = ChaChaRng::<20>::new_n([0; 32]);
+		let mut bytes = [0; 64 * 5];
+		rng.random_bytes(&mut bytes);
+		for (i, block) in bytes.as_chunks::<64>().0.iter().enumerate() {
+			assert_eq!(*block, ChaCha::<20>::new_n([0; 8], [0; 3], i as u32).keystream_block());
+		}
+	}
+
+	#[test]
+	fn test_bytes_split() {
+		let mut expect = [0; 300];
+		ChaChaRng::new([3; 32]).random_bytes(&mut expect);
+
+		for split in [[0, 0], [4, 8], [60, 64], [64, 128], [68, 252], [100, 300]] {
+			let mut rng = ChaChaRng::new([3; 32]);
+			let mut bytes = [0; 300];
+			let (a, rest) = bytes.split_at_mut(split[0]);
+			let (b, c) = rest.split_at_mut(split[1] - split[0]);
+			rng.random_bytes(a);
+			rng.random_bytes(b);
+			rng.random_bytes(c);
+			assert_eq!(bytes, expect);
+			assert_eq!(rng.byte_position(), 300);
+		}
+
+		// a partial word is used up
+		let mut rng = ChaChaRng::new([3; 32]);
+		let mut bytes = [0; 5];
+		rng.random_bytes(&mut bytes);
+		assert_eq!(bytes, expect[..5]);
+		assert_eq!(rng.byte_position(), 8);
+		assert_eq!(rng.random_u32().to_le_bytes(), expect[8..12]);
+	}
+
+	#[test]
+	fn test_seek() {
+		let mut expect = [0; 1000];
+		ChaChaRng::new([3; 32]).random_bytes(&mut expect);
+
+		let mut rng = ChaChaRng::new([3; 32]);
+		for offset in [0, 1, 3, 4, 63, 64, 65, 127, 128, 500, 995, 996] {
+			rng.seek_bytes(offset);
+			assert_eq!(rng.byte_position(), offset);
+
+			let i = offset as usize;
+			assert_eq!(rng.random_u32().to_le_bytes(), expect[i..i + 4], "{offset}");
+			assert_eq!(rng.byte_position(), offset + 4);
+
+			rng.seek_bytes(offset);
+			let mut bytes = [0; 1000];
+			rng.random_bytes(&mut bytes[i..]);
+			assert_eq!(bytes[i..], expect[i..], "{offset}");
+		}
+
+		// seeking lands on the same state as reading
+		let mut a = ChaChaRng::new([3; 32]);
+		let mut b = ChaChaRng::new([3; 32]);
+		for len in [0, 64, 36, 64, 128, 4] {
+			a.random_bytes(&mut expect[..len]);
+			b.seek_bytes(a.byte_position());
+			assert!(a == b);
+			assert_eq!(a.random_u64(), b.random_u64());
+		}
+
+		// far past the 32 bit block count
+		let mut rng = ChaChaRng::new([3; 32]);
+		rng.seek_bytes(64 << 32);
+		let mut core = ChaCha::from_key_bytes_djb(&[3; 32], &[0; 8], 1 << 32);
+		let mut block = [0; 64];
+		core.fill_keystream(&mut block);
+		assert_eq!(rng.random_u32().to_le_bytes(), block[..4]);
+	}
+
+	#[test]
+	fn test_nonce() {
+		let mut a = ChaChaRng::new_nonce([3; 32], [1; 8]);
+		let mut b = ChaCha::from_key_bytes_djb(&[3; 32], &[1; 8], 0);
+		let mut x = [0; 200];
+		let mut y = [0; 200];
+		a.random_bytes(&mut x);
+		b.fill_keystream(&mut y);
+		assert_eq!(x, y);
+
+		let mut c = ChaChaRng::new([3; 32]);
+		assert_ne!(c.random_u64(), ChaChaRng::new_nonce([3; 32], [1; 8]).random_u64());
+	}
+}
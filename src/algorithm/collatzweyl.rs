@@ -1,7 +1,7 @@
 
 // remember to skip first 48/96 states
 // https://arxiv.org/abs/2312.17043
-#[derive(Clone)]
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub struct CollatzWeyl64 {
 	x: u64,
 	a: u64,
@@ -32,12 +32,32 @@ impl CollatzWeyl64 {
 		Self::new_raw(state, seed | 1)
 	}
 
+	/// construct a new `CollatzWeyl64`, returning
+	/// [`crate::Error::InvalidParameter`] if `seed` is even, instead of
+	/// adjusting it like [`Self::new_one()`].
+	#[inline]
+	pub const fn try_new_one(seed: u64) -> Result<Self, crate::Error> {
+		Self::try_new_two(0, seed)
+	}
+
+	/// construct a new `CollatzWeyl64`, returning
+	/// [`crate::Error::InvalidParameter`] if `seed` is even, instead of
+	/// adjusting it like [`Self::new_two()`].
+	#[inline]
+	pub const fn try_new_two(state: u64, seed: u64) -> Result<Self, crate::Error> {
+		if seed & 1 == 0 {
+			return Err(crate::Error::InvalidParameter);
+		}
+		Ok(Self::new_raw(state, seed))
+	}
+
 	#[inline]
 	pub const fn get(&mut self) -> u64 {
 		self.a = self.a.wrapping_add(self.x);
 		self.weyl = self.weyl.wrapping_add(self.s);
+		// x = (x >> 1) * ((a += x) | 1) ^ (weyl += s);
 		self.x = (self.x >> 1).wrapping_mul(self.a | 1) ^ self.weyl;
-		// is this the correct precedence?
+		// return a >> 48 ^ x;
 		(self.a >> 48) ^ self.x
 	}
 }
@@ -58,13 +78,19 @@ impl crate::RandomImpl for CollatzWeyl64 {
 	}
 }
 
+impl crate::FromRandom for CollatzWeyl64 {
+	fn from_random(random: &mut impl crate::Random) -> Self {
+		Self::new_two(random.random(), random.random())
+	}
+}
+
 impl core::fmt::Debug for CollatzWeyl64 {
 	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
 		write!(f, "CollatzWeyl64")
 	}
 }
 
-#[derive(Clone)]
+#[derive(Clone, PartialEq, Eq)]
 pub struct CollatzWeyl128_64 {
 	x: u128,
 	a: u64,
@@ -98,11 +124,33 @@ impl CollatzWeyl128_64 {
 		)
 	}
 
+	/// construct a new `CollatzWeyl128_64`, returning
+	/// [`crate::Error::InvalidParameter`] if `seed` is even, instead of
+	/// adjusting it like [`Self::new_one()`].
+	#[inline]
+	pub const fn try_new_one(seed: u64) -> Result<Self, crate::Error> {
+		Self::try_new_two(0, seed)
+	}
+
+	/// construct a new `CollatzWeyl128_64`, returning
+	/// [`crate::Error::InvalidParameter`] if `seed` is even, instead of
+	/// adjusting it like [`Self::new_two()`].
+	#[inline]
+	pub const fn try_new_two(state: u128, seed: u64) -> Result<Self, crate::Error> {
+		if seed & 1 == 0 {
+			return Err(crate::Error::InvalidParameter);
+		}
+		Ok(Self::new_raw(state, seed))
+	}
+
 	#[inline]
 	pub const fn get(&mut self) -> u128 {
 		self.a = (self.a as u128).wrapping_add(self.x) as u64;
 		self.weyl = self.weyl.wrapping_add(self.s);
+		// unlike the others, the reference multiplies `(x | 1) * (a >> 1)`
+		// x = (x | 1) * ((a += x) >> 1) ^ (weyl += s);
 		self.x = (self.x | 1).wrapping_mul((self.a >> 1) as u128) ^ self.weyl as u128;
+		// return a >> 48 ^ x;
 		(self.a >> 48) as u128 ^ self.x
 	}
 }
@@ -119,7 +167,13 @@ impl crate::RandomImpl for CollatzWeyl128_64 {
 	}
 
 	fn random_bytes(&mut self, dst: &mut [u8]) {
-		crate::common::bytes_from_u64(self, dst);
+		crate::common::bytes_from_u128(|| self.get(), dst);
+	}
+}
+
+impl crate::FromRandom for CollatzWeyl128_64 {
+	fn from_random(random: &mut impl crate::Random) -> Self {
+		Self::new_two(random.random(), random.random())
 	}
 }
 
@@ -129,7 +183,7 @@ impl core::fmt::Debug for CollatzWeyl128_64 {
 	}
 }
 
-#[derive(Clone)]
+#[derive(Clone, PartialEq, Eq)]
 pub struct CollatzWeyl128 {
 	x: u128,
 	a: u128,
@@ -163,11 +217,32 @@ impl CollatzWeyl128 {
 		)
 	}
 
+	/// construct a new `CollatzWeyl128`, returning
+	/// [`crate::Error::InvalidParameter`] if `seed` is even, instead of
+	/// adjusting it like [`Self::new_one()`].
+	#[inline]
+	pub const fn try_new_one(seed: u128) -> Result<Self, crate::Error> {
+		Self::try_new_two(0, seed)
+	}
+
+	/// construct a new `CollatzWeyl128`, returning
+	/// [`crate::Error::InvalidParameter`] if `seed` is even, instead of
+	/// adjusting it like [`Self::new_two()`].
+	#[inline]
+	pub const fn try_new_two(state: u128, seed: u128) -> Result<Self, crate::Error> {
+		if seed & 1 == 0 {
+			return Err(crate::Error::InvalidParameter);
+		}
+		Ok(Self::new_raw(state, seed))
+	}
+
 	#[inline]
 	pub const fn get(&mut self) -> u128 {
 		self.a = self.a.wrapping_add(self.x);
 		self.weyl = self.weyl.wrapping_add(self.s);
+		// x = (x >> 1) * ((a += x) | 1) ^ (weyl += s);
 		self.x = (self.x >> 1).wrapping_mul(self.a | 1) ^ self.weyl;
+		// return a >> 96 ^ x;
 		(self.a >> 96) ^ self.x
 	}
 }
@@ -184,7 +259,13 @@ impl crate::RandomImpl for CollatzWeyl128 {
 	}
 
 	fn random_bytes(&mut self, dst: &mut [u8]) {
-		crate::common::bytes_from_u64(self, dst);
+		crate::common::bytes_from_u128(|| self.get(), dst);
+	}
+}
+
+impl crate::FromRandom for CollatzWeyl128 {
+	fn from_random(random: &mut impl crate::Random) -> Self {
+		Self::new_two(random.random(), random.random())
 	}
 }
 
@@ -194,3 +275,143 @@ impl core::fmt::Debug for CollatzWeyl128 {
 	}
 }
 
+
+
+#[cfg(test)]
+mod test {
+	use crate::{CollatzWeyl64, CollatzWeyl128_64, CollatzWeyl128};
+
+	// vectors are from a transcription of the reference code in the paper,
+	// without skipping any outputs
+
+	#[test]
+	fn test_64() {
+		let mut rng = CollatzWeyl64::new_one(0x9e3779b97f4a7c15);
+		let expect = [
+			11400714819323198485,
+			2083102596547643599,
+			7095754141924197971,
+			18282545114554722036,
+			13384471899968963654,
+			6847830461577649112,
+			14109635446595764126,
+			13196287054587457534,
+			15640901662822888495,
+			1340045712324244551,
+		];
+		for x in expect {
+			assert_eq!(rng.get(), x);
+		}
+
+		let mut rng = CollatzWeyl64::new_two(0x0123456789abcdef, 0xdeadbeefcafef00c);
+		let expect = [
+			12928973334634142391,
+			6307754052873125076,
+			18364782705188832336,
+			7098747501066020006,
+			16025866732573457171,
+			1123412163105753211,
+			5482435535496685412,
+			10649065218060552980,
+			12319115676800276391,
+			13210556753591708919,
+		];
+		for x in expect {
+			assert_eq!(rng.get(), x);
+		}
+	}
+
+	#[test]
+	fn test_128_64() {
+		let mut rng = CollatzWeyl128_64::new_one(0x9e3779b97f4a7c15);
+		let expect = [
+			11400714819323198485,
+			2083102596547643599,
+			15951025926146410569,
+			6183650162246302746,
+			9312428646516512101,
+			9975640663279254104,
+			13051433761596835391,
+			961969128838672120,
+			9307094233738339465,
+			16547864706559320079,
+		];
+		for x in expect {
+			assert_eq!(rng.get() as u64, x);
+		}
+
+		let mut rng = CollatzWeyl128_64::new_two(0x0123456789abcdef_fedcba9876543210, 0xdeadbeefcafef00c);
+		let expect = [
+			3479362042156103513,
+			14092163041299184845,
+			8067112324263135102,
+			4594477582022464100,
+			5701209254266326841,
+			6851415795875263257,
+			13675103419019284473,
+			2187949726536271998,
+			9620135172255769901,
+			6215059347443235184,
+		];
+		for x in expect {
+			assert_eq!(rng.get() as u64, x);
+		}
+	}
+
+	#[test]
+	fn test_128() {
+		let mut rng = CollatzWeyl128::new_one(0x9e3779b97f4a7c15f39cc0605cedc835);
+		let expect = [
+			210306068529402873165736369884012333109,
+			1039482429121024672270710382007378097,
+			283511926030415263977846301661850766273,
+			231400068614274098545387822128048323261,
+			60224040534324645637634274751121694640,
+			61749190501530164987237918130367292547,
+			280772405951104581997647552966594345725,
+			166399239440774588260241563028116254181,
+			90064925827677295606615509880839140867,
+			50245519437319876657110392250050340909,
+		];
+		for x in expect {
+			assert_eq!(rng.get(), x);
+		}
+
+		let mut rng = CollatzWeyl128::new_two(0x0123456789abcdef_fedcba9876543210, 0xdeadbeefcafef00d_0123456789abcdee);
+		let expect = [
+			338996733450450773072696276016792318208,
+			275695147957332870300943317677199945388,
+			320170530159716101178382917030254899253,
+			228539143349893144721529681335619098330,
+			59719209363225469146451730842839268976,
+			166550934238681274307570023333430817831,
+			98721657542140185365755353028724639990,
+			91834521661684477305722506247660585085,
+			46888648989079433675715262321265391388,
+			250985801467034490188245825360201774430,
+		];
+		for x in expect {
+			assert_eq!(rng.get(), x);
+		}
+	}
+
+	#[test]
+	fn test_bytes() {
+		use crate::RandomImpl;
+
+		let mut a = CollatzWeyl128::new_one(1);
+		let mut b = a.clone();
+
+		let mut bytes = [0u8; 32];
+		a.random_bytes(&mut bytes);
+		assert_eq!(bytes[..16], b.get().to_le_bytes());
+		assert_eq!(bytes[16..], b.get().to_le_bytes());
+
+		let mut a = CollatzWeyl128_64::new_one(1);
+		let mut b = a.clone();
+
+		a.random_bytes(&mut bytes);
+		assert_eq!(bytes[..16], b.get().to_le_bytes());
+		assert_eq!(bytes[16..], b.get().to_le_bytes());
+	}
+}
@@ -56,7 +56,7 @@ pub const fn dornd(rand: &mut (u8, u8, u8, u8), carry: &mut bool) {
 
 /// [8bit lagged fibonacci generator](https://en.wikipedia.org/wiki/Lagged_Fibonacci_generator),
 /// extracted from Elite's [source code](https://elite.bbcelite.com/cassette/main/subroutine/dornd.html).
-#[derive(Clone)]
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub struct FibLFG8 {
 	rand: (u8, u8, u8, u8),
 	carry: bool,
@@ -83,6 +83,18 @@ impl FibLFG8 {
 		Self::new_raw(f0, f1, m0, m1, false)
 	}
 
+	/// construct a new `FibLFG8`, returning [`crate::Error::ZeroSeed`] if
+	/// any byte of `seed` is `0`, instead of adjusting it like
+	/// [`Self::new()`].
+	#[inline]
+	pub const fn try_new(seed: u32) -> Result<Self, crate::Error> {
+		let [f1, m1, f0, m0] = seed.to_be_bytes();
+		if f0 == 0 || f1 == 0 || m0 == 0 || m1 == 0 {
+			return Err(crate::Error::ZeroSeed);
+		}
+		Ok(Self::new_raw(f0, f1, m0, m1, false))
+	}
+
 	#[inline]
 	pub const fn rand(&mut self) -> &mut (u8, u8, u8, u8) {
 		&mut self.rand
@@ -127,17 +139,39 @@ impl FibLFG8 {
 		dornd(&mut self.rand, &mut self.carry);
 		self.rand.1
 	}
+
+	/// like [`Self::get()`], but also returns `RAND+3`, which is the
+	/// previous value of `RAND+1`. this is the pair of bytes DORND leaves
+	/// in the `A` and `X` registers.
+	#[inline]
+	pub const fn get_all(&mut self) -> (u8, u8) {
+		dornd(&mut self.rand, &mut self.carry);
+		(self.rand.1, self.rand.3)
+	}
+
+	/// advance this generator `n` times, discarding the results.
+	#[inline]
+	pub const fn step_n(&mut self, n: u32) {
+		let mut i = 0;
+		while i < n {
+			dornd(&mut self.rand, &mut self.carry);
+			i += 1;
+		}
+	}
 }
 
 impl crate::RandomImpl for FibLFG8 {
 	#[inline]
 	fn random_u64(&mut self) -> u64 {
-		crate::common::u64_from_bytes(self)
+		crate::common::u32_compose_u64(self.random_u32(), self.random_u32())
 	}
 
 	#[inline]
 	fn random_u32(&mut self) -> u32 {
-		crate::common::u32_from_bytes(self)
+		crate::common::u16_compose_u32(
+			crate::common::u8_compose_u16(self.get(), self.get()),
+			crate::common::u8_compose_u16(self.get(), self.get()),
+		)
 	}
 
 	#[inline]
@@ -148,6 +182,12 @@ impl crate::RandomImpl for FibLFG8 {
 	}
 }
 
+impl crate::FromRandom for FibLFG8 {
+	fn from_random(random: &mut impl crate::Random) -> Self {
+		Self::new(random.random())
+	}
+}
+
 impl core::fmt::Debug for FibLFG8 {
 	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
 		write!(f, "FibLFG8")
@@ -156,7 +196,7 @@ impl core::fmt::Debug for FibLFG8 {
 
 #[cfg(test)]
 mod test {
-    use crate::FibLFG8;
+	use crate::FibLFG8;
 
 	#[test]
 	fn test_basic() {
@@ -170,6 +210,76 @@ mod test {
 		assert_eq!(rng.get(), 41);
 		assert_eq!(rng.get(), 234);
 	}
-}
 
+	#[test]
+	fn test_carry() {
+		// RAND = &F0, RAND+1 = &FF, RAND+2 = &7F, RAND+3 = &01, with C set.
+		// (RAND+1, RAND+3, C) after each call, stepped through by hand
+		// from the 6502 source.
+		let mut rng = FibLFG8::new_raw(0x7f, 0xf0, 0x01, 0xff, true);
+
+		let expect = [
+			(1, 255, true),
+			(1, 1, true),
+			(3, 1, false),
+			(4, 3, false),
+			(7, 4, false),
+			(12, 7, false),
+			(19, 12, false),
+			(31, 19, false),
+			(50, 31, false),
+			(82, 50, false),
+			(133, 82, false),
+			(215, 133, false),
+		];
+
+		for (a, x, c) in expect {
+			assert_eq!(rng.get_all(), (a, x));
+			assert_eq!(*rng.carry(), c);
+		}
+	}
 
+	#[test]
+	fn test_startup() {
+		// the seeds Elite assembles into RAND, `EQUD &34785349`, so RAND = &49,
+		// RAND+1 = &53, RAND+2 = &78, RAND+3 = &34, with C clear. the expected
+		// (RAND+1, RAND+3, C) come from a separate simulation of the 6502
+		// instructions, not from `dornd()`.
+		let mut rng = FibLFG8::new_raw(0x78, 0x49, 0x34, 0x53, false);
+
+		let expect = [
+			(136, 83, false),
+			(219, 136, false),
+			(99, 219, true),
+			(63, 99, true),
+			(162, 63, false),
+			(225, 162, false),
+			(132, 225, true),
+			(101, 132, true),
+			(233, 101, false),
+			(79, 233, true),
+			(56, 79, true),
+			(136, 56, false),
+		];
+
+		for (a, x, c) in expect {
+			assert_eq!(rng.get_all(), (a, x));
+			assert_eq!(*rng.carry(), c);
+		}
+	}
+
+	#[test]
+	fn test_step() {
+		let mut a = FibLFG8::new(0x0212c845);
+		let mut b = a;
+
+		a.step_n(1000);
+		for _ in 0..1000 {
+			b.get();
+		}
+
+		assert_eq!(a.rand(), b.rand());
+		assert_eq!(a.carry(), b.carry());
+		assert_eq!(a.get(), b.get());
+	}
+}
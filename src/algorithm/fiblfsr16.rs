@@ -1,14 +1,46 @@
 
 /// [16bit fibonacci linear-feedback shift register](https://en.wikipedia.org/wiki/Linear-feedback_shift_register#Fibonacci_LFSRs) psuedo-rng.
-#[derive(Clone)]
-pub struct FibLFSR16 {
+/// 
+/// `POLY` selects the taps of the register: the feedback bit is the parity
+/// of `lfsr & POLY`, which is shifted in from the top as the register is
+/// shifted right. bit `i` of `POLY` corresponds to the `x^(16 - i)` term of
+/// the feedback polynomial, so the default of `0x2d` (bits 0, 2, 3 and 5)
+/// is `x^16 + x^14 + x^13 + x^11 + 1`.
+/// 
+/// the `x^16` term, bit 0, must always be set, as otherwise the register
+/// would really be shorter than 16 bits. this is checked at compile time:
+/// 
+/// ```compile_fail
+/// # use prrng::FibLFSR16;
+/// let rng = FibLFSR16::<0x2c>::new_n(1);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct FibLFSR16<const POLY: u16 = 0x2d> {
 	bit: u16,
 	lfsr: u16,
 }
 
 impl FibLFSR16 {
+	/// construct a new `FibLFSR16`, with the default taps.
+	/// see [`Self::new_n()`] for a generic constructor method.
+	#[inline]
+	pub const fn new(seed: u16) -> Self {
+		Self::new_n(seed)
+	}
+
+	/// construct a new `FibLFSR16`, with the default taps, returning
+	/// [`crate::Error::ZeroSeed`] if `seed` is `0`.
+	/// see [`Self::try_new_n()`] for a generic constructor method.
+	#[inline]
+	pub const fn try_new(seed: u16) -> Result<Self, crate::Error> {
+		Self::try_new_n(seed)
+	}
+}
+
+impl<const POLY: u16> FibLFSR16<POLY> {
 	#[inline]
 	pub const fn new_raw(seed: u16) -> Self {
+		const { assert!(POLY & 1 != 0, "bit 0 of `POLY` must be set") };
 		Self {
 			bit: 0,
 			lfsr: seed,
@@ -16,20 +48,46 @@ impl FibLFSR16 {
 	}
 
 	#[inline]
-	pub const fn new(seed: u16) -> Self {
+	pub const fn new_n(seed: u16) -> Self {
 		let seed = crate::common::u16_or_1(seed);
 		Self::new_raw(seed)
 	}
-	
+
+	/// construct a new `FibLFSR16`, returning [`crate::Error::ZeroSeed`] if
+	/// `seed` is `0`, instead of adjusting it like [`Self::new_n()`].
 	#[inline]
-	pub const fn get(&mut self) -> u16 {
-		self.bit = ((self.lfsr) ^ (self.lfsr >> 2) ^ (self.lfsr >> 3) ^ (self.lfsr >> 5)) & 1;
+	pub const fn try_new_n(seed: u16) -> Result<Self, crate::Error> {
+		if seed == 0 {
+			return Err(crate::Error::ZeroSeed);
+		}
+		Ok(Self::new_raw(seed))
+	}
+
+	/// clock the register once, returning the new bit.
+	#[inline]
+	pub const fn get_bit(&mut self) -> bool {
+		self.bit = ((self.lfsr & POLY).count_ones() & 1) as u16;
 		self.lfsr = (self.lfsr >> 1) | (self.bit << 15);
+		self.bit != 0
+	}
+
+	/// clock the register 16 times, returning the 16 new bits.
+	/// 
+	/// previously, this clocked the register once, so that consecutive
+	/// outputs shared 15 bits. since the period of the register (`2^16 - 1`)
+	/// is coprime with 16, the period of this method's output is unchanged.
+	#[inline]
+	pub const fn get(&mut self) -> u16 {
+		let mut i = 0;
+		while i < 16 {
+			self.get_bit();
+			i += 1;
+		}
 		self.lfsr
 	}
 }
 
-impl crate::RandomImpl for FibLFSR16 {
+impl<const POLY: u16> crate::RandomImpl for FibLFSR16<POLY> {
 	#[inline]
 	fn random_u64(&mut self) -> u64 {
 		crate::common::u32_compose_u64(self.random_u32(), self.random_u32())
@@ -41,13 +99,144 @@ impl crate::RandomImpl for FibLFSR16 {
 	}
 
 	fn random_bytes(&mut self, dst: &mut [u8]) {
-		crate::common::bytes_from_u32(self, dst);
+		crate::common::bytes_from_u16(|| self.get(), dst);
 	}
 }
 
-impl core::fmt::Debug for FibLFSR16 {
+impl<const POLY: u16> crate::FromRandom for FibLFSR16<POLY> {
+	fn from_random(random: &mut impl crate::Random) -> Self {
+		Self::new_n(random.random())
+	}
+}
+
+impl<const POLY: u16> core::fmt::Debug for FibLFSR16<POLY> {
 	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
 		write!(f, "FibLFSR16")
 	}
 }
 
+
+
+#[cfg(test)]
+mod test {
+	use crate::FibLFSR16;
+
+	#[test]
+	fn test_bit() {
+		let mut a = FibLFSR16::new(0xace1);
+		let mut b = a;
+
+		let x = b.get();
+		let mut y = 0;
+		for _ in 0..16 {
+			y = (y >> 1) | ((a.get_bit() as u16) << 15);
+		}
+		assert_eq!(x, y);
+	}
+
+	#[test]
+	fn test_fresh() {
+		let mut rng = FibLFSR16::new(0xace1);
+
+		let mut shifted = 0;
+		let mut prev = rng.get();
+		for _ in 0..1024 {
+			let next = rng.get();
+			if next & 0x7fff == prev >> 1 {
+				shifted += 1;
+			}
+			prev = next;
+		}
+		assert!(shifted < 8);
+	}
+
+	#[test]
+	fn test_sanity() {
+		let mut rng = FibLFSR16::new(0xace1);
+
+		// monobit
+		let mut ones = 0;
+		for _ in 0..4096 {
+			ones += rng.get().count_ones();
+		}
+		assert!((31744..33792).contains(&ones));
+
+		// serial correlation
+		let n = 4096.0;
+		let (mut sx, mut sxx, mut sxy) = (0.0, 0.0, 0.0);
+		let mut prev = rng.get() as f64;
+		for _ in 0..4096 {
+			let x = rng.get() as f64;
+			sx += x;
+			sxx += x * x;
+			sxy += prev * x;
+			prev = x;
+		}
+		let mean = sx / n;
+		let r = (sxy / n - mean * mean) / (sxx / n - mean * mean);
+		assert!(r.abs() < 0.05, "{r}");
+	}
+
+	#[test]
+	fn test_zero() {
+		let mut rng = FibLFSR16::new(0);
+		for _ in 0..16 {
+			assert_ne!(rng.get(), 0);
+		}
+
+		let mut rng = FibLFSR16::<0x2d>::new_raw(0);
+		assert_eq!(rng.get(), 0);
+	}
+
+	#[test]
+	fn test_default() {
+		// the taps used before `POLY` was introduced
+		let mut rng = FibLFSR16::new(0xace1);
+		let mut lfsr = 0xace1u16;
+		for _ in 0..1024 {
+			let bit = (lfsr ^ (lfsr >> 2) ^ (lfsr >> 3) ^ (lfsr >> 5)) & 1;
+			lfsr = (lfsr >> 1) | (bit << 15);
+			assert_eq!(rng.get_bit(), bit != 0);
+		}
+	}
+
+	#[test]
+	fn test_poly() {
+		// x^16 + x^15 + x^13 + x^4 + 1
+		let mut rng = FibLFSR16::<0x100b>::new_n(1);
+		let first = rng.get();
+		let mut period = 1;
+		while rng.get() != first {
+			period += 1;
+		}
+		assert_eq!(period, 65535);
+	}
+
+	#[test]
+	fn test_period() {
+		let mut rng = FibLFSR16::new(1);
+		let first = rng.get();
+		let mut period = 1;
+		while rng.get() != first {
+			period += 1;
+		}
+		assert_eq!(period, 65535);
+	}
+
+	#[test]
+	fn test_bytes() {
+		use crate::RandomImpl;
+
+		let mut a = FibLFSR16::new(1);
+		let mut b = a;
+
+		let mut bytes = [0u8; 3];
+		a.random_bytes(&mut bytes);
+
+		let [x0, x1] = b.get().to_le_bytes();
+		let [y0, _] = b.get().to_le_bytes();
+		assert_eq!(bytes, [x0, x1, y0]);
+
+		assert_eq!(a.get(), b.get());
+	}
+}
@@ -4,32 +4,124 @@
 //! 
 //! the output of a LCG follows this formula: `(seed * A + C) % M`.
 //! performance-wise, this is excellent if multiplication and modular
-//! division is fast. alternatively, if `M` is one less than a power of
-//! 2, then the modulus can be optimized into a simple bitwise `&`.
+//! division is fast. alternatively, if `M` is a power of 2, then the
+//! modulus can be optimized into a simple bitwise `&` with `M - 1`, which
+//! is selected at compile time.
 //! given well selected parameters, an LCG can also generate relatively
 //! high quality values.
 //! 
 //! security-wise, you should never use an LCG for unpredictable numbers.
 //! 
+//! a modulus `M` of `0` is treated as `2^width` (for example, `2^64` for
+//! [`Lcg64`]), where the modulus is skipped entirely in favor of wrapping
+//! arithmetic. this is selected at compile time, and has no runtime cost.
+//! 
+//! if `M` is neither `0` nor a power of 2, `seed * A + C` is computed in the next wider
+//! integer type before being reduced, so it never overflows. [`Lcg128`] has
+//! no wider type, and wraps at `2^128` before being reduced.
+//! 
 //! this module packages up LCGs of different bit sizes, with associated
 //! constants representing these different parameters.
+//! 
+//! ## choosing a preset
+//! 
+//! - for new code, prefer [`LcgSteele64`] or [`LcgSteele128`]. their
+//!   multipliers were selected for good spectral properties, and their
+//!   power of 2 modulus is free.
+//! - [`MINSTD`], [`Mmix`], [`JavaRandom`] and [`VisualBasic6`] exist to
+//!   reproduce the output of other software.
+//! - [`RANDU`] is only useful as a demonstration of a bad generator.
+//! 
+//! as with any power of 2 modulus LCG, the low bits of the output have
+//! short periods; prefer the high bits when narrowing.
 
 /// 8 bit linear congruential generator. see [module level documenation](self).
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub struct Lcg8<const A: u8, const C: u8, const M: u8> {
 	seed: u8,
 }
 
 impl<const A: u8, const C: u8, const M: u8,> Lcg8<A, C, M> {
+	/// construct a new `Lcg8`, without checking `seed`.
+	/// 
+	/// if `C` is `0`, a seed of `0` (or any multiple of `M`) will cause
+	/// this generator to only emit `0`s. see [`Self::new()`] for a
+	/// constructor that accounts for this.
 	#[inline]
-	pub const fn new(seed: u8) -> Self {
+	pub const fn new_raw(seed: u8) -> Self {
 		Self {
 			seed,
 		}
 	}
 
+	/// construct a new `Lcg8`.
+	/// 
+	/// if `C` is `0`, `seed` is reduced modulo `M`, then set to `1` if it
+	/// is `0`. otherwise, `seed` is used as is. see [`Self::new_raw()`] for
+	/// a constructor that does not do this.
+	#[inline]
+	pub const fn new(seed: u8) -> Self {
+		if C == 0 {
+			Self::new_raw(crate::common::u8_or_1(Self::reduce(seed)))
+		} else {
+			Self::new_raw(seed)
+		}
+	}
+
+	/// construct a new `Lcg8`, returning [`crate::Error::SeedOutOfRange`] if
+	/// `seed` is not less than `M`, or [`crate::Error::ZeroSeed`] if `C`
+	/// and `seed` are both `0`. see [`Self::new()`] for a constructor that
+	/// accepts any seed.
+	#[inline]
+	pub const fn try_new(seed: u8) -> Result<Self, crate::Error> {
+		if M != 0 && seed >= M {
+			return Err(crate::Error::SeedOutOfRange);
+		}
+		if C == 0 && seed == 0 {
+			return Err(crate::Error::ZeroSeed);
+		}
+		Ok(Self::new_raw(seed))
+	}
+
+	#[inline(always)]
+	const fn reduce(x: u8) -> u8 {
+		if M == 0 {
+			x
+		} else if M.is_power_of_two() {
+			x & (M - 1)
+		} else {
+			x % M
+		}
+	}
+
 	#[inline]
 	pub const fn get(&mut self) -> u8 {
-		self.seed = self.seed.wrapping_mul(A).wrapping_add(C) % M;
+		self.seed = if M == 0 || M.is_power_of_two() {
+			// the low bits of a wrapping product are exact
+			Self::reduce(self.seed.wrapping_mul(A).wrapping_add(C))
+		} else {
+			((self.seed as u16 * A as u16 + C as u16) % M as u16) as u8
+		};
+		self.seed
+	}
+
+	/// the current seed. the next call to [`Self::get()`] is derived from
+	/// this value.
+	#[inline]
+	pub const fn seed(&self) -> u8 {
+		self.seed
+	}
+
+	/// replace the current seed, without checking it.
+	/// see [`Self::new_raw()`].
+	#[inline]
+	pub const fn set_seed(&mut self, seed: u8) {
+		self.seed = seed;
+	}
+
+	/// consume this generator, returning the current seed.
+	#[inline]
+	pub const fn into_seed(self) -> u8 {
 		self.seed
 	}
 }
@@ -37,12 +129,15 @@ impl<const A: u8, const C: u8, const M: u8,> Lcg8<A, C, M> {
 impl<const A: u8, const C: u8, const M: u8> crate::RandomImpl for Lcg8<A, C, M> {
 	#[inline]
 	fn random_u64(&mut self) -> u64 {
-		crate::common::u64_from_bytes(self)
+		crate::common::u32_compose_u64(self.random_u32(), self.random_u32())
 	}
 
 	#[inline]
 	fn random_u32(&mut self) -> u32 {
-		crate::common::u32_from_bytes(self)
+		crate::common::u16_compose_u32(
+			crate::common::u8_compose_u16(self.get(), self.get()),
+			crate::common::u8_compose_u16(self.get(), self.get()),
+		)
 	}
 
 	#[inline]
@@ -55,27 +150,98 @@ impl<const A: u8, const C: u8, const M: u8> crate::RandomImpl for Lcg8<A, C, M>
 
 impl<const A: u8, const C: u8, const M: u8> core::fmt::Debug for Lcg8<A, C, M> {
 	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-		write!(f, "Lcg8({}, {}, {}", A, C, M)
+		write!(f, "Lcg8({}, {}, {}, seed: {})", A, C, M, self.seed)
 	}
 }
 
 
 /// 16 bit linear congruential generator. see [module level documenation](self).
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub struct Lcg16<const A: u16, const C: u16, const M: u16> {
 	seed: u16,
 }
 
 impl<const A: u16, const C: u16, const M: u16> Lcg16<A, C, M> {
+	/// construct a new `Lcg16`, without checking `seed`.
+	/// 
+	/// if `C` is `0`, a seed of `0` (or any multiple of `M`) will cause
+	/// this generator to only emit `0`s. see [`Self::new()`] for a
+	/// constructor that accounts for this.
 	#[inline]
-	pub const fn new(seed: u16) -> Self {
+	pub const fn new_raw(seed: u16) -> Self {
 		Self {
 			seed,
 		}
 	}
 
+	/// construct a new `Lcg16`.
+	/// 
+	/// if `C` is `0`, `seed` is reduced modulo `M`, then set to `1` if it
+	/// is `0`. otherwise, `seed` is used as is. see [`Self::new_raw()`] for
+	/// a constructor that does not do this.
+	#[inline]
+	pub const fn new(seed: u16) -> Self {
+		if C == 0 {
+			Self::new_raw(crate::common::u16_or_1(Self::reduce(seed)))
+		} else {
+			Self::new_raw(seed)
+		}
+	}
+
+	/// construct a new `Lcg16`, returning [`crate::Error::SeedOutOfRange`] if
+	/// `seed` is not less than `M`, or [`crate::Error::ZeroSeed`] if `C`
+	/// and `seed` are both `0`. see [`Self::new()`] for a constructor that
+	/// accepts any seed.
+	#[inline]
+	pub const fn try_new(seed: u16) -> Result<Self, crate::Error> {
+		if M != 0 && seed >= M {
+			return Err(crate::Error::SeedOutOfRange);
+		}
+		if C == 0 && seed == 0 {
+			return Err(crate::Error::ZeroSeed);
+		}
+		Ok(Self::new_raw(seed))
+	}
+
+	#[inline(always)]
+	const fn reduce(x: u16) -> u16 {
+		if M == 0 {
+			x
+		} else if M.is_power_of_two() {
+			x & (M - 1)
+		} else {
+			x % M
+		}
+	}
+
 	#[inline]
 	pub const fn get(&mut self) -> u16 {
-		self.seed = self.seed.wrapping_mul(A).wrapping_add(C) % M;
+		self.seed = if M == 0 || M.is_power_of_two() {
+			// the low bits of a wrapping product are exact
+			Self::reduce(self.seed.wrapping_mul(A).wrapping_add(C))
+		} else {
+			((self.seed as u32 * A as u32 + C as u32) % M as u32) as u16
+		};
+		self.seed
+	}
+
+	/// the current seed. the next call to [`Self::get()`] is derived from
+	/// this value.
+	#[inline]
+	pub const fn seed(&self) -> u16 {
+		self.seed
+	}
+
+	/// replace the current seed, without checking it.
+	/// see [`Self::new_raw()`].
+	#[inline]
+	pub const fn set_seed(&mut self, seed: u16) {
+		self.seed = seed;
+	}
+
+	/// consume this generator, returning the current seed.
+	#[inline]
+	pub const fn into_seed(self) -> u16 {
 		self.seed
 	}
 }
@@ -92,32 +258,103 @@ impl<const A: u16, const C: u16, const M: u16> crate::RandomImpl for Lcg16<A, C,
 	}
 
 	fn random_bytes(&mut self, dst: &mut [u8]) {
-		crate::common::bytes_from_u32(self, dst);
+		crate::common::bytes_from_u16(|| self.get(), dst);
 	}
 }
 
 impl<const A: u16, const C: u16, const M: u16> core::fmt::Debug for Lcg16<A, C, M> {
 	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-		write!(f, "Lcg16({}, {}, {}", A, C, M)
+		write!(f, "Lcg16({}, {}, {}, seed: {})", A, C, M, self.seed)
 	}
 }
 
 /// 32 bit linear congruential generator. see [module level documenation](self).
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub struct Lcg32<const A: u32, const C: u32, const M: u32> {
 	seed: u32,
 }
 
 impl<const A: u32, const C: u32, const M: u32> Lcg32<A, C, M> {
+	/// construct a new `Lcg32`, without checking `seed`.
+	/// 
+	/// if `C` is `0`, a seed of `0` (or any multiple of `M`) will cause
+	/// this generator to only emit `0`s. see [`Self::new()`] for a
+	/// constructor that accounts for this.
 	#[inline]
-	pub const fn new(seed: u32) -> Self {
+	pub const fn new_raw(seed: u32) -> Self {
 		Self {
 			seed,
 		}
 	}
 
+	/// construct a new `Lcg32`.
+	/// 
+	/// if `C` is `0`, `seed` is reduced modulo `M`, then set to `1` if it
+	/// is `0`. otherwise, `seed` is used as is. see [`Self::new_raw()`] for
+	/// a constructor that does not do this.
+	#[inline]
+	pub const fn new(seed: u32) -> Self {
+		if C == 0 {
+			Self::new_raw(crate::common::u32_or_1(Self::reduce(seed)))
+		} else {
+			Self::new_raw(seed)
+		}
+	}
+
+	/// construct a new `Lcg32`, returning [`crate::Error::SeedOutOfRange`] if
+	/// `seed` is not less than `M`, or [`crate::Error::ZeroSeed`] if `C`
+	/// and `seed` are both `0`. see [`Self::new()`] for a constructor that
+	/// accepts any seed.
+	#[inline]
+	pub const fn try_new(seed: u32) -> Result<Self, crate::Error> {
+		if M != 0 && seed >= M {
+			return Err(crate::Error::SeedOutOfRange);
+		}
+		if C == 0 && seed == 0 {
+			return Err(crate::Error::ZeroSeed);
+		}
+		Ok(Self::new_raw(seed))
+	}
+
+	#[inline(always)]
+	const fn reduce(x: u32) -> u32 {
+		if M == 0 {
+			x
+		} else if M.is_power_of_two() {
+			x & (M - 1)
+		} else {
+			x % M
+		}
+	}
+
 	#[inline]
 	pub const fn get(&mut self) -> u32 {
-		self.seed = self.seed.wrapping_mul(A).wrapping_add(C) % M;
+		self.seed = if M == 0 || M.is_power_of_two() {
+			// the low bits of a wrapping product are exact
+			Self::reduce(self.seed.wrapping_mul(A).wrapping_add(C))
+		} else {
+			((self.seed as u64 * A as u64 + C as u64) % M as u64) as u32
+		};
+		self.seed
+	}
+
+	/// the current seed. the next call to [`Self::get()`] is derived from
+	/// this value.
+	#[inline]
+	pub const fn seed(&self) -> u32 {
+		self.seed
+	}
+
+	/// replace the current seed, without checking it.
+	/// see [`Self::new_raw()`].
+	#[inline]
+	pub const fn set_seed(&mut self, seed: u32) {
+		self.seed = seed;
+	}
+
+	/// consume this generator, returning the current seed.
+	#[inline]
+	pub const fn into_seed(self) -> u32 {
 		self.seed
 	}
 }
@@ -140,26 +377,235 @@ impl<const A: u32, const C: u32, const M: u32> crate::RandomImpl for Lcg32<A, C,
 
 impl<const A: u32, const C: u32, const M: u32> core::fmt::Debug for Lcg32<A, C, M> {
 	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-		write!(f, "Lcg32({}, {}, {}", A, C, M)
+		write!(f, "Lcg32({}, {}, {}, seed: {})", A, C, M, self.seed)
+	}
+}
+
+/// 32 bit linear congruential generator, stepped with
+/// [schrage's method](https://doi.org/10.1145/355826.355828).
+/// see [module level documenation](self).
+/// 
+/// [`Lcg32`] computes `seed * A` in a `u64` when `M` is not a power of 2,
+/// which is slow on targets without a fast 64 bit multiply. schrage's method
+/// splits `M` into `A * (M / A) + M % A`, and computes the same result using
+/// only `u32` arithmetic, at the cost of a division and a modulo by a
+/// constant.
+/// 
+/// this produces the same values as the equivalent [`Lcg32`], but only
+/// works if `M % A < M / A`. `A` and `M` must also not be `0`, and `C` must
+/// be less than `M`. these are checked at compile time:
+/// 
+/// ```
+/// # use prrng::lcg::LcgSchrage32;
+/// // MINSTD
+/// let mut rng = LcgSchrage32::<48271, 0, 2147483647>::new(1);
+/// assert_eq!(rng.get(), 48271);
+/// assert_eq!(rng.get(), 182605794);
+/// ```
+/// 
+/// ```compile_fail
+/// # use prrng::lcg::LcgSchrage32;
+/// // Fishman's multiplier is too large
+/// let rng = LcgSchrage32::<950706376, 0, 2147483647>::new(1);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct LcgSchrage32<const A: u32, const C: u32, const M: u32> {
+	seed: u32,
+}
+
+impl<const A: u32, const C: u32, const M: u32> LcgSchrage32<A, C, M> {
+	const Q: u32 = M / A;
+	const R: u32 = M % A;
+
+	/// construct a new `LcgSchrage32`, without checking `seed`.
+	/// 
+	/// `seed` must be less than `M`. if `C` is `0`, a seed of `0` will cause
+	/// this generator to only emit `0`s. see [`Self::new()`] for a
+	/// constructor that accounts for this.
+	#[inline]
+	pub const fn new_raw(seed: u32) -> Self {
+		const {
+			assert!(A != 0, "`A` must not be 0");
+			assert!(M != 0, "`M` must not be 0");
+			assert!(C < M, "`C` must be less than `M`");
+			assert!(M % A < M / A, "`M % A` must be less than `M / A`");
+		};
+		Self {
+			seed,
+		}
+	}
+
+	/// construct a new `LcgSchrage32`.
+	/// 
+	/// `seed` is reduced modulo `M`, then, if `C` is `0`, set to `1` if it is
+	/// `0`. see [`Self::new_raw()`] for a constructor that does not do this.
+	#[inline]
+	pub const fn new(seed: u32) -> Self {
+		if C == 0 {
+			Self::new_raw(crate::common::u32_or_1(seed % M))
+		} else {
+			Self::new_raw(seed % M)
+		}
+	}
+
+	/// construct a new `LcgSchrage32`, returning
+	/// [`crate::Error::SeedOutOfRange`] if `seed` is not less than `M`, or
+	/// [`crate::Error::ZeroSeed`] if `C` and `seed` are both `0`. see
+	/// [`Self::new()`] for a constructor that accepts any seed.
+	#[inline]
+	pub const fn try_new(seed: u32) -> Result<Self, crate::Error> {
+		if seed >= M {
+			return Err(crate::Error::SeedOutOfRange);
+		}
+		if C == 0 && seed == 0 {
+			return Err(crate::Error::ZeroSeed);
+		}
+		Ok(Self::new_raw(seed))
+	}
+
+	#[inline]
+	pub const fn get(&mut self) -> u32 {
+		// seed * A = A * (seed % Q) - R * (seed / Q)  (mod M)
+		// both products are less than `M`, since `R < Q`
+		let a = A * (self.seed % Self::Q);
+		let b = Self::R * (self.seed / Self::Q);
+		let x = if a >= b { a - b } else { a + (M - b) };
+
+		// x + C, without overflowing
+		self.seed = if x >= M - C { x - (M - C) } else { x + C };
+		self.seed
+	}
+
+	/// the current seed. the next call to [`Self::get()`] is derived from
+	/// this value.
+	#[inline]
+	pub const fn seed(&self) -> u32 {
+		self.seed
+	}
+
+	/// replace the current seed, without checking it.
+	/// see [`Self::new_raw()`].
+	#[inline]
+	pub const fn set_seed(&mut self, seed: u32) {
+		self.seed = seed;
+	}
+
+	/// consume this generator, returning the current seed.
+	#[inline]
+	pub const fn into_seed(self) -> u32 {
+		self.seed
+	}
+}
+
+impl<const A: u32, const C: u32, const M: u32> crate::RandomImpl for LcgSchrage32<A, C, M> {
+	#[inline]
+	fn random_u64(&mut self) -> u64 {
+		crate::common::u32_compose_u64(self.random_u32(), self.random_u32())
+	}
+
+	#[inline]
+	fn random_u32(&mut self) -> u32 {
+		self.get()
+	}
+
+	fn random_bytes(&mut self, dst: &mut [u8]) {
+		crate::common::bytes_from_u32(self, dst);
+	}
+}
+
+impl<const A: u32, const C: u32, const M: u32> core::fmt::Debug for LcgSchrage32<A, C, M> {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		write!(f, "LcgSchrage32({}, {}, {}, seed: {})", A, C, M, self.seed)
 	}
 }
 
 /// 64 bit linear congruential generator. see [module level documenation](self).
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub struct Lcg64<const A: u64, const C: u64, const M: u64> {
 	seed: u64,
 }
 
 impl<const A: u64, const C: u64, const M: u64> Lcg64<A, C, M> {
+	/// construct a new `Lcg64`, without checking `seed`.
+	/// 
+	/// if `C` is `0`, a seed of `0` (or any multiple of `M`) will cause
+	/// this generator to only emit `0`s. see [`Self::new()`] for a
+	/// constructor that accounts for this.
 	#[inline]
-	pub const fn new(seed: u64) -> Self {
+	pub const fn new_raw(seed: u64) -> Self {
 		Self {
 			seed,
 		}
 	}
 
+	/// construct a new `Lcg64`.
+	/// 
+	/// if `C` is `0`, `seed` is reduced modulo `M`, then set to `1` if it
+	/// is `0`. otherwise, `seed` is used as is. see [`Self::new_raw()`] for
+	/// a constructor that does not do this.
+	#[inline]
+	pub const fn new(seed: u64) -> Self {
+		if C == 0 {
+			Self::new_raw(crate::common::u64_or_1(Self::reduce(seed)))
+		} else {
+			Self::new_raw(seed)
+		}
+	}
+
+	/// construct a new `Lcg64`, returning [`crate::Error::SeedOutOfRange`] if
+	/// `seed` is not less than `M`, or [`crate::Error::ZeroSeed`] if `C`
+	/// and `seed` are both `0`. see [`Self::new()`] for a constructor that
+	/// accepts any seed.
+	#[inline]
+	pub const fn try_new(seed: u64) -> Result<Self, crate::Error> {
+		if M != 0 && seed >= M {
+			return Err(crate::Error::SeedOutOfRange);
+		}
+		if C == 0 && seed == 0 {
+			return Err(crate::Error::ZeroSeed);
+		}
+		Ok(Self::new_raw(seed))
+	}
+
+	#[inline(always)]
+	const fn reduce(x: u64) -> u64 {
+		if M == 0 {
+			x
+		} else if M.is_power_of_two() {
+			x & (M - 1)
+		} else {
+			x % M
+		}
+	}
+
 	#[inline]
 	pub const fn get(&mut self) -> u64 {
-		self.seed = self.seed.wrapping_mul(A).wrapping_add(C) % M;
+		self.seed = if M == 0 || M.is_power_of_two() {
+			// the low bits of a wrapping product are exact
+			Self::reduce(self.seed.wrapping_mul(A).wrapping_add(C))
+		} else {
+			((self.seed as u128 * A as u128 + C as u128) % M as u128) as u64
+		};
+		self.seed
+	}
+
+	/// the current seed. the next call to [`Self::get()`] is derived from
+	/// this value.
+	#[inline]
+	pub const fn seed(&self) -> u64 {
+		self.seed
+	}
+
+	/// replace the current seed, without checking it.
+	/// see [`Self::new_raw()`].
+	#[inline]
+	pub const fn set_seed(&mut self, seed: u64) {
+		self.seed = seed;
+	}
+
+	/// consume this generator, returning the current seed.
+	#[inline]
+	pub const fn into_seed(self) -> u64 {
 		self.seed
 	}
 }
@@ -182,26 +628,92 @@ impl<const A: u64, const C: u64, const M: u64> crate::RandomImpl for Lcg64<A, C,
 
 impl<const A: u64, const C: u64, const M: u64> core::fmt::Debug for Lcg64<A, C, M> {
 	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-		write!(f, "Lcg64({}, {}, {}", A, C, M)
+		write!(f, "Lcg64({}, {}, {}, seed: {})", A, C, M, self.seed)
 	}
 }
 
-/// 64 bit linear congruential generator. see [module level documenation](self).
+/// 128 bit linear congruential generator. see [module level documenation](self).
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub struct Lcg128<const A: u128, const C: u128, const M: u128> {
 	seed: u128,
 }
 
 impl<const A: u128, const C: u128, const M: u128> Lcg128<A, C, M> {
+	/// construct a new `Lcg128`, without checking `seed`.
+	/// 
+	/// if `C` is `0`, a seed of `0` (or any multiple of `M`) will cause
+	/// this generator to only emit `0`s. see [`Self::new()`] for a
+	/// constructor that accounts for this.
 	#[inline]
-	pub const fn new(seed: u128) -> Self {
+	pub const fn new_raw(seed: u128) -> Self {
 		Self {
 			seed,
 		}
 	}
 
+	/// construct a new `Lcg128`.
+	/// 
+	/// if `C` is `0`, `seed` is reduced modulo `M`, then set to `1` if it
+	/// is `0`. otherwise, `seed` is used as is. see [`Self::new_raw()`] for
+	/// a constructor that does not do this.
+	#[inline]
+	pub const fn new(seed: u128) -> Self {
+		if C == 0 {
+			Self::new_raw(crate::common::u128_or_1(Self::reduce(seed)))
+		} else {
+			Self::new_raw(seed)
+		}
+	}
+
+	/// construct a new `Lcg128`, returning [`crate::Error::SeedOutOfRange`] if
+	/// `seed` is not less than `M`, or [`crate::Error::ZeroSeed`] if `C`
+	/// and `seed` are both `0`. see [`Self::new()`] for a constructor that
+	/// accepts any seed.
+	#[inline]
+	pub const fn try_new(seed: u128) -> Result<Self, crate::Error> {
+		if M != 0 && seed >= M {
+			return Err(crate::Error::SeedOutOfRange);
+		}
+		if C == 0 && seed == 0 {
+			return Err(crate::Error::ZeroSeed);
+		}
+		Ok(Self::new_raw(seed))
+	}
+
+	#[inline(always)]
+	const fn reduce(x: u128) -> u128 {
+		if M == 0 {
+			x
+		} else if M.is_power_of_two() {
+			x & (M - 1)
+		} else {
+			x % M
+		}
+	}
+
 	#[inline]
 	pub const fn get(&mut self) -> u128 {
-		self.seed = self.seed.wrapping_mul(A).wrapping_add(C) % M;
+		self.seed = Self::reduce(self.seed.wrapping_mul(A).wrapping_add(C));
+		self.seed
+	}
+
+	/// the current seed. the next call to [`Self::get()`] is derived from
+	/// this value.
+	#[inline]
+	pub const fn seed(&self) -> u128 {
+		self.seed
+	}
+
+	/// replace the current seed, without checking it.
+	/// see [`Self::new_raw()`].
+	#[inline]
+	pub const fn set_seed(&mut self, seed: u128) {
+		self.seed = seed;
+	}
+
+	/// consume this generator, returning the current seed.
+	#[inline]
+	pub const fn into_seed(self) -> u128 {
 		self.seed
 	}
 }
@@ -218,13 +730,13 @@ impl<const A: u128, const C: u128, const M: u128> crate::RandomImpl for Lcg128<A
 	}
 
 	fn random_bytes(&mut self, dst: &mut [u8]) {
-		crate::common::bytes_from_u64(self, dst);
+		crate::common::bytes_from_u128(|| self.get(), dst);
 	}
 }
 
 impl<const A: u128, const C: u128, const M: u128> core::fmt::Debug for Lcg128<A, C, M> {
 	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-		write!(f, "Lcg128({}, {}, {}", A, C, M)
+		write!(f, "Lcg128({}, {}, {}, seed: {})", A, C, M, self.seed)
 	}
 }
 
@@ -239,7 +751,7 @@ pub type Lecuyer16 = Lcg16<17364, 0, 65521>;
 /// assert_eq!(rng.get(), 282475249);
 /// assert_eq!(rng.get(), 1622650073);
 /// ```
-pub type MINSTD88 = Lcg64<16807, 0, 2147483647>;
+pub type MINSTD88 = Lcg32<16807, 0, 2147483647>;
 
 /// ```
 /// # use prrng::lcg::MINSTD;
@@ -248,10 +760,35 @@ pub type MINSTD88 = Lcg64<16807, 0, 2147483647>;
 /// assert_eq!(rng.get(), 182605794);
 /// assert_eq!(rng.get(), 1291394886);
 /// ```
-pub type MINSTD = Lcg64<48271, 0, 2147483647>;
+/// 
+/// as a multiplicative generator, a seed of `0` would lock this generator
+/// at `0`. [`Lcg32::new()`] guards against this:
+/// 
+/// ```
+/// # use prrng::lcg::MINSTD;
+/// let mut rng = MINSTD::new_raw(0);
+/// assert_eq!(rng.get(), 0);
+/// assert_eq!(rng.get(), 0);
+/// 
+/// let mut rng = MINSTD::new(0);
+/// assert_eq!(rng.get(), 48271);
+/// 
+/// let mut rng = MINSTD::new(2147483647);
+/// assert_eq!(rng.get(), 48271);
+/// ```
+pub type MINSTD = Lcg32<48271, 0, 2147483647>;
 
 // https://www.jstor.org/stable/2008698
-// https://oeis.org/A384546
+/// ```
+/// # use prrng::lcg::Fishman;
+/// let mut rng = Fishman::new(1);
+/// // https://oeis.org/A384546
+/// assert_eq!(rng.get(), 950706376);
+/// assert_eq!(rng.get(), 129027171);
+/// assert_eq!(rng.get(), 1728259899);
+/// assert_eq!(rng.get(), 365181143);
+/// assert_eq!(rng.get(), 1966843080);
+/// ```
 pub type Fishman = Lcg32<950706376, 0, 2147483647>;
 
 /// based on the RANDF [`LCG`](`Lcg32`) constants.
@@ -288,7 +825,241 @@ pub type RANF = Lcg64<44485709377909, 0, 0x1000000000000>;
 /// ```
 pub type RANDU = Lcg32<65539, 0, 0x80000000>;
 
-pub type VisualBasic6 = Lcg32<0x43fd43fd, 0xc39ec3, 0xffffff>;
+/// Knuth's MMIX [`LCG`](`Lcg64`) constants, with a modulus of `2^64`.
+/// 
+/// ```
+/// # use prrng::lcg::Mmix;
+/// let mut rng = Mmix::new(1);
+/// assert_eq!(rng.get(), 7806831264735756412);
+/// assert_eq!(rng.get(), 9396908728118811419);
+/// assert_eq!(rng.get(), 11960119808228829710);
+/// assert_eq!(rng.get(), 7062582979898595269);
+/// ```
+pub type Mmix = Lcg64<6364136223846793005, 1442695040888963407, 0>;
+
+/// the [`LCG`](`Lcg64`) constants used by Java's `java.util.Random`, with a
+/// modulus of `2^48`.
+/// 
+/// this only reproduces the internal state. Java scrambles its seed with
+/// `seed ^ 0x5DEECE66D` when constructed, and returns `seed >> 16` from
+/// `nextInt()`.
+/// 
+/// ```
+/// # use prrng::lcg::JavaRandom;
+/// // `new Random(42)`
+/// let mut rng = JavaRandom::new(42 ^ 0x5DEECE66D);
+/// 
+/// // `nextInt()`
+/// assert_eq!((rng.get() >> 16) as i32, -1170105035);
+/// ```
+pub type JavaRandom = Lcg64<0x5DEECE66D, 0xB, 0x1000000000000>;
+
+/// the [`LCG`](`Lcg32`) constants used by Visual Basic 6's `Rnd()`, with a
+/// modulus of `2^24`. `Rnd()` returns the state divided by `2^24`, and
+/// starts with a seed of `0x50000`.
+/// 
+/// ```
+/// # use prrng::lcg::VisualBasic6;
+/// let mut rng = VisualBasic6::new(0x50000);
+/// 
+/// assert_eq!(rng.get(), 11837123); // 0.7055475
+/// assert_eq!(rng.get(), 8949370); // 0.533424
+/// assert_eq!(rng.get(), 9722709); // 0.5795186
+/// assert_eq!(rng.get(), 4858052); // 0.2895625
+/// ```
+pub type VisualBasic6 = Lcg32<0x43fd43fd, 0xc39ec3, 0x1000000>;
+
+/// a [`VisualBasic6`] generator, with the starting seed used by `Rnd()`.
+/// 
+/// ```
+/// # use prrng::lcg::VISUAL_BASIC_6;
+/// let mut rng = VISUAL_BASIC_6;
+/// assert_eq!(rng.get(), 11837123);
+/// ```
+pub const VISUAL_BASIC_6: VisualBasic6 = VisualBasic6::new(0x50000);
+
+/// a [`LCG`](`Lcg64`) with a modulus of `2^64`, using a multiplier from
+/// Steele and Vigna's
+/// ["Computationally easy, spectrally good multipliers for congruential pseudorandom number generators"](https://arxiv.org/abs/2001.05304).
+/// 
+/// ```
+/// # use prrng::lcg::LcgSteele64;
+/// let mut rng = LcgSteele64::new(1);
+/// assert_eq!(rng.get(), 15074714826142052246);
+/// assert_eq!(rng.get(), 9434471399554972751);
+/// assert_eq!(rng.get(), 17765461988220738300);
+/// assert_eq!(rng.get(), 4584375959701616813);
+/// ```
+pub type LcgSteele64 = Lcg64<0xd1342543de82ef95, 1, 0>;
+
+/// a [`LCG`](`Lcg128`) with a modulus of `2^128`, using a multiplier from
+/// Steele and Vigna's
+/// ["Computationally easy, spectrally good multipliers for congruential pseudorandom number generators"](https://arxiv.org/abs/2001.05304).
+/// 
+/// ```
+/// # use prrng::lcg::LcgSteele128;
+/// let mut rng = LcgSteele128::new(1);
+/// assert_eq!(rng.get(), 291382399519485789170309121576895642646);
+/// assert_eq!(rng.get(), 291019338372889720580919441480921732559);
+/// assert_eq!(rng.get(), 150929156256991935535799592323339177468);
+/// assert_eq!(rng.get(), 318959681634938854976994963096442697133);
+/// ```
+pub type LcgSteele128 = Lcg128<0xdb36357734e34abb0050d0761fcdfc15, 1, 0>;
+
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn test_wrapping() {
+		let mut a = Lcg8::<5, 3, 0>::new(200);
+		let mut b = Lcg16::<0xfff1, 7, 0>::new(u16::MAX);
+		let mut c = Lcg32::<0xffff_fff1, 7, 0>::new(u32::MAX);
+		let mut d = Lcg128::<0xffff_ffff_ffff_ffff_ffff, 1, 0>::new(u128::MAX);
 
+		let (mut x8, mut x16, mut x32, mut x128) = (200u8, u16::MAX, u32::MAX, u128::MAX);
+		for _ in 0..64 {
+			x8 = x8.wrapping_mul(5).wrapping_add(3);
+			x16 = x16.wrapping_mul(0xfff1).wrapping_add(7);
+			x32 = x32.wrapping_mul(0xffff_fff1).wrapping_add(7);
+			x128 = x128.wrapping_mul(0xffff_ffff_ffff_ffff_ffff).wrapping_add(1);
 
+			assert_eq!(a.get(), x8);
+			assert_eq!(b.get(), x16);
+			assert_eq!(c.get(), x32);
+			assert_eq!(d.get(), x128);
+		}
+	}
+
+	#[test]
+	fn test_prime() {
+		// prime modulus presets are computed without overflowing
+		let mut a = Lecuyer8::new(1);
+		let mut b = Lecuyer16::new(1);
+		let mut c = MINSTD::new(1);
+		let mut d = Lcg64::<0x5851_f42d_4c95_7f2d, 0, 0xffff_ffff_ffff_ffc5>::new(u64::MAX - 100);
+		let (mut x8, mut x16, mut x32, mut x64) = (1u128, 1u128, 1u128, u64::MAX as u128 - 100);
+		for _ in 0..64 {
+			x8 = x8 * 55 % 251;
+			x16 = x16 * 17364 % 65521;
+			x32 = x32 * 48271 % 2147483647;
+			x64 = x64 * 0x5851_f42d_4c95_7f2d % 0xffff_ffff_ffff_ffc5;
+
+			assert_eq!(a.get() as u128, x8);
+			assert_eq!(b.get() as u128, x16);
+			assert_eq!(c.get() as u128, x32);
+			assert_eq!(d.get() as u128, x64);
+		}
+
+		// full period
+		let mut rng = Lecuyer8::new(1);
+		let mut period = 1;
+		while rng.get() != 1 {
+			period += 1;
+		}
+		assert_eq!(period, 250);
+	}
+
+	#[test]
+	fn test_schrage() {
+		fn lockstep<const A: u32, const C: u32, const M: u32>(seed: u32) {
+			let mut a = Lcg32::<A, C, M>::new(seed);
+			let mut b = LcgSchrage32::<A, C, M>::new(seed);
+			for _ in 0..10000 {
+				assert_eq!(a.get(), b.get());
+			}
+		}
+
+		for seed in [0, 1, 12345, 2147483646] {
+			lockstep::<48271, 0, 2147483647>(seed);
+			lockstep::<16807, 0, 2147483647>(seed);
+			lockstep::<40692, 0, 2147483399>(seed);
+			lockstep::<48271, 12345, 2147483647>(seed);
+			lockstep::<3, 4294967290, 4294967291>(seed);
+		}
+	}
+
+	#[test]
+	fn test_fishman() {
+		// https://oeis.org/A384546
+		let mut rng = Fishman::new(1);
+		for x in [950706376, 129027171, 1728259899, 365181143, 1966843080] {
+			assert_eq!(rng.get(), x);
+		}
+
+		let mut rng = Fishman::new(1);
+		let mut x = 1u64;
+		for _ in 0..1000 {
+			x = x * 950706376 % 2147483647;
+			assert_eq!(rng.get() as u64, x);
+		}
+	}
+
+	#[test]
+	fn test_zero_seed() {
+		let mut a = Lecuyer8::new(0);
+		let mut b = Lcg16::<5, 0, 0>::new(0);
+		let mut c = Fishman::new(0);
+		let mut d = RANF::new(0x1000000000000);
+		let mut e = Lcg128::<3, 0, 7>::new(14);
+
+		for _ in 0..16 {
+			assert_ne!(a.get(), 0);
+			assert_ne!(b.get(), 0);
+			assert_ne!(c.get(), 0);
+			assert_ne!(d.get(), 0);
+			assert_ne!(e.get(), 0);
+		}
+
+		let mut rng = MINSTD::new(0);
+		assert_ne!(rng.get(), 0);
+		let mut rng = MINSTD::new(2147483647);
+		assert_ne!(rng.get(), 0);
+		let mut rng = MINSTD::new_raw(0);
+		assert_eq!(rng.get(), 0);
+		assert_eq!(rng.get(), 0);
+
+		// additive generators are left alone
+		let mut rng = Mmix::new(0);
+		assert_eq!(rng.get(), 1442695040888963407);
+	}
+
+	#[test]
+	fn test_seed() {
+		let mut rng = MINSTD::new(1);
+		rng.get();
+		let saved = rng.seed();
+		let a = rng.get();
+
+		rng.set_seed(saved);
+		assert_eq!(rng.get(), a);
+		assert_eq!(rng.into_seed(), a);
 
+		let mut rng = Lcg8::<5, 3, 0>::new_raw(0);
+		rng.set_seed(200);
+		assert_eq!(rng.seed(), 200);
+		assert_eq!(rng.get(), 200u8.wrapping_mul(5).wrapping_add(3));
+	}
+
+	#[test]
+	fn test_bytes_u16() {
+		use crate::RandomImpl;
+
+		// bytes are each value in order, little-endian. this used to go
+		// through `random_u32`, which swapped each pair of values and used
+		// up a whole pair for an odd tail.
+		let mut a = Lcg16::<0xfff1, 7, 0>::new(1);
+		let mut b = Lcg16::<0xfff1, 7, 0>::new(1);
+
+		let mut bytes = [0u8; 5];
+		a.random_bytes(&mut bytes);
+
+		let [x0, x1] = b.get().to_le_bytes();
+		let [y0, y1] = b.get().to_le_bytes();
+		let [z0, _] = b.get().to_le_bytes();
+		assert_eq!(bytes, [x0, x1, y0, y1, z0]);
+
+		assert_eq!(a.get(), b.get());
+	}
+}
@@ -1,15 +1,24 @@
 
 pub mod chacha;
+pub mod chacharng;
 pub mod collatzweyl;
 pub mod fiblfg8;
 pub mod fiblfsr16;
 pub mod lcg;
 pub mod mtwister;
+pub mod pcg8;
+pub mod pcg16;
 pub mod pcg32;
+pub mod rc4;
+#[cfg(all(any(test, feature = "rdrand"), target_arch = "x86_64"))]
+pub mod rdrand;
 pub mod splitmix64;
+pub mod sponge;
 pub mod wichhill;
+pub mod xoroshiro64;
 pub mod xorshift32;
 pub mod xorshift64;
 pub mod xorshift128p;
 pub mod xoshiro256ss;
+pub mod xoshiro512ss;
 
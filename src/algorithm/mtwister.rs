@@ -12,7 +12,7 @@ const LOWER_MASK: u32 = 0x7fffffff;
 
 // https://www.math.sci.hiroshima-u.ac.jp/m-mat/MT/MT2002/emt19937ar.html
 // https://github.com/ESultanik/mtwister
-#[derive(Clone)]
+#[derive(Clone, PartialEq, Eq)]
 pub struct MTwister {
 	buf: [u32; STATE_N],
 	index: usize,
@@ -37,6 +37,64 @@ impl MTwister {
 			index: STATE_N,
 		}
 	}
+
+	/// construct a new `MTwister` from a key of any length. this is
+	/// `init_by_array()` from the reference implementation, which is also
+	/// how python's `random.seed()` seeds its generator from an integer.
+	/// 
+	/// ```
+	/// # use prrng::MTwister;
+	/// // the key used by the reference `mt19937ar.out`
+	/// let mut rng = MTwister::new_array(&[0x123, 0x234, 0x345, 0x456]);
+	/// assert_eq!(rng.get(), 1067595299);
+	/// assert_eq!(rng.get(), 955945823);
+	/// ```
+	/// 
+	/// ## panics
+	/// 
+	/// panics if `key` is empty.
+	#[track_caller]
+	pub const fn new_array(key: &[u32]) -> Self {
+		assert!(!key.is_empty(), "`key` must not be empty");
+
+		let mut this = Self::new(19650218);
+		let buf = &mut this.buf;
+
+		let mut i = 1;
+		let mut j = 0;
+		let mut k = if key.len() > STATE_N { key.len() } else { STATE_N };
+		while k > 0 {
+			buf[i] = (buf[i] ^ (buf[i - 1] ^ (buf[i - 1] >> 30)).wrapping_mul(1664525))
+				.wrapping_add(key[j])
+				.wrapping_add(j as u32);
+			i += 1;
+			j += 1;
+			if i >= STATE_N {
+				buf[0] = buf[STATE_N - 1];
+				i = 1;
+			}
+			if j >= key.len() {
+				j = 0;
+			}
+			k -= 1;
+		}
+
+		k = STATE_N - 1;
+		while k > 0 {
+			buf[i] = (buf[i] ^ (buf[i - 1] ^ (buf[i - 1] >> 30)).wrapping_mul(1566083941))
+				.wrapping_sub(i as u32);
+			i += 1;
+			if i >= STATE_N {
+				buf[0] = buf[STATE_N - 1];
+				i = 1;
+			}
+			k -= 1;
+		}
+
+		// makes sure the state is not all zeros
+		buf[0] = 0x80000000;
+		this
+	}
 	
 	pub const fn run(&mut self) {
 		let mut kk = 0;
@@ -86,6 +144,60 @@ impl MTwister {
 		self.index += 1;
 		Self::temper(ret)
 	}
+
+	/// fill `dst` with the next `dst.len()` values, the same as calling
+	/// [`Self::get()`] `dst.len()` times.
+	/// 
+	/// this copies whole runs of tempered values at once, instead of
+	/// checking the index for every value.
+	/// 
+	/// ```
+	/// # use prrng::MTwister;
+	/// let mut rng = MTwister::new(5489);
+	/// 
+	/// let mut dst = [0; 1000];
+	/// rng.fill(&mut dst);
+	/// assert_eq!(dst[..2], [3499211612, 581869302]);
+	/// ```
+	pub const fn fill(&mut self, dst: &mut [u32]) {
+		let mut i = 0;
+		while i < dst.len() {
+			if self.index >= STATE_N {
+				self.run();
+			}
+			while i < dst.len() && self.index < STATE_N {
+				dst[i] = Self::temper(self.buf[self.index]);
+				self.index += 1;
+				i += 1;
+			}
+		}
+	}
+
+	/// fill `dst` with the next block of values. this is the same as
+	/// [`Self::fill()`], and is a single run of the recurrence if the
+	/// previous block was used up exactly.
+	#[inline]
+	pub const fn fill_block(&mut self, dst: &mut [u32; STATE_N]) {
+		self.fill(dst);
+	}
+
+	/// returns a value in `[0, 1)` with 53 bits of resolution, consuming two
+	/// values. this is `genrand_res53()` from the reference implementation.
+	/// 
+	/// this is different from [`crate::Random::random_f64()`], which will
+	/// produce different values.
+	/// 
+	/// ```
+	/// # use prrng::MTwister;
+	/// let mut rng = MTwister::new(5489);
+	/// assert_eq!(rng.get_f64_res53(), 0.8147236863931789);
+	/// assert_eq!(rng.get_f64_res53(), 0.9057919370756192);
+	/// ```
+	pub const fn get_f64_res53(&mut self) -> f64 {
+		let a = self.get() >> 5;
+		let b = self.get() >> 6;
+		(a as f64 * 67108864.0 + b as f64) * (1.0 / 9007199254740992.0)
+	}
 }
 
 impl crate::RandomImpl for MTwister {
@@ -104,9 +216,99 @@ impl crate::RandomImpl for MTwister {
 	}
 }
 
+impl crate::FromRandom for MTwister {
+	fn from_random(random: &mut impl crate::Random) -> Self {
+		Self::new(random.random())
+	}
+}
+
 impl core::fmt::Debug for MTwister {
 	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
 		write!(f, "MTwister")
 	}
 }
 
+
+
+#[cfg(test)]
+mod test {
+	use crate::MTwister;
+
+	#[test]
+	fn test_reference() {
+		let mut rng = MTwister::new(5489);
+		assert_eq!(rng.get(), 3499211612);
+		assert_eq!(rng.get(), 581869302);
+	}
+
+	#[test]
+	fn test_reference_array() {
+		// the reference `mt19937ar.out`: 1000 values of `genrand_int32()`,
+		// then 1000 of `genrand_real2()`, printed with 8 decimals
+		let mut rng = MTwister::new_array(&[0x123, 0x234, 0x345, 0x456]);
+		for x in [
+			1067595299, 955945823, 477289528, 4107218783, 4228976476,
+			3344332714, 3355579695, 227628506, 810200273, 2591290167,
+		] {
+			assert_eq!(rng.get(), x);
+		}
+		for _ in 10..1000 {
+			rng.get();
+		}
+		for x in ["0.76275443", "0.99000644", "0.98670464", "0.10143112", "0.27933125"] {
+			let real2 = rng.get() as f64 * (1.0 / 4294967296.0);
+			assert_eq!(std::format!("{real2:.8}"), x);
+		}
+	}
+
+	#[test]
+	fn test_res53() {
+		// python's `random.random()` is `genrand_res53()`, and
+		// `random.seed(n)` is `init_by_array([n])`
+		let mut rng = MTwister::new_array(&[0]);
+		assert_eq!(rng.get_f64_res53(), 0.8444218515250481);
+		assert_eq!(rng.get_f64_res53(), 0.7579544029403025);
+		assert_eq!(rng.get_f64_res53(), 0.420571580830845);
+
+		let mut rng = MTwister::new_array(&[42]);
+		assert_eq!(rng.get_f64_res53(), 0.6394267984578837);
+		assert_eq!(rng.get_f64_res53(), 0.025010755222666936);
+		assert_eq!(rng.get_f64_res53(), 0.27502931836911926);
+
+		// matlab's `rng default` is `init_genrand(5489)`, and its `rand` is
+		// also `genrand_res53()`: 0.8147, 0.9058, 0.1270, 0.9134, 0.6324
+		let mut rng = MTwister::new(5489);
+		assert_eq!(rng.get_f64_res53(), 0.8147236863931789);
+		assert_eq!(rng.get_f64_res53(), 0.9057919370756192);
+		assert_eq!(rng.get_f64_res53(), 0.12698681629350606);
+		assert_eq!(rng.get_f64_res53(), 0.9133758561390194);
+		assert_eq!(rng.get_f64_res53(), 0.6323592462254095);
+	}
+
+	#[test]
+	#[should_panic = "`key` must not be empty"]
+	fn test_array_empty() {
+		MTwister::new_array(&[]);
+	}
+
+	#[test]
+	fn test_fill() {
+		let mut a = MTwister::new(5489);
+		let mut b = a.clone();
+
+		for len in [0, 1, 100, 623, 624, 625, 2000] {
+			let mut dst = [0; 2000];
+			a.fill(&mut dst[..len]);
+			for x in &dst[..len] {
+				assert_eq!(*x, b.get());
+			}
+		}
+
+		let mut block = [0; 624];
+		a.fill_block(&mut block);
+		for x in block {
+			assert_eq!(x, b.get());
+		}
+		assert_eq!(a.get(), b.get());
+	}
+}
@@ -0,0 +1,87 @@
+// https://github.com/imneme/pcg-c/blob/master/include/pcg_variants.h
+
+/// [pcg](https://www.pcg-random.org/) psuedo-rng, with 32 bits of state,
+/// and the XSH-RS output function. yields u16 values.
+/// 
+/// this is pcg-c's `setseq_32_xsh_rs_16`, seeded the same way as
+/// [`crate::Pcg32`]. it only needs 32 bit arithmetic, but its period is
+/// only `2^32`, which can be exhausted in seconds on a desktop machine.
+/// prefer [`crate::Pcg32`] unless the state has to be small.
+/// 
+/// ```
+/// # use prrng::Pcg16;
+/// let mut rng = Pcg16::new(42, 54);
+/// 
+/// assert_eq!(rng.get(), 42717);
+/// assert_eq!(rng.get(), 34900);
+/// assert_eq!(rng.get(), 23473);
+/// assert_eq!(rng.get(), 44515);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Pcg16 {
+	state: u32,
+	index: u32,
+}
+
+impl Pcg16 {
+	#[inline]
+	pub const fn new_raw(seed: u32, id: u32) -> Self {
+		Self {
+			state: seed,
+			index: id,
+		}
+	}
+
+	#[inline]
+	pub const fn new(seed: u32, id: u32) -> Self {
+		let mut ret = Self::new_raw(0, (id << 1) | 1);
+		ret.step();
+		ret.state = ret.state.wrapping_add(seed);
+		ret.step();
+		ret
+	}
+
+	// advance the lcg, returning the previous state
+	#[inline(always)]
+	const fn step(&mut self) -> u32 {
+		let prev = self.state;
+		self.state = prev
+			.wrapping_mul(747796405)
+			.wrapping_add(self.index);
+		prev
+	}
+
+	#[inline]
+	pub const fn get(&mut self) -> u16 {
+		let prev = self.step();
+		(((prev >> 11) ^ prev) >> ((prev >> 30) + 11)) as u16
+	}
+}
+
+impl crate::RandomImpl for Pcg16 {
+	#[inline]
+	fn random_u64(&mut self) -> u64 {
+		crate::common::u32_compose_u64(self.random_u32(), self.random_u32())
+	}
+
+	#[inline]
+	fn random_u32(&mut self) -> u32 {
+		crate::common::u16_compose_u32(self.get(), self.get())
+	}
+
+	fn random_bytes(&mut self, dst: &mut [u8]) {
+		crate::common::bytes_from_u16(|| self.get(), dst);
+	}
+}
+
+impl crate::FromRandom for Pcg16 {
+	fn from_random(random: &mut impl crate::Random) -> Self {
+		Self::new(random.random(), random.random())
+	}
+}
+
+impl core::fmt::Debug for Pcg16 {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		write!(f, "Pcg16")
+	}
+}
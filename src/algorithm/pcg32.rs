@@ -1,6 +1,43 @@
-
 // https://github.com/imneme/pcg-c-basic/blob/master/pcg_basic.c
-#[derive(Clone)]
+// https://github.com/imneme/pcg-c/blob/master/include/pcg_variants.h
+
+const MULTIPLIER: u64 = 6364136223846793005;
+
+// advance the shared 64bit lcg, returning the previous state
+#[inline(always)]
+const fn step(state: &mut u64, inc: u64) -> u64 {
+	let prev = *state;
+	*state = prev.wrapping_mul(MULTIPLIER).wrapping_add(inc);
+	prev
+}
+
+// pcg-c's `srandom_r`, returning `(state, inc)`
+#[inline(always)]
+const fn seed(seed: u64, id: u64) -> (u64, u64) {
+	let inc = (id << 1) | 1;
+	let mut state = 0;
+	step(&mut state, inc);
+	state = state.wrapping_add(seed);
+	step(&mut state, inc);
+	(state, inc)
+}
+
+/// [pcg](https://www.pcg-random.org/) psuedo-rng, with 64 bits of state,
+/// and the XSH-RR output function. yields u32 values.
+/// 
+/// this is pcg-c's `pcg32`. see [`Pcg32XshRs`] and [`PcgRxsMXs64`] for
+/// other output functions over the same state.
+/// 
+/// ```
+/// # use prrng::Pcg32;
+/// let mut rng = Pcg32::new(42, 54);
+/// 
+/// assert_eq!(rng.get(), 0xa15c02b7);
+/// assert_eq!(rng.get(), 0x7b47f409);
+/// assert_eq!(rng.get(), 0xba1d3330);
+/// assert_eq!(rng.get(), 0x83d2f293);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub struct Pcg32 {
 	state: u64,
 	index: u64,
@@ -17,20 +54,13 @@ impl Pcg32 {
 
 	#[inline]
 	pub const fn new(seed: u64, id: u64) -> Self {
-		let mut ret = Self::new_raw(0, (id << 1) | 1);
-		ret.get();
-		ret.state = ret.state.wrapping_add(seed);
-		ret.get();
-		ret
+		let (state, index) = self::seed(seed, id);
+		Self::new_raw(state, index)
 	}
 
 	#[inline]
 	pub const fn get(&mut self) -> u32 {
-		let prev: u64 = self.state;
-
-		self.state = prev
-			.wrapping_mul(6364136223846793005)
-			.wrapping_add(self.index);
+		let prev = step(&mut self.state, self.index);
 		let x: u32 = (((prev >> 18) ^ prev) >> 27) as u32;
 		let rot: u32 = (prev >> 59) as u32;
 
@@ -54,9 +84,184 @@ impl crate::RandomImpl for Pcg32 {
 	}
 }
 
+impl crate::FromRandom for Pcg32 {
+	fn from_random(random: &mut impl crate::Random) -> Self {
+		Self::new(random.random(), random.random())
+	}
+}
+
 impl core::fmt::Debug for Pcg32 {
 	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
 		write!(f, "Pcg32")
 	}
 }
 
+
+
+/// [pcg](https://www.pcg-random.org/) psuedo-rng, with 64 bits of state,
+/// and the XSH-RS output function. yields u32 values.
+/// 
+/// this is pcg-c's `setseq_64_xsh_rs_32`. it shares its state and seeding
+/// with [`Pcg32`], but uses a random shift instead of a random rotation,
+/// which is slightly cheaper and slightly weaker.
+/// 
+/// ```
+/// # use prrng::Pcg32XshRs;
+/// let mut rng = Pcg32XshRs::new(42, 54);
+/// 
+/// assert_eq!(rng.get(), 1545299392);
+/// assert_eq!(rng.get(), 2415717169);
+/// assert_eq!(rng.get(), 3435843701);
+/// assert_eq!(rng.get(), 3090997190);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Pcg32XshRs {
+	state: u64,
+	index: u64,
+}
+
+impl Pcg32XshRs {
+	#[inline]
+	pub const fn new_raw(seed: u64, id: u64) -> Self {
+		Self {
+			state: seed,
+			index: id,
+		}
+	}
+
+	#[inline]
+	pub const fn new(seed: u64, id: u64) -> Self {
+		let (state, index) = self::seed(seed, id);
+		Self::new_raw(state, index)
+	}
+
+	#[inline]
+	pub const fn get(&mut self) -> u32 {
+		let prev = step(&mut self.state, self.index);
+		(((prev >> 22) ^ prev) >> ((prev >> 61) + 22)) as u32
+	}
+}
+
+impl crate::RandomImpl for Pcg32XshRs {
+	#[inline]
+	fn random_u64(&mut self) -> u64 {
+		crate::common::u32_compose_u64(self.get(), self.get())
+	}
+
+	#[inline]
+	fn random_u32(&mut self) -> u32 {
+		self.get()
+	}
+
+	fn random_bytes(&mut self, dst: &mut [u8]) {
+		crate::common::bytes_from_u32(self, dst);
+	}
+}
+
+impl crate::FromRandom for Pcg32XshRs {
+	fn from_random(random: &mut impl crate::Random) -> Self {
+		Self::new(random.random(), random.random())
+	}
+}
+
+impl core::fmt::Debug for Pcg32XshRs {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		write!(f, "Pcg32XshRs")
+	}
+}
+
+
+/// [pcg](https://www.pcg-random.org/) psuedo-rng, with 64 bits of state,
+/// and the RXS-M-XS output function. yields u64 values.
+/// 
+/// this is pcg-c's `setseq_64_rxs_m_xs_64`. it shares its state and seeding
+/// with [`Pcg32`], but outputs the whole 64 bits. the output function is a
+/// bijection, so no value repeats until the state does, after `2^64`
+/// values.
+/// 
+/// ```
+/// # use prrng::PcgRxsMXs64;
+/// let mut rng = PcgRxsMXs64::new(42, 54);
+/// 
+/// assert_eq!(rng.get(), 16270310837369308859);
+/// assert_eq!(rng.get(), 7310394323356280452);
+/// assert_eq!(rng.get(), 14358865894078177398);
+/// assert_eq!(rng.get(), 11430022384407591164);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct PcgRxsMXs64 {
+	state: u64,
+	index: u64,
+}
+
+impl PcgRxsMXs64 {
+	#[inline]
+	pub const fn new_raw(seed: u64, id: u64) -> Self {
+		Self {
+			state: seed,
+			index: id,
+		}
+	}
+
+	#[inline]
+	pub const fn new(seed: u64, id: u64) -> Self {
+		let (state, index) = self::seed(seed, id);
+		Self::new_raw(state, index)
+	}
+
+	#[inline]
+	pub const fn get(&mut self) -> u64 {
+		let prev = step(&mut self.state, self.index);
+		let word = ((prev >> ((prev >> 59) + 5)) ^ prev).wrapping_mul(12605985483714917081);
+		(word >> 43) ^ word
+	}
+}
+
+impl crate::RandomImpl for PcgRxsMXs64 {
+	#[inline]
+	fn random_u64(&mut self) -> u64 {
+		self.get()
+	}
+
+	#[inline]
+	fn random_u32(&mut self) -> u32 {
+		self.get() as u32
+	}
+
+	fn random_bytes(&mut self, dst: &mut [u8]) {
+		crate::common::bytes_from_u64(self, dst);
+	}
+}
+
+impl crate::FromRandom for PcgRxsMXs64 {
+	fn from_random(random: &mut impl crate::Random) -> Self {
+		Self::new(random.random(), random.random())
+	}
+}
+
+impl core::fmt::Debug for PcgRxsMXs64 {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		write!(f, "PcgRxsMXs64")
+	}
+}
+
+
+#[cfg(test)]
+mod test {
+	use crate::{Pcg32, Pcg32XshRs, PcgRxsMXs64};
+
+	#[test]
+	fn test_shared_state() {
+		// every variant steps the same lcg, and only differs in its output
+		let mut a = Pcg32::new(42, 54);
+		let mut b = Pcg32XshRs::new(42, 54);
+		let mut c = PcgRxsMXs64::new(42, 54);
+		for _ in 0..64 {
+			assert_eq!((a.state, a.index), (b.state, b.index));
+			assert_eq!((a.state, a.index), (c.state, c.index));
+			a.get();
+			b.get();
+			c.get();
+		}
+	}
+}
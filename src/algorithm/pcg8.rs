@@ -0,0 +1,120 @@
+// https://github.com/imneme/pcg-c/blob/master/include/pcg_variants.h
+
+/// [pcg](https://www.pcg-random.org/) psuedo-rng, with 16 bits of state,
+/// and the XSH-RS output function. yields u8 values.
+/// 
+/// this is pcg-c's `setseq_16_xsh_rs_8`, seeded the same way as
+/// [`crate::Pcg32`]. it is meant for 8 bit targets, where even 32 bit
+/// arithmetic is expensive. its period is only `2^16`, so every stream
+/// repeats after 65536 bytes; this is fine for things like game effects,
+/// but not for anything statistical.
+/// 
+/// ```
+/// # use prrng::Pcg8;
+/// let mut rng = Pcg8::new(42, 54);
+/// 
+/// assert_eq!(rng.get(), 116);
+/// assert_eq!(rng.get(), 77);
+/// assert_eq!(rng.get(), 199);
+/// assert_eq!(rng.get(), 100);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Pcg8 {
+	state: u16,
+	index: u16,
+}
+
+impl Pcg8 {
+	#[inline]
+	pub const fn new_raw(seed: u16, id: u16) -> Self {
+		Self {
+			state: seed,
+			index: id,
+		}
+	}
+
+	#[inline]
+	pub const fn new(seed: u16, id: u16) -> Self {
+		let mut ret = Self::new_raw(0, (id << 1) | 1);
+		ret.step();
+		ret.state = ret.state.wrapping_add(seed);
+		ret.step();
+		ret
+	}
+
+	// advance the lcg, returning the previous state
+	#[inline(always)]
+	const fn step(&mut self) -> u16 {
+		let prev = self.state;
+		self.state = prev
+			.wrapping_mul(12829)
+			.wrapping_add(self.index);
+		prev
+	}
+
+	#[inline]
+	pub const fn get(&mut self) -> u8 {
+		let prev = self.step();
+		(((prev >> 7) ^ prev) >> ((prev >> 14) + 3)) as u8
+	}
+}
+
+impl crate::RandomImpl for Pcg8 {
+	#[inline]
+	fn random_u64(&mut self) -> u64 {
+		crate::common::u32_compose_u64(self.random_u32(), self.random_u32())
+	}
+
+	#[inline]
+	fn random_u32(&mut self) -> u32 {
+		crate::common::u16_compose_u32(
+			crate::common::u8_compose_u16(self.get(), self.get()),
+			crate::common::u8_compose_u16(self.get(), self.get()),
+		)
+	}
+
+	#[inline]
+	fn random_bytes(&mut self, dst: &mut [u8]) {
+		for i in dst {
+			*i = self.get();
+		}
+	}
+}
+
+impl crate::FromRandom for Pcg8 {
+	fn from_random(random: &mut impl crate::Random) -> Self {
+		Self::new(random.random(), random.random())
+	}
+}
+
+impl core::fmt::Debug for Pcg8 {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		write!(f, "Pcg8")
+	}
+}
+
+
+#[cfg(test)]
+mod test {
+	use crate::Pcg8;
+
+	#[test]
+	fn test_period() {
+		for id in [0, 1, 54, u16::MAX] {
+			let start = Pcg8::new(42, id);
+			let mut rng = start;
+
+			let mut counts = [0u32; 256];
+			// the lcg is full period
+			for i in 1..=1 << 16 {
+				counts[rng.get() as usize] += 1;
+				assert_eq!(rng == start, i == 1 << 16);
+			}
+
+			// every byte shows up roughly equally over a whole period
+			for count in counts {
+				assert!((192..320).contains(&count), "{count}");
+			}
+		}
+	}
+}
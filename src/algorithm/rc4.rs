@@ -0,0 +1,192 @@
+/// [RC4](https://en.wikipedia.org/wiki/RC4) psuedo-rng. yields u8 values.
+/// 
+/// **RC4 is broken, and must not be used for anything cryptographic.** its
+/// keystream has well known biases, especially in the first few hundred
+/// bytes, and practical attacks exist against every protocol that used it.
+/// this exists only to reproduce the output of old software, like
+/// `arc4random` before it switched to [`crate::ChaCha`].
+/// 
+/// ```
+/// # use prrng::Rc4;
+/// let mut rng = Rc4::new(b"Key");
+/// 
+/// let mut message = *b"Plaintext";
+/// for i in &mut message {
+///     *i ^= rng.get();
+/// }
+/// assert_eq!(message, [0xbb, 0xf3, 0x16, 0xe8, 0xd9, 0x40, 0xaf, 0x0a, 0xd3]);
+/// ```
+#[derive(Clone, PartialEq, Eq)]
+pub struct Rc4 {
+	s: [u8; 256],
+	i: u8,
+	j: u8,
+}
+
+impl Rc4 {
+	/// construct a new `Rc4`, running the key-scheduling algorithm over
+	/// `key`.
+	/// 
+	/// ## panics
+	/// 
+	/// panics if `key` is empty, or longer than 256 bytes. see
+	/// [`Self::try_new()`] for a constructor that doesn't panic.
+	#[inline]
+	pub const fn new(key: &[u8]) -> Self {
+		assert!(!key.is_empty(), "`key` must not be empty");
+		assert!(key.len() <= 256, "`key` must not be longer than 256 bytes");
+
+		let mut s = [0; 256];
+		let mut i = 0;
+		while i < s.len() {
+			s[i] = i as u8;
+			i += 1;
+		}
+
+		let mut j = 0u8;
+		let mut i = 0;
+		while i < s.len() {
+			j = j.wrapping_add(s[i]).wrapping_add(key[i % key.len()]);
+			let t = s[i];
+			s[i] = s[j as usize];
+			s[j as usize] = t;
+			i += 1;
+		}
+
+		Self {
+			s,
+			i: 0,
+			j: 0,
+		}
+	}
+
+	/// construct a new `Rc4`, returning [`crate::Error::InvalidParameter`]
+	/// if `key` is empty, or longer than 256 bytes.
+	#[inline]
+	pub const fn try_new(key: &[u8]) -> Result<Self, crate::Error> {
+		if key.is_empty() || key.len() > 256 {
+			return Err(crate::Error::InvalidParameter);
+		}
+		Ok(Self::new(key))
+	}
+
+	#[inline]
+	pub const fn get(&mut self) -> u8 {
+		self.i = self.i.wrapping_add(1);
+		self.j = self.j.wrapping_add(self.s[self.i as usize]);
+
+		let t = self.s[self.i as usize];
+		self.s[self.i as usize] = self.s[self.j as usize];
+		self.s[self.j as usize] = t;
+
+		let k = self.s[self.i as usize].wrapping_add(self.s[self.j as usize]);
+		self.s[k as usize]
+	}
+
+	/// discard the next `n` bytes.
+	/// 
+	/// the first bytes of the keystream are the most biased. the common
+	/// mitigations, RC4-drop\[768\] and RC4-drop\[3072\], discard that many
+	/// bytes right after construction.
+	/// 
+	/// ```
+	/// # use prrng::Rc4;
+	/// let mut a = Rc4::new(b"Key");
+	/// let mut b = a.clone();
+	/// 
+	/// a.drop_n(768);
+	/// for _ in 0..768 {
+	///     b.get();
+	/// }
+	/// assert_eq!(a.get(), b.get());
+	/// ```
+	#[inline]
+	pub const fn drop_n(&mut self, n: usize) {
+		let mut i = 0;
+		while i < n {
+			self.get();
+			i += 1;
+		}
+	}
+}
+
+impl crate::RandomImpl for Rc4 {
+	#[inline]
+	fn random_u64(&mut self) -> u64 {
+		crate::common::u32_compose_u64(self.random_u32(), self.random_u32())
+	}
+
+	#[inline]
+	fn random_u32(&mut self) -> u32 {
+		crate::common::u16_compose_u32(
+			crate::common::u8_compose_u16(self.get(), self.get()),
+			crate::common::u8_compose_u16(self.get(), self.get()),
+		)
+	}
+
+	#[inline]
+	fn random_bytes(&mut self, dst: &mut [u8]) {
+		for i in dst {
+			*i = self.get();
+		}
+	}
+}
+
+impl crate::FromRandom for Rc4 {
+	fn from_random(random: &mut impl crate::Random) -> Self {
+		Self::new(&random.random::<[u8; 32]>())
+	}
+}
+
+impl core::fmt::Debug for Rc4 {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		write!(f, "Rc4")
+	}
+}
+
+
+#[cfg(test)]
+mod test {
+	use crate::{Rc4, RandomImpl};
+
+	#[test]
+	fn test_vectors() {
+		// from the rc4 wikipedia page
+		for (key, keystream) in [
+			(&b"Key"[..], &[0xeb, 0x9f, 0x77, 0x81, 0xb7, 0x34, 0xca, 0x72, 0xa7, 0x19][..]),
+			(b"Wiki", &[0x60, 0x44, 0xdb, 0x6d, 0x41, 0xb7]),
+			(b"Secret", &[0x04, 0xd4, 0x6b, 0x05, 0x3c, 0xa8, 0x7b, 0x59]),
+		] {
+			let mut rng = Rc4::new(key);
+			for &x in keystream {
+				assert_eq!(rng.get(), x);
+			}
+		}
+
+		let mut rng = Rc4::new(b"Secret");
+		let mut message = *b"Attack at dawn";
+		let mut keystream = [0; 14];
+		rng.random_bytes(&mut keystream);
+		for (m, k) in message.iter_mut().zip(keystream) {
+			*m ^= k;
+		}
+		assert_eq!(message, [
+			0x45, 0xa0, 0x1f, 0x64, 0x5f, 0xc3, 0x5b, 0x38,
+			0x35, 0x52, 0x54, 0x4b, 0x9b, 0xf5,
+		]);
+	}
+
+	#[test]
+	fn test_key_length() {
+		assert_eq!(Rc4::try_new(&[]), Err(crate::Error::InvalidParameter));
+		assert_eq!(Rc4::try_new(&[0; 257]), Err(crate::Error::InvalidParameter));
+		assert!(Rc4::try_new(&[0; 256]).is_ok());
+		assert!(Rc4::try_new(&[0]).is_ok());
+	}
+
+	#[test]
+	#[should_panic = "`key` must not be empty"]
+	fn test_empty() {
+		let _ = Rc4::new(&[]);
+	}
+}
@@ -0,0 +1,279 @@
+use core::arch::x86_64;
+
+const RDRAND_RETRIES: usize = 10;
+const RDSEED_RETRIES: usize = 100;
+
+/// the cpu's hardware rng, through the `rdrand` instruction. yields u64
+/// values.
+/// 
+/// `rdrand` is a csprng that the cpu continuously reseeds from its own
+/// entropy source. it is much slower than any software generator here, so
+/// it is best used to seed one, see [`crate::FromRandom`].
+/// 
+/// `rdrand` can fail when the hardware is briefly out of values. each draw
+/// is retried 10 times, as intel recommends, after which
+/// [`crate::RandomImpl`] methods panic. a failure that persists that long
+/// means the hardware is broken, but [`Self::try_u64()`] and
+/// [`Self::try_u32()`] can be used to handle it instead.
+/// 
+/// this is only available on `x86_64`, with the `rdrand` feature enabled.
+/// 
+/// ```
+/// use prrng::{Random, RdRand, XorShift64};
+/// 
+/// if let Some(mut hw) = RdRand::new() {
+///     let rng: XorShift64 = hw.random();
+/// }
+/// ```
+#[derive(Clone, Copy)]
+pub struct RdRand {
+	// only constructed once `Self::is_available()` has been checked
+	_available: (),
+}
+
+impl RdRand {
+	/// returns whether the cpu supports the `rdrand` instruction, checked
+	/// with `cpuid`.
+	/// 
+	/// [`Self::new()`] already checks this.
+	#[inline]
+	pub fn is_available() -> bool {
+		// `__cpuid` is safe on newer compilers, but not on the minimum
+		// supported one
+		#[allow(unused_unsafe)]
+		let ecx = unsafe { x86_64::__cpuid(1) }.ecx;
+		ecx & (1 << 30) != 0
+	}
+
+	/// construct a new `RdRand`, or `None` if the cpu doesn't support
+	/// `rdrand`.
+	#[inline]
+	pub fn new() -> Option<Self> {
+		if Self::is_available() {
+			Some(Self {
+				_available: (),
+			})
+		} else {
+			None
+		}
+	}
+
+	/// draw a u64, or `None` if the hardware failed 10 times in a row.
+	#[inline]
+	pub fn try_u64(&mut self) -> Option<u64> {
+		let mut x = 0;
+		for _ in 0..RDRAND_RETRIES {
+			let ok = unsafe {
+				// safety:
+				// `self` exists, so the cpu supports `rdrand`
+				x86_64::_rdrand64_step(&mut x)
+			};
+			if ok == 1 {
+				return Some(x);
+			}
+		}
+		None
+	}
+
+	/// draw a u32, or `None` if the hardware failed 10 times in a row.
+	#[inline]
+	pub fn try_u32(&mut self) -> Option<u32> {
+		let mut x = 0;
+		for _ in 0..RDRAND_RETRIES {
+			let ok = unsafe {
+				// safety:
+				// `self` exists, so the cpu supports `rdrand`
+				x86_64::_rdrand32_step(&mut x)
+			};
+			if ok == 1 {
+				return Some(x);
+			}
+		}
+		None
+	}
+}
+
+impl crate::RandomImpl for RdRand {
+	/// ## panics
+	/// 
+	/// panics if the hardware fails 10 times in a row.
+	#[inline]
+	fn random_u64(&mut self) -> u64 {
+		self.try_u64().expect("`rdrand` failed")
+	}
+
+	/// ## panics
+	/// 
+	/// panics if the hardware fails 10 times in a row.
+	#[inline]
+	fn random_u32(&mut self) -> u32 {
+		self.try_u32().expect("`rdrand` failed")
+	}
+
+	fn random_bytes(&mut self, dst: &mut [u8]) {
+		crate::common::bytes_from_u64(self, dst);
+	}
+}
+
+impl core::fmt::Debug for RdRand {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		write!(f, "RdRand")
+	}
+}
+
+
+/// the cpu's hardware entropy source, through the `rdseed` instruction.
+/// yields u64 values.
+/// 
+/// unlike [`RdRand`], `rdseed` reads the entropy source directly, rather
+/// than a csprng seeded from it. it is even slower, and is only meant for
+/// seeding.
+/// 
+/// `rdseed` runs out of values much more easily than `rdrand`, especially
+/// when several threads use it at once, so failures are expected. each
+/// draw is retried up to 100 times, with a spin loop hint between attempts,
+/// after which [`crate::RandomImpl`] methods panic. use [`Self::try_u64()`]
+/// and [`Self::try_u32()`] to handle it instead, for example by falling back
+/// to [`RdRand`].
+/// 
+/// this is only available on `x86_64`, with the `rdrand` feature enabled.
+#[derive(Clone, Copy)]
+pub struct RdSeed {
+	// only constructed once `Self::is_available()` has been checked
+	_available: (),
+}
+
+impl RdSeed {
+	/// returns whether the cpu supports the `rdseed` instruction, checked
+	/// with `cpuid`.
+	/// 
+	/// [`Self::new()`] already checks this.
+	#[inline]
+	pub fn is_available() -> bool {
+		#[allow(unused_unsafe)]
+		let max = unsafe { x86_64::__get_cpuid_max(0) }.0;
+		if max < 7 {
+			return false;
+		}
+		#[allow(unused_unsafe)]
+		let ebx = unsafe { x86_64::__cpuid_count(7, 0) }.ebx;
+		ebx & (1 << 18) != 0
+	}
+
+	/// construct a new `RdSeed`, or `None` if the cpu doesn't support
+	/// `rdseed`.
+	#[inline]
+	pub fn new() -> Option<Self> {
+		if Self::is_available() {
+			Some(Self {
+				_available: (),
+			})
+		} else {
+			None
+		}
+	}
+
+	/// draw a u64, or `None` if the hardware failed 100 times in a row.
+	#[inline]
+	pub fn try_u64(&mut self) -> Option<u64> {
+		let mut x = 0;
+		for _ in 0..RDSEED_RETRIES {
+			let ok = unsafe {
+				// safety:
+				// `self` exists, so the cpu supports `rdseed`
+				x86_64::_rdseed64_step(&mut x)
+			};
+			if ok == 1 {
+				return Some(x);
+			}
+			core::hint::spin_loop();
+		}
+		None
+	}
+
+	/// draw a u32, or `None` if the hardware failed 100 times in a row.
+	#[inline]
+	pub fn try_u32(&mut self) -> Option<u32> {
+		let mut x = 0;
+		for _ in 0..RDSEED_RETRIES {
+			let ok = unsafe {
+				// safety:
+				// `self` exists, so the cpu supports `rdseed`
+				x86_64::_rdseed32_step(&mut x)
+			};
+			if ok == 1 {
+				return Some(x);
+			}
+			core::hint::spin_loop();
+		}
+		None
+	}
+}
+
+impl crate::RandomImpl for RdSeed {
+	/// ## panics
+	/// 
+	/// panics if the hardware fails 100 times in a row.
+	#[inline]
+	fn random_u64(&mut self) -> u64 {
+		self.try_u64().expect("`rdseed` failed")
+	}
+
+	/// ## panics
+	/// 
+	/// panics if the hardware fails 100 times in a row.
+	#[inline]
+	fn random_u32(&mut self) -> u32 {
+		self.try_u32().expect("`rdseed` failed")
+	}
+
+	fn random_bytes(&mut self, dst: &mut [u8]) {
+		crate::common::bytes_from_u64(self, dst);
+	}
+}
+
+impl core::fmt::Debug for RdSeed {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		write!(f, "RdSeed")
+	}
+}
+
+
+#[cfg(test)]
+mod test {
+	use crate::{RandomImpl, RdRand, RdSeed};
+
+	#[test]
+	fn test_rdrand() {
+		let Some(mut rng) = RdRand::new() else {
+			assert!(!RdRand::is_available());
+			return;
+		};
+
+		let a = rng.random_u64();
+		assert!((0..8).any(|_| rng.random_u64() != a));
+
+		let a = rng.random_u32();
+		assert!((0..8).any(|_| rng.random_u32() != a));
+
+		let mut bytes = [0u8; 37];
+		rng.random_bytes(&mut bytes);
+		assert!(bytes.iter().any(|&x| x != bytes[0]));
+	}
+
+	#[test]
+	fn test_rdseed() {
+		let Some(mut rng) = RdSeed::new() else {
+			assert!(!RdSeed::is_available());
+			return;
+		};
+
+		// failures are allowed here, but shouldn't happen every time
+		let draws = [(); 8].map(|_| rng.try_u64());
+		let first = draws.iter().flatten().next().expect("`rdseed` never succeeded");
+		assert!(
+			draws.iter().flatten().count() == 1
+			|| draws.iter().flatten().any(|x| x != first)
+		);
+	}
+}
@@ -1,5 +1,5 @@
 
-#[derive(Clone)]
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub struct SplitMix64 {
 	seed: u64,
 }
@@ -38,6 +38,12 @@ impl crate::RandomImpl for SplitMix64 {
 	}
 }
 
+impl crate::FromRandom for SplitMix64 {
+	fn from_random(random: &mut impl crate::Random) -> Self {
+		Self::new(random.random())
+	}
+}
+
 impl core::fmt::Debug for SplitMix64 {
 	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
 		write!(f, "SplitMix64")
@@ -0,0 +1,226 @@
+/// sponge psuedo-rng over the
+/// [keccak-f\[200\]](https://keccak.team/keccak_specs_summary.html)
+/// permutation. yields u8 values.
+/// 
+/// the whole state is 25 bytes. the seed is absorbed into it, then output is
+/// squeezed out of the first 4 bytes (the rate), permuting the state again
+/// every 4 bytes. the other 21 bytes (the capacity) are never output.
+/// 
+/// this is meant for targets where the 64 byte state of [`crate::ChaCha`]
+/// is too much, but the output should still be hard to predict. be honest
+/// with yourself about what that buys: the 168 bit capacity bounds generic
+/// attacks at around `2^84` work, not the `2^256` of a [`crate::ChaCha`]
+/// key, and keccak-f\[200\] has had far less analysis than either chacha or
+/// the full size keccak-f\[1600\]. nothing is mixed back in after
+/// construction either, so anyone who sees the state can predict all future
+/// output. prefer [`crate::ChaCha`] whenever it fits.
+/// 
+/// ```
+/// # use prrng::SpongeRng;
+/// let mut rng = SpongeRng::new(b"prrng");
+/// 
+/// assert_eq!(rng.get_byte(), 125);
+/// assert_eq!(rng.get_byte(), 252);
+/// assert_eq!(rng.get_byte(), 163);
+/// assert_eq!(rng.get_byte(), 101);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct SpongeRng {
+	state: [u8; 25],
+	// `state[index..RATE]` has not been output yet
+	index: u8,
+}
+
+const RATE: usize = 4;
+
+const ROUND_CONSTANTS: [u8; 18] = [
+	0x01, 0x82, 0x8a, 0x00, 0x8b, 0x01, 0x81, 0x09, 0x8a,
+	0x88, 0x09, 0x0a, 0x8b, 0x8b, 0x89, 0x03, 0x02, 0x80,
+];
+
+// indexed by `x + 5 * y`, already reduced mod 8
+const ROTATIONS: [u32; 25] = [
+	0, 1, 6, 4, 3,
+	4, 4, 6, 7, 4,
+	3, 2, 3, 1, 7,
+	1, 5, 7, 5, 0,
+	2, 2, 5, 0, 6,
+];
+
+impl SpongeRng {
+	/// construct a new `SpongeRng`, absorbing all of `seed`.
+	/// 
+	/// `seed` can be any length, including empty. it is padded, so seeds
+	/// that only differ by trailing zeros still give different output.
+	#[inline]
+	pub const fn new(seed: &[u8]) -> Self {
+		let mut state = [0; 25];
+
+		let mut i = 0;
+		while i + RATE <= seed.len() {
+			let mut j = 0;
+			while j < RATE {
+				state[j] ^= seed[i + j];
+				j += 1;
+			}
+			permute(&mut state);
+			i += RATE;
+		}
+
+		// pad10*1
+		let mut j = 0;
+		while i + j < seed.len() {
+			state[j] ^= seed[i + j];
+			j += 1;
+		}
+		state[j] ^= 0x01;
+		state[RATE - 1] ^= 0x80;
+		permute(&mut state);
+
+		Self {
+			state,
+			index: 0,
+		}
+	}
+
+	#[inline]
+	pub const fn get_byte(&mut self) -> u8 {
+		if self.index as usize == RATE {
+			permute(&mut self.state);
+			self.index = 0;
+		}
+		let result = self.state[self.index as usize];
+		self.index += 1;
+		result
+	}
+
+	// absorb another block after construction, for `crate::JitterAccumulator`.
+	// output restarts from the new state.
+	#[inline]
+	pub(crate) const fn absorb(&mut self, block: [u8; RATE]) {
+		let mut i = 0;
+		while i < RATE {
+			self.state[i] ^= block[i];
+			i += 1;
+		}
+		permute(&mut self.state);
+		self.index = 0;
+	}
+}
+
+impl crate::RandomImpl for SpongeRng {
+	#[inline]
+	fn random_u64(&mut self) -> u64 {
+		crate::common::u32_compose_u64(self.random_u32(), self.random_u32())
+	}
+
+	#[inline]
+	fn random_u32(&mut self) -> u32 {
+		crate::common::u16_compose_u32(
+			crate::common::u8_compose_u16(self.get_byte(), self.get_byte()),
+			crate::common::u8_compose_u16(self.get_byte(), self.get_byte()),
+		)
+	}
+
+	#[inline]
+	fn random_bytes(&mut self, dst: &mut [u8]) {
+		for i in dst {
+			*i = self.get_byte();
+		}
+	}
+}
+
+impl crate::FromRandom for SpongeRng {
+	fn from_random(random: &mut impl crate::Random) -> Self {
+		Self::new(&random.random::<[u8; 32]>())
+	}
+}
+
+impl core::fmt::Debug for SpongeRng {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		write!(f, "SpongeRng")
+	}
+}
+
+// keccak-f[200], with lanes indexed by `x + 5 * y`
+const fn permute(a: &mut [u8; 25]) {
+	let mut round = 0;
+	while round < ROUND_CONSTANTS.len() {
+		// theta
+		let mut c = [0u8; 5];
+		let mut x = 0;
+		while x < 5 {
+			c[x] = a[x] ^ a[x + 5] ^ a[x + 10] ^ a[x + 15] ^ a[x + 20];
+			x += 1;
+		}
+		let mut x = 0;
+		while x < 5 {
+			let d = c[(x + 4) % 5] ^ c[(x + 1) % 5].rotate_left(1);
+			let mut y = 0;
+			while y < 5 {
+				a[x + 5 * y] ^= d;
+				y += 1;
+			}
+			x += 1;
+		}
+
+		// rho and pi
+		let mut b = [0u8; 25];
+		let mut i = 0;
+		while i < 25 {
+			let (x, y) = (i % 5, i / 5);
+			b[y + 5 * ((2 * x + 3 * y) % 5)] = a[i].rotate_left(ROTATIONS[i]);
+			i += 1;
+		}
+
+		// chi
+		let mut i = 0;
+		while i < 25 {
+			let (x, y) = (i % 5, i / 5);
+			a[i] = b[i] ^ (!b[(x + 1) % 5 + 5 * y] & b[(x + 2) % 5 + 5 * y]);
+			i += 1;
+		}
+
+		// iota
+		a[0] ^= ROUND_CONSTANTS[round];
+
+		round += 1;
+	}
+}
+
+
+#[cfg(test)]
+mod test {
+	use crate::{RandomImpl, SpongeRng};
+
+	#[test]
+	fn test_permutation() {
+		// from the keccak team's KeccakF-200-IntermediateValues.txt
+		let mut state = [0; 25];
+		super::permute(&mut state);
+		assert_eq!(state, [
+			0x3c, 0x28, 0x26, 0x84, 0x1c, 0xb3, 0x5c, 0x17, 0x1e, 0xaa, 0xe9, 0xb8, 0x11,
+			0x13, 0x4c, 0xea, 0xa3, 0x85, 0x2c, 0x69, 0xd2, 0xc5, 0xab, 0xaf, 0xea,
+		]);
+		super::permute(&mut state);
+		assert_eq!(state, [
+			0x1b, 0xef, 0x68, 0x94, 0x92, 0xa8, 0xa5, 0x43, 0xa5, 0x99, 0x9f, 0xdb, 0x83,
+			0x4e, 0x31, 0x66, 0xa1, 0x4b, 0xe8, 0x27, 0xd9, 0x50, 0x40, 0x47, 0x9e,
+		]);
+	}
+
+	#[test]
+	fn test_squeeze() {
+		// crosses a rate block, and an exactly full seed block
+		let mut rng = SpongeRng::new(b"abcdefgh");
+		let mut bytes = [0; 8];
+		rng.random_bytes(&mut bytes);
+		assert_eq!(bytes, [13, 31, 170, 165, 195, 30, 251, 4]);
+
+		let mut rng = SpongeRng::new(b"");
+		assert_eq!(rng.random_u32(), u32::from_be_bytes([165, 180, 217, 148]));
+
+		// padding keeps trailing zeros significant
+		assert!(SpongeRng::new(b"a") != SpongeRng::new(b"a\0"));
+	}
+}
@@ -10,16 +10,20 @@
 /// assert_eq!(rng.get(), 0.5273524613909046);
 /// assert_eq!(rng.get(), 0.44624074405335046);
 /// ```
-#[derive(Clone)]
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub struct WichHill {
 	seed: (u32, u32, u32),
 }
 
+const MODULUS: [u32; 3] = [30269, 30307, 30323];
+
 impl WichHill {
 	/// construct a new `WichHill` instance from raw seeds.
 	/// 
-	/// all three seeds should be between `1..=30000`. values outside of
-	/// this may produce unexpected values.
+	/// every seed should be between `1` and its respective modulus
+	/// (`30269`, `30307`, and `30323`), exclusive. values outside of
+	/// this may produce unexpected values. see [`Self::new()`] for
+	/// a constructor that accounts for this.
 	/// 
 	/// ## examples
 	/// 
@@ -41,22 +45,84 @@ impl WichHill {
 		}
 	}
 
+	/// construct a new `WichHill` instance.
+	/// 
+	/// every seed is reduced modulo its respective modulus, and then set to
+	/// `1` if it is `0`. see [`Self::try_new()`] for a constructor that
+	/// rejects these seeds instead.
+	/// 
+	/// ```
+	/// # use prrng::WichHill;
+	/// let mut a = WichHill::new([u32::MAX; 3]);
+	/// let mut b = WichHill::new([u32::MAX % 30269, u32::MAX % 30307, u32::MAX % 30323]);
+	/// 
+	/// assert_eq!(a.get(), b.get());
+	/// ```
 	#[inline]
 	pub const fn new(mut seed: [u32; 3]) -> Self {
-		seed[0] = crate::common::u32_or_1(seed[0]);
-		seed[1] = crate::common::u32_or_1(seed[1]);
-		seed[2] = crate::common::u32_or_1(seed[2]);
+		seed[0] = crate::common::u32_or_1(seed[0] % MODULUS[0]);
+		seed[1] = crate::common::u32_or_1(seed[1] % MODULUS[1]);
+		seed[2] = crate::common::u32_or_1(seed[2] % MODULUS[2]);
 		Self::new_raw(seed)
 	}
 
+	/// construct a new `WichHill` instance, returning
+	/// [`crate::Error::ZeroSeed`] if any seed is `0`, or
+	/// [`crate::Error::SeedOutOfRange`] if any seed is not less than its
+	/// respective modulus.
+	/// 
+	/// ```
+	/// # use prrng::{Error, WichHill};
+	/// assert!(WichHill::try_new([1, 2, 3]).is_ok());
+	/// assert_eq!(WichHill::try_new([0, 2, 3]).unwrap_err(), Error::ZeroSeed);
+	/// assert_eq!(WichHill::try_new([1, 30307, 3]).unwrap_err(), Error::SeedOutOfRange);
+	/// ```
+	#[inline]
+	pub const fn try_new(seed: [u32; 3]) -> Result<Self, crate::Error> {
+		let mut i = 0;
+		while i < seed.len() {
+			if seed[i] == 0 {
+				return Err(crate::Error::ZeroSeed);
+			}
+			if seed[i] >= MODULUS[i] {
+				return Err(crate::Error::SeedOutOfRange);
+			}
+			i += 1;
+		}
+		Ok(Self::new_raw(seed))
+	}
+
+	#[inline]
+	const fn step(&mut self) {
+		self.seed.0 = (self.seed.0 as u64 * 171 % MODULUS[0] as u64) as u32;
+		self.seed.1 = (self.seed.1 as u64 * 172 % MODULUS[1] as u64) as u32;
+		self.seed.2 = (self.seed.2 as u64 * 170 % MODULUS[2] as u64) as u32;
+	}
+
+	/// returns the next value, following the original algorithm.
 	#[inline]
 	pub const fn get(&mut self) -> f64 {
-		self.seed.0 = (self.seed.0 * 171) % 30269;
-		self.seed.1 = (self.seed.1 * 172) % 30307;
-		self.seed.2 = (self.seed.2 * 170) % 30323;
+		self.step();
 		let x = self.seed.0 as f64 / 30269.0 + self.seed.1 as f64 / 30307.0 + self.seed.2 as f64 / 30323.0;
 		x % 1.0
 	}
+
+	/// returns the next value as a `u32`, derived directly from the three
+	/// integer states rather than from [`Self::get()`].
+	/// 
+	/// the three states are combined into a single ~44bit integer, which is
+	/// then mixed down into 32 bits. this advances the generator exactly like
+	/// `get()` does.
+	#[inline]
+	pub const fn get_u32(&mut self) -> u32 {
+		self.step();
+		let mut x = (self.seed.0 as u64 * MODULUS[1] as u64 + self.seed.1 as u64)
+			* MODULUS[2] as u64
+			+ self.seed.2 as u64;
+		x = (x ^ (x >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+		x = (x ^ (x >> 27)).wrapping_mul(0x94d049bb133111eb);
+		(x >> 32) as u32
+	}
 }
 
 impl crate::RandomImpl for WichHill {
@@ -67,7 +133,7 @@ impl crate::RandomImpl for WichHill {
 
 	#[inline]
 	fn random_u32(&mut self) -> u32 {
-		crate::common::f64_to_u32(self.get())
+		self.get_u32()
 	}
 	
 	fn random_bytes(&mut self, dst: &mut [u8]) {
@@ -75,9 +141,263 @@ impl crate::RandomImpl for WichHill {
 	}
 }
 
+impl crate::FromRandom for WichHill {
+	fn from_random(random: &mut impl crate::Random) -> Self {
+		Self::new(random.random())
+	}
+}
+
 impl core::fmt::Debug for WichHill {
 	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
 		write!(f, "WichHill")
 	}
 }
 
+
+
+/// the 2006 revision of [Wichmann-Hill](https://en.wikipedia.org/wiki/Wichmann%E2%80%93Hill),
+/// with four components. psuedo-rng.
+/// 
+/// the original [`WichHill`] has a period of around `7 * 10^12`, which a
+/// modern simulation can run through. this revision uses four congruential
+/// generators with moduli just under `2^31`, for a period of around `2^121`.
+/// everything else works the same: [`Self::get()`] sums the normalized
+/// components, and the integer methods of [`crate::RandomImpl`] are
+/// derived from the integer states instead.
+/// 
+/// ```
+/// # use prrng::WichHill2006;
+/// let mut rng = WichHill2006::new([10, 20, 30, 40]);
+/// 
+/// assert_eq!(rng.get(), 0.0014277456536368146);
+/// assert_eq!(rng.get(), 0.8763929790061893);
+/// assert_eq!(rng.get(), 0.7358422718825541);
+/// assert_eq!(rng.get(), 0.602604516156378);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct WichHill2006 {
+	seed: [u32; 4],
+}
+
+const MULTIPLIER_2006: [u32; 4] = [11600, 47003, 23000, 33000];
+const MODULUS_2006: [u32; 4] = [2147483579, 2147483543, 2147483423, 2147483123];
+
+impl WichHill2006 {
+	/// construct a new `WichHill2006` instance from raw seeds.
+	/// 
+	/// every seed should be between `1` and its respective modulus
+	/// (`2147483579`, `2147483543`, `2147483423`, and `2147483123`),
+	/// exclusive. see [`Self::new()`] for a constructor that accounts for
+	/// this.
+	#[inline]
+	pub const fn new_raw(seed: [u32; 4]) -> Self {
+		Self {
+			seed,
+		}
+	}
+
+	/// construct a new `WichHill2006` instance.
+	/// 
+	/// every seed is reduced modulo its respective modulus, and then set to
+	/// `1` if it is `0`. see [`Self::try_new()`] for a constructor that
+	/// rejects these seeds instead.
+	#[inline]
+	pub const fn new(mut seed: [u32; 4]) -> Self {
+		let mut i = 0;
+		while i < seed.len() {
+			seed[i] = crate::common::u32_or_1(seed[i] % MODULUS_2006[i]);
+			i += 1;
+		}
+		Self::new_raw(seed)
+	}
+
+	/// construct a new `WichHill2006` instance, returning
+	/// [`crate::Error::ZeroSeed`] if any seed is `0`, or
+	/// [`crate::Error::SeedOutOfRange`] if any seed is not less than its
+	/// respective modulus.
+	#[inline]
+	pub const fn try_new(seed: [u32; 4]) -> Result<Self, crate::Error> {
+		let mut i = 0;
+		while i < seed.len() {
+			if seed[i] == 0 {
+				return Err(crate::Error::ZeroSeed);
+			}
+			if seed[i] >= MODULUS_2006[i] {
+				return Err(crate::Error::SeedOutOfRange);
+			}
+			i += 1;
+		}
+		Ok(Self::new_raw(seed))
+	}
+
+	#[inline]
+	const fn step(&mut self) {
+		let mut i = 0;
+		while i < self.seed.len() {
+			self.seed[i] = (self.seed[i] as u64 * MULTIPLIER_2006[i] as u64 % MODULUS_2006[i] as u64) as u32;
+			i += 1;
+		}
+	}
+
+	/// returns the next value, following the published algorithm.
+	#[inline]
+	pub const fn get(&mut self) -> f64 {
+		self.step();
+		let x = self.seed[0] as f64 / MODULUS_2006[0] as f64
+			+ self.seed[1] as f64 / MODULUS_2006[1] as f64
+			+ self.seed[2] as f64 / MODULUS_2006[2] as f64
+			+ self.seed[3] as f64 / MODULUS_2006[3] as f64;
+		x % 1.0
+	}
+
+	/// returns the next value as a `u64`, derived directly from the four
+	/// integer states rather than from [`Self::get()`].
+	/// 
+	/// the four states are combined into a single ~124bit integer, which is
+	/// then mixed down into 64 bits. this advances the generator exactly like
+	/// `get()` does.
+	#[inline]
+	pub const fn get_u64(&mut self) -> u64 {
+		self.step();
+		let mut x = 0u128;
+		let mut i = 0;
+		while i < self.seed.len() {
+			x = x * MODULUS_2006[i] as u128 + self.seed[i] as u128;
+			i += 1;
+		}
+		let mut x = (x >> 64) as u64 ^ x as u64;
+		x = (x ^ (x >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+		x = (x ^ (x >> 27)).wrapping_mul(0x94d049bb133111eb);
+		x ^ (x >> 31)
+	}
+}
+
+impl crate::RandomImpl for WichHill2006 {
+	#[inline]
+	fn random_u64(&mut self) -> u64 {
+		self.get_u64()
+	}
+
+	#[inline]
+	fn random_u32(&mut self) -> u32 {
+		self.get_u64() as u32
+	}
+
+	fn random_bytes(&mut self, dst: &mut [u8]) {
+		crate::common::bytes_from_u64(self, dst);
+	}
+}
+
+impl crate::FromRandom for WichHill2006 {
+	fn from_random(random: &mut impl crate::Random) -> Self {
+		Self::new(random.random())
+	}
+}
+
+impl core::fmt::Debug for WichHill2006 {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		write!(f, "WichHill2006")
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use crate::{WichHill, WichHill2006};
+
+	#[test]
+	fn test_overflow() {
+		let mut rng = WichHill::new_raw([u32::MAX; 3]);
+		for _ in 0..16 {
+			let x = rng.get();
+			assert!((0.0..1.0).contains(&x));
+		}
+
+		let mut a = WichHill::new([30269, 30307 * 2, 30323 * 3 + 5]);
+		let mut b = WichHill::new_raw([1, 1, 5]);
+		for _ in 0..16 {
+			assert_eq!(a.get(), b.get());
+		}
+	}
+
+	#[test]
+	fn test_integer() {
+		use crate::RandomImpl;
+
+		let mut a = WichHill::new([10, 20, 30]);
+		let mut b = WichHill::new([10, 20, 30]);
+		for _ in 0..16 {
+			assert_eq!(a.random_u32(), b.random_u32());
+		}
+
+		let mut counts = [0u32; 256];
+		let mut buf = [0u8; 256 * 64];
+		a.random_bytes(&mut buf);
+		for i in buf {
+			counts[i as usize] += 1;
+		}
+		for i in counts {
+			assert!(i > 64 / 2 && i < 64 * 2);
+		}
+	}
+
+	#[test]
+	fn test_2006() {
+		use crate::RandomImpl;
+
+		let mut a = WichHill2006::new([u32::MAX; 4]);
+		let mut b = WichHill2006::new_raw([137, 209, 449, 1049]);
+		for _ in 0..16 {
+			let x = a.get();
+			assert!((0.0..1.0).contains(&x));
+			assert_eq!(x, b.get());
+		}
+
+		let mut rng = WichHill2006::new([10, 20, 30, 40]);
+		let mut counts = [0u32; 256];
+		let mut buf = [0u8; 256 * 64];
+		rng.random_bytes(&mut buf);
+		for i in buf {
+			counts[i as usize] += 1;
+		}
+		for i in counts {
+			assert!(i > 64 / 2 && i < 64 * 2);
+		}
+	}
+
+	#[test]
+	fn test_2006_published() {
+		// the 32 bit integer routine published with the 2006 revision, which
+		// steps each component with schrage's method instead of a 64 bit
+		// product, and sums them exactly like `get()`
+		fn published(state: &mut [i32; 4]) -> f64 {
+			let [ix, iy, iz, it] = state;
+			*ix = 11600 * (*ix % 185127) - 10379 * (*ix / 185127);
+			*iy = 47003 * (*iy % 45688) - 10479 * (*iy / 45688);
+			*iz = 23000 * (*iz % 93368) - 19423 * (*iz / 93368);
+			*it = 33000 * (*it % 65075) - 8123 * (*it / 65075);
+			if *ix < 0 { *ix += 2147483579; }
+			if *iy < 0 { *iy += 2147483543; }
+			if *iz < 0 { *iz += 2147483423; }
+			if *it < 0 { *it += 2147483123; }
+
+			let w = *ix as f64 / 2147483579.0
+				+ *iy as f64 / 2147483543.0
+				+ *iz as f64 / 2147483423.0
+				+ *it as f64 / 2147483123.0;
+			w - w as i32 as f64
+		}
+
+		// `m = a * q + r` for each published `q` and `r`
+		for (i, (q, r)) in [(185127, 10379), (45688, 10479), (93368, 19423), (65075, 8123)].into_iter().enumerate() {
+			assert_eq!(super::MULTIPLIER_2006[i] * q + r, super::MODULUS_2006[i]);
+		}
+
+		for seed in [[1, 2, 3, 4], [10, 20, 30, 40], [2147483578, 2147483542, 2147483422, 2147483122]] {
+			let mut rng = WichHill2006::new_raw(seed);
+			let mut state = seed.map(|x| x as i32);
+			for _ in 0..100000 {
+				assert_eq!(rng.get(), published(&mut state));
+			}
+		}
+	}
+}
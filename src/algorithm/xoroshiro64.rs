@@ -0,0 +1,178 @@
+/// [64bit xoroshiro64**](https://prng.di.unimi.it/) psuedo-rng. yields u32 values.
+/// 
+/// this has 64 bits of state, but only uses 32 bit arithmetic, which makes
+/// it a good fit for small targets without fast 64 bit operations.
+/// 
+/// ```
+/// # use prrng::Xoroshiro64ss;
+/// let mut rng = Xoroshiro64ss::new([1, 2]);
+/// 
+/// assert_eq!(rng.get(), 3802928447);
+/// assert_eq!(rng.get(), 813792938);
+/// assert_eq!(rng.get(), 1618621494);
+/// assert_eq!(rng.get(), 2955957307);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Xoroshiro64ss {
+	seed: (u32, u32),
+}
+
+impl Xoroshiro64ss {
+	#[inline]
+	pub const fn new_raw(seed: [u32; 2]) -> Self {
+		Self {
+			seed: (seed[0], seed[1]),
+		}
+	}
+
+	#[inline]
+	pub const fn new(mut seed: [u32; 2]) -> Self {
+		seed[0] = crate::common::u32_or_1(seed[0]);
+		seed[1] = crate::common::u32_or_1(seed[1]);
+		Self::new_raw(seed)
+	}
+
+	/// construct a new `Xoroshiro64ss`, returning [`crate::Error::ZeroSeed`]
+	/// if any part of `seed` is `0`, instead of adjusting it like
+	/// [`Self::new()`].
+	#[inline]
+	pub const fn try_new(seed: [u32; 2]) -> Result<Self, crate::Error> {
+		if seed[0] == 0 || seed[1] == 0 {
+			return Err(crate::Error::ZeroSeed);
+		}
+		Ok(Self::new_raw(seed))
+	}
+
+	#[inline]
+	pub const fn get(&mut self) -> u32 {
+		let result = self.seed.0
+			.wrapping_mul(0x9e3779bb)
+			.rotate_left(5)
+			.wrapping_mul(5);
+		step(&mut self.seed);
+		result
+	}
+}
+
+impl crate::RandomImpl for Xoroshiro64ss {
+	#[inline]
+	fn random_u64(&mut self) -> u64 {
+		crate::common::u32_compose_u64(self.get(), self.get())
+	}
+
+	#[inline]
+	fn random_u32(&mut self) -> u32 {
+		self.get()
+	}
+
+	fn random_bytes(&mut self, dst: &mut [u8]) {
+		crate::common::bytes_from_u32(self, dst);
+	}
+}
+
+impl crate::FromRandom for Xoroshiro64ss {
+	fn from_random(random: &mut impl crate::Random) -> Self {
+		Self::new(random.random())
+	}
+}
+
+impl core::fmt::Debug for Xoroshiro64ss {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		write!(f, "Xoroshiro64ss")
+	}
+}
+
+
+/// [64bit xoroshiro64*](https://prng.di.unimi.it/) psuedo-rng. yields u32 values.
+/// 
+/// this is slightly faster than [`Xoroshiro64ss`], but its scrambler is a
+/// single multiplication, which leaves the lowest bits of each value with
+/// low linear complexity. this doesn't matter for floats generated from the
+/// high bits, but [`crate::Random::random_f64()`] composes two values, so
+/// the low bits of the first value end up in the middle of the mantissa.
+/// prefer [`Xoroshiro64ss`] when every bit matters.
+/// 
+/// ```
+/// # use prrng::Xoroshiro64s;
+/// let mut rng = Xoroshiro64s::new([1, 2]);
+/// 
+/// assert_eq!(rng.get(), 2654435771);
+/// assert_eq!(rng.get(), 327208753);
+/// assert_eq!(rng.get(), 4063491769);
+/// assert_eq!(rng.get(), 4259754937);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Xoroshiro64s {
+	seed: (u32, u32),
+}
+
+impl Xoroshiro64s {
+	#[inline]
+	pub const fn new_raw(seed: [u32; 2]) -> Self {
+		Self {
+			seed: (seed[0], seed[1]),
+		}
+	}
+
+	#[inline]
+	pub const fn new(mut seed: [u32; 2]) -> Self {
+		seed[0] = crate::common::u32_or_1(seed[0]);
+		seed[1] = crate::common::u32_or_1(seed[1]);
+		Self::new_raw(seed)
+	}
+
+	/// construct a new `Xoroshiro64s`, returning [`crate::Error::ZeroSeed`]
+	/// if any part of `seed` is `0`, instead of adjusting it like
+	/// [`Self::new()`].
+	#[inline]
+	pub const fn try_new(seed: [u32; 2]) -> Result<Self, crate::Error> {
+		if seed[0] == 0 || seed[1] == 0 {
+			return Err(crate::Error::ZeroSeed);
+		}
+		Ok(Self::new_raw(seed))
+	}
+
+	#[inline]
+	pub const fn get(&mut self) -> u32 {
+		let result = self.seed.0.wrapping_mul(0x9e3779bb);
+		step(&mut self.seed);
+		result
+	}
+}
+
+impl crate::RandomImpl for Xoroshiro64s {
+	#[inline]
+	fn random_u64(&mut self) -> u64 {
+		crate::common::u32_compose_u64(self.get(), self.get())
+	}
+
+	#[inline]
+	fn random_u32(&mut self) -> u32 {
+		self.get()
+	}
+
+	fn random_bytes(&mut self, dst: &mut [u8]) {
+		crate::common::bytes_from_u32(self, dst);
+	}
+}
+
+impl crate::FromRandom for Xoroshiro64s {
+	fn from_random(random: &mut impl crate::Random) -> Self {
+		Self::new(random.random())
+	}
+}
+
+impl core::fmt::Debug for Xoroshiro64s {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		write!(f, "Xoroshiro64s")
+	}
+}
+
+// the linear engine shared by both scramblers
+#[inline(always)]
+const fn step(seed: &mut (u32, u32)) {
+	let s0 = seed.0;
+	let s1 = seed.1 ^ s0;
+	seed.0 = s0.rotate_left(26) ^ s1 ^ (s1 << 9);
+	seed.1 = s1.rotate_left(13);
+}
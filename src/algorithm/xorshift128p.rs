@@ -10,7 +10,7 @@
 /// assert_eq!(rng.get(), 703687785278400);
 /// assert_eq!(rng.get(), 2111062671688522);
 /// ```
-#[derive(Clone)]
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub struct XorShift128p {
 	seed: (u64, u64),
 }
@@ -30,6 +30,17 @@ impl XorShift128p {
 		Self::new_raw(seed)
 	}
 
+	/// construct a new `XorShift128p`, returning [`crate::Error::ZeroSeed`]
+	/// if any part of `seed` is `0`, instead of adjusting it like
+	/// [`Self::new()`].
+	#[inline]
+	pub const fn try_new(seed: [u64; 2]) -> Result<Self, crate::Error> {
+		if seed[0] == 0 || seed[1] == 0 {
+			return Err(crate::Error::ZeroSeed);
+		}
+		Ok(Self::new_raw(seed))
+	}
+
 	#[inline]
 	pub const fn get(&mut self) -> u64 {
 		let mut t: u64 = self.seed.0;
@@ -59,6 +70,12 @@ impl crate::RandomImpl for XorShift128p {
 	}
 }
 
+impl crate::FromRandom for XorShift128p {
+	fn from_random(random: &mut impl crate::Random) -> Self {
+		Self::new(random.random())
+	}
+}
+
 impl core::fmt::Debug for XorShift128p {
 	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
 		write!(f, "XorShift128p")
@@ -17,7 +17,7 @@
 /// assert_eq!(rng.get(), 2647435461);
 /// assert_eq!(rng.get(), 307599695);
 /// ```
-#[derive(Clone)]
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub struct XorShift32 {
 	seed: u32,
 }
@@ -76,6 +76,22 @@ impl XorShift32 {
 		Self::new_raw(seed)
 	}
 
+	/// construct a new [`XorShift32`], returning [`crate::Error::ZeroSeed`]
+	/// if `seed` is `0`, instead of adjusting it like [`Self::new()`].
+	/// 
+	/// ```
+	/// # use prrng::XorShift32;
+	/// assert!(XorShift32::try_new(1).is_ok());
+	/// assert!(XorShift32::try_new(0).is_err());
+	/// ```
+	#[inline]
+	pub const fn try_new(seed: u32) -> Result<Self, crate::Error> {
+		if seed == 0 {
+			return Err(crate::Error::ZeroSeed);
+		}
+		Ok(Self::new_raw(seed))
+	}
+
 	#[inline]
 	pub const fn get(&mut self) -> u32 {
 		let mut x = self.seed;
@@ -103,6 +119,12 @@ impl crate::RandomImpl for XorShift32 {
 	}
 }
 
+impl crate::FromRandom for XorShift32 {
+	fn from_random(random: &mut impl crate::Random) -> Self {
+		Self::new(random.random())
+	}
+}
+
 impl core::fmt::Debug for XorShift32 {
 	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
 		write!(f, "XorShift32")
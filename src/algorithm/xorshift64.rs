@@ -10,7 +10,7 @@
 /// assert_eq!(rng.get(), 11177516664432764457);
 /// assert_eq!(rng.get(), 17678023832001937445);
 /// ```
-#[derive(Clone)]
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub struct XorShift64 {
 	seed: u64,
 }
@@ -29,6 +29,16 @@ impl XorShift64 {
 		Self::new_raw(seed)
 	}
 
+	/// construct a new `XorShift64`, returning [`crate::Error::ZeroSeed`]
+	/// if `seed` is `0`, instead of adjusting it like [`Self::new()`].
+	#[inline]
+	pub const fn try_new(seed: u64) -> Result<Self, crate::Error> {
+		if seed == 0 {
+			return Err(crate::Error::ZeroSeed);
+		}
+		Ok(Self::new_raw(seed))
+	}
+
 	#[inline]
 	pub const fn get(&mut self) -> u64 {
 		let mut x = self.seed;
@@ -56,6 +66,12 @@ impl crate::RandomImpl for XorShift64 {
 	}
 }
 
+impl crate::FromRandom for XorShift64 {
+	fn from_random(random: &mut impl crate::Random) -> Self {
+		Self::new(random.random())
+	}
+}
+
 impl core::fmt::Debug for XorShift64 {
 	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
 		write!(f, "XorShift64")
@@ -1,5 +1,5 @@
 
-#[derive(Clone)]
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub struct XorShift256ss {
 	seed: (u64, u64, u64, u64),
 }
@@ -21,6 +21,21 @@ impl XorShift256ss {
 		Self::new_raw(seed)
 	}
 
+	/// construct a new `XorShift256ss`, returning [`crate::Error::ZeroSeed`]
+	/// if any part of `seed` is `0`, instead of adjusting it like
+	/// [`Self::new()`].
+	#[inline]
+	pub const fn try_new(seed: [u64; 4]) -> Result<Self, crate::Error> {
+		let mut i = 0;
+		while i < seed.len() {
+			if seed[i] == 0 {
+				return Err(crate::Error::ZeroSeed);
+			}
+			i += 1;
+		}
+		Ok(Self::new_raw(seed))
+	}
+
 	#[inline]
 	pub const fn get(&mut self) -> u64 {
 		let result = self.seed.1
@@ -57,6 +72,12 @@ impl crate::RandomImpl for XorShift256ss {
 	}
 }
 
+impl crate::FromRandom for XorShift256ss {
+	fn from_random(random: &mut impl crate::Random) -> Self {
+		Self::new(random.random())
+	}
+}
+
 impl core::fmt::Debug for XorShift256ss {
 	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
 		write!(f, "XorShift256ss")
@@ -0,0 +1,189 @@
+/// [512bit xoshiro512**](https://prng.di.unimi.it/) psuedo-rng. yields u64 values.
+/// 
+/// this is the same design as [`crate::XorShift256ss`], with twice the
+/// state, and a period of `2^512 - 1`. it is a little slower, and the extra
+/// state is rarely worth it: [`crate::XorShift256ss`] already has a period
+/// far beyond anything that could be consumed.
+/// 
+/// where the extra state helps is splitting one seed into many
+/// non-overlapping sub-streams, see [`Self::jump()`]. each jump skips ahead
+/// `2^256` values, so `2^256` sub-streams of that length are available,
+/// instead of `2^128` sub-streams of `2^128` values.
+/// 
+/// ```
+/// # use prrng::Xoshiro512ss;
+/// let mut rng = Xoshiro512ss::new([1, 2, 3, 4, 5, 6, 7, 8]);
+/// 
+/// assert_eq!(rng.get(), 11520);
+/// assert_eq!(rng.get(), 0);
+/// assert_eq!(rng.get(), 23040);
+/// assert_eq!(rng.get(), 23667840);
+/// ```
+#[derive(Clone, PartialEq, Eq)]
+pub struct Xoshiro512ss {
+	seed: [u64; 8],
+}
+
+const JUMP: [u64; 8] = [
+	0x33ed89b6e7a353f9,
+	0x760083d7955323be,
+	0x2837f2fbb5f22fae,
+	0x4b8c5674d309511c,
+	0xb11ac47a7ba28c25,
+	0xf1be7667092bcc1c,
+	0x53851efdb6df0aaf,
+	0x1ebbc8b23eaf25db,
+];
+
+impl Xoshiro512ss {
+	#[inline]
+	pub const fn new_raw(seed: [u64; 8]) -> Self {
+		Self {
+			seed,
+		}
+	}
+
+	#[inline]
+	pub const fn new(mut seed: [u64; 8]) -> Self {
+		let mut i = 0;
+		while i < seed.len() {
+			seed[i] = crate::common::u64_or_1(seed[i]);
+			i += 1;
+		}
+		Self::new_raw(seed)
+	}
+
+	/// construct a new `Xoshiro512ss`, returning [`crate::Error::ZeroSeed`]
+	/// if any part of `seed` is `0`, instead of adjusting it like
+	/// [`Self::new()`].
+	#[inline]
+	pub const fn try_new(seed: [u64; 8]) -> Result<Self, crate::Error> {
+		let mut i = 0;
+		while i < seed.len() {
+			if seed[i] == 0 {
+				return Err(crate::Error::ZeroSeed);
+			}
+			i += 1;
+		}
+		Ok(Self::new_raw(seed))
+	}
+
+	#[inline]
+	pub const fn get(&mut self) -> u64 {
+		let s = &mut self.seed;
+		let result = s[1]
+			.wrapping_mul(5)
+			.rotate_left(7)
+			.wrapping_mul(9);
+		let t = s[1] << 11;
+
+		s[2] ^= s[0];
+		s[5] ^= s[1];
+		s[1] ^= s[2];
+		s[7] ^= s[3];
+		s[3] ^= s[4];
+		s[4] ^= s[5];
+		s[0] ^= s[6];
+		s[6] ^= s[7];
+
+		s[6] ^= t;
+		s[7] = s[7].rotate_left(21);
+
+		result
+	}
+
+	/// advance the generator by `2^256` values, as if [`Self::get()`] was
+	/// called that many times.
+	/// 
+	/// calling this repeatedly on a clone gives non-overlapping sub-streams,
+	/// for example one per thread.
+	/// 
+	/// ```
+	/// # use prrng::Xoshiro512ss;
+	/// let mut rng = Xoshiro512ss::new([1, 2, 3, 4, 5, 6, 7, 8]);
+	/// rng.jump();
+	/// 
+	/// assert_eq!(rng.get(), 9855632635473413185);
+	/// assert_eq!(rng.get(), 8685991250662704880);
+	/// ```
+	pub const fn jump(&mut self) {
+		let mut t = [0; 8];
+
+		let mut i = 0;
+		while i < JUMP.len() {
+			let mut b = 0;
+			while b < 64 {
+				if JUMP[i] & 1 << b != 0 {
+					let mut w = 0;
+					while w < t.len() {
+						t[w] ^= self.seed[w];
+						w += 1;
+					}
+				}
+				self.get();
+				b += 1;
+			}
+			i += 1;
+		}
+
+		self.seed = t;
+	}
+}
+
+impl crate::RandomImpl for Xoshiro512ss {
+	#[inline]
+	fn random_u64(&mut self) -> u64 {
+		self.get()
+	}
+
+	#[inline]
+	fn random_u32(&mut self) -> u32 {
+		self.get() as u32
+	}
+
+	fn random_bytes(&mut self, dst: &mut [u8]) {
+		crate::common::bytes_from_u64(self, dst);
+	}
+}
+
+impl crate::FromRandom for Xoshiro512ss {
+	fn from_random(random: &mut impl crate::Random) -> Self {
+		Self::new(random.random())
+	}
+}
+
+impl core::fmt::Debug for Xoshiro512ss {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		write!(f, "Xoshiro512ss")
+	}
+}
+
+
+#[cfg(test)]
+mod test {
+	use crate::Xoshiro512ss;
+
+	#[test]
+	fn test_jump() {
+		let mut rng = Xoshiro512ss::new([1, 2, 3, 4, 5, 6, 7, 8]);
+		rng.jump();
+
+		// computed with the reference algorithm
+		assert!(rng == Xoshiro512ss::new_raw([
+			0x362505100e9f7d7c,
+			0x63fab37a35129580,
+			0xac6a00ec8dc639a2,
+			0xded17b8d82675240,
+			0x72579e2a291b4b08,
+			0xc67538b8bc1fb96d,
+			0x381684e2d1d18563,
+			0xcf5958f38a851658,
+		]));
+
+		// sub-streams don't start on the same values
+		let mut a = rng.clone();
+		let mut b = rng.clone();
+		b.jump();
+		assert!((0..64).all(|_| a.get() != b.get()));
+	}
+}
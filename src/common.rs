@@ -1,6 +1,24 @@
 //! this module provides a few common, simple utility functions you might
 //! find useful when implementing your own [`crate::RandomImpl`] types.
 
+/// construct a `u16` with [`crate::RandomImpl::random_bytes()`] with
+/// little-endian ordering.
+#[inline(always)]
+pub fn u16_from_bytes<R: crate::RandomImpl>(random: &mut R) -> u16 {
+	let mut bytes = [0u8; _];
+	random.random_bytes(&mut bytes);
+	u16::from_le_bytes(bytes)
+}
+
+/// construct a `u128` with [`crate::RandomImpl::random_bytes()`] with
+/// little-endian ordering.
+#[inline(always)]
+pub fn u128_from_bytes<R: crate::RandomImpl>(random: &mut R) -> u128 {
+	let mut bytes = [0u8; _];
+	random.random_bytes(&mut bytes);
+	u128::from_le_bytes(bytes)
+}
+
 /// construct a `u64` with [`crate::RandomImpl::random_bytes()`] with
 /// little-endian ordering.
 #[inline(always)]
@@ -68,6 +86,53 @@ pub fn bytes_from_u64<R: crate::RandomImpl>(random: &mut R, dst: &mut [u8]) {
 	}
 }
 
+/// fill a buffer with `u128` values from `next`, with little-endian ordering.
+/// 
+/// since [`crate::RandomImpl`] has no `u128` method, this takes a closure,
+/// usually wrapping a generator's own `get()`.
+/// 
+/// ```
+/// # use prrng::common::bytes_from_u128;
+/// let mut values = [
+///     0x0f_0e_0d_0c_0b_0a_09_08_07_06_05_04_03_02_01_00u128,
+///     0x1f_1e_1d_1c_1b_1a_19_18_17_16_15_14_13_12_11_10u128,
+/// ].into_iter();
+/// 
+/// // 16 + 4 bytes
+/// let mut buf = [0u8; 20];
+/// 
+/// bytes_from_u128(|| values.next().unwrap(), &mut buf);
+/// 
+/// assert_eq!(
+///     buf,
+///     [
+///         // first value
+///         0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07,
+///         0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f,
+///         // second value (cut off)
+///         0x10, 0x11, 0x12, 0x13,
+///     ],
+/// );
+/// ```
+#[inline(always)]
+pub fn bytes_from_u128<F: FnMut() -> u128>(mut next: F, dst: &mut [u8]) {
+	let (chunks, extra) = dst.as_chunks_mut();
+
+	for chunk in chunks {
+		*chunk = next().to_le_bytes();
+	}
+
+	if extra.is_empty() {
+		return;
+	}
+
+	let last = next().to_le_bytes();
+
+	for (o, i) in extra.iter_mut().zip(last.iter()) {
+		*o = *i;
+	}
+}
+
 /// fill a buffer with values from [`crate::RandomImpl::random_u32()`], with
 /// little-endian ordering.
 /// 
@@ -117,6 +182,119 @@ pub fn bytes_from_u32<R: crate::RandomImpl>(random: &mut R, dst: &mut [u8]) {
 	}
 }
 
+/// fill a buffer with `u16` values from `next`, with little-endian ordering.
+/// 
+/// since [`crate::RandomImpl`] has no `u16` method, this takes a closure,
+/// usually wrapping a generator's own `get()`.
+/// 
+/// ```
+/// # use prrng::common::bytes_from_u16;
+/// # use prrng::FibLFSR16;
+/// let mut rng = FibLFSR16::new(1);
+/// 
+/// {
+///     let mut rng = rng.clone();
+///     // demonstrating the first two values of this generator:
+///     assert_eq!(rng.get(), 0x68_01);
+///     assert_eq!(rng.get(), 0x14_41);
+/// }
+/// 
+/// // 2 + 1 bytes
+/// let mut buf = [0u8; 3];
+/// 
+/// bytes_from_u16(|| rng.get(), &mut buf);
+/// 
+/// assert_eq!(
+///     buf,
+///     [
+///         // first value
+///         0x01, 0x68,
+///         // second value (cut off)
+///         0x41,
+///     ],
+/// );
+/// ```
+#[inline(always)]
+pub fn bytes_from_u16<F: FnMut() -> u16>(mut next: F, dst: &mut [u8]) {
+	let (chunks, extra) = dst.as_chunks_mut();
+
+	for chunk in chunks {
+		*chunk = next().to_le_bytes();
+	}
+
+	if extra.is_empty() {
+		return;
+	}
+
+	let last = next().to_le_bytes();
+
+	for (o, i) in extra.iter_mut().zip(last.iter()) {
+		*o = *i;
+	}
+}
+
+/// returns a new `u32`, uniformly distributed within `0 .. bound`, using
+/// bitmask rejection.
+/// 
+/// values are masked to the smallest power of two that is at least `bound`,
+/// and drawn again if they are not below `bound`. this never divides, and
+/// less than half of all values are rejected, so on average, less than 2
+/// values are consumed. this is unlike
+/// [`crate::Random::random_u32_bound()`], and will return different values.
+/// 
+/// ```
+/// # use prrng::common::bounded_u32;
+/// # use prrng::XorShift32;
+/// let mut rng = XorShift32::new(1);
+/// 
+/// for _ in 0..100 {
+///     assert!(bounded_u32(&mut rng, 10) < 10);
+/// }
+/// ```
+/// 
+/// ## panics
+/// 
+/// panics if `bound` is `0`.
+#[inline]
+pub fn bounded_u32<R: crate::RandomImpl>(random: &mut R, bound: u32) -> u32 {
+	assert!(bound != 0, "`bound` must not be 0");
+	let mask = u32::MAX.checked_shr((bound - 1).leading_zeros()).unwrap_or(0);
+	loop {
+		let x = random.random_u32() & mask;
+		if x < bound {
+			return x;
+		}
+	}
+}
+
+/// returns a new `u64`, uniformly distributed within `0 .. bound`, using
+/// bitmask rejection. see [`bounded_u32()`].
+/// 
+/// ```
+/// # use prrng::common::bounded_u64;
+/// # use prrng::XorShift64;
+/// let mut rng = XorShift64::new(1);
+/// 
+/// for _ in 0..100 {
+///     assert!(bounded_u64(&mut rng, 10) < 10);
+/// }
+/// ```
+/// 
+/// ## panics
+/// 
+/// panics if `bound` is `0`.
+#[inline]
+pub fn bounded_u64<R: crate::RandomImpl>(random: &mut R, bound: u64) -> u64 {
+	assert!(bound != 0, "`bound` must not be 0");
+	let mask = u64::MAX.checked_shr((bound - 1).leading_zeros()).unwrap_or(0);
+	loop {
+		let x = random.random_u64() & mask;
+		if x < bound {
+			return x;
+		}
+	}
+}
+
 /// construct a `u128` from two `u64`.
 /// 
 /// ```
@@ -134,6 +312,123 @@ pub const fn u64_compose_u128(x: u64, y: u64) -> u128 {
 	(x as u128) << 64 | y as u128
 }
 
+/// split a `u128` into two `u64`, as `(high, low)`. this is the inverse of
+/// [`u64_compose_u128()`].
+/// 
+/// ```
+/// # use prrng::common::u128_split_u64;
+/// assert_eq!(
+///     u128_split_u64(0xf0f0f0f0f0f0f0f07070707070707070),
+///     (0xf0f0f0f0f0f0f0f0, 0x7070707070707070),
+/// );
+/// ```
+#[inline(always)]
+pub const fn u128_split_u64(x: u128) -> (u64, u64) {
+	((x >> 64) as u64, x as u64)
+}
+
+/// fill a slice with values from [`crate::RandomImpl::random_u32()`].
+/// 
+/// ```
+/// # use prrng::common::fill_u32_slice;
+/// # use prrng::XorShift32;
+/// let mut rng = XorShift32::new(1);
+/// 
+/// let mut buf = [0u32; 2];
+/// fill_u32_slice(&mut rng, &mut buf);
+/// 
+/// assert_eq!(buf, [0x00_04_20_21, 0x04_08_06_01]);
+/// ```
+#[inline]
+pub fn fill_u32_slice<R: crate::RandomImpl>(random: &mut R, dst: &mut [u32]) {
+	for i in dst {
+		*i = random.random_u32();
+	}
+}
+
+/// fill a slice with values from [`crate::RandomImpl::random_u64()`].
+/// 
+/// ```
+/// # use prrng::common::fill_u64_slice;
+/// # use prrng::XorShift64;
+/// let mut rng = XorShift64::new(1);
+/// 
+/// let mut buf = [0u64; 2];
+/// fill_u64_slice(&mut rng, &mut buf);
+/// 
+/// assert_eq!(buf, [0x00_00_00_00_40_82_20_41, 0x10_00_41_06_0c_01_14_41]);
+/// ```
+#[inline]
+pub fn fill_u64_slice<R: crate::RandomImpl>(random: &mut R, dst: &mut [u64]) {
+	for i in dst {
+		*i = random.random_u64();
+	}
+}
+
+/// fill a slice with a single call to [`crate::RandomImpl::random_bytes()`],
+/// reading each value with little-endian ordering.
+/// 
+/// this may be faster than [`fill_u32_slice()`] for generators with an
+/// efficient `random_bytes`. for generators that implement `random_bytes`
+/// with [`bytes_from_u32()`], both produce the same values.
+/// 
+/// ```
+/// # use prrng::common::fill_u32_slice_from_bytes;
+/// # use prrng::XorShift32;
+/// let mut rng = XorShift32::new(1);
+/// 
+/// let mut buf = [0u32; 2];
+/// fill_u32_slice_from_bytes(&mut rng, &mut buf);
+/// 
+/// assert_eq!(buf, [0x00_04_20_21, 0x04_08_06_01]);
+/// ```
+#[inline]
+pub fn fill_u32_slice_from_bytes<R: crate::RandomImpl>(random: &mut R, dst: &mut [u32]) {
+	let bytes = unsafe {
+		// safety:
+		// `u8` has an alignment of 1, the length covers exactly the same
+		// memory as `dst`, and any bytes are a valid `u32`.
+		core::slice::from_raw_parts_mut(dst.as_mut_ptr().cast::<u8>(), core::mem::size_of_val(dst))
+	};
+	random.random_bytes(bytes);
+
+	for i in dst {
+		*i = u32::from_le(*i);
+	}
+}
+
+/// fill a slice with a single call to [`crate::RandomImpl::random_bytes()`],
+/// reading each value with little-endian ordering.
+/// 
+/// this may be faster than [`fill_u64_slice()`] for generators with an
+/// efficient `random_bytes`. for generators that implement `random_bytes`
+/// with [`bytes_from_u64()`], both produce the same values.
+/// 
+/// ```
+/// # use prrng::common::fill_u64_slice_from_bytes;
+/// # use prrng::XorShift64;
+/// let mut rng = XorShift64::new(1);
+/// 
+/// let mut buf = [0u64; 2];
+/// fill_u64_slice_from_bytes(&mut rng, &mut buf);
+/// 
+/// assert_eq!(buf, [0x00_00_00_00_40_82_20_41, 0x10_00_41_06_0c_01_14_41]);
+/// ```
+#[inline]
+pub fn fill_u64_slice_from_bytes<R: crate::RandomImpl>(random: &mut R, dst: &mut [u64]) {
+	let bytes = unsafe {
+		// safety:
+		// `u8` has an alignment of 1, the length covers exactly the same
+		// memory as `dst`, and any bytes are a valid `u64`.
+		core::slice::from_raw_parts_mut(dst.as_mut_ptr().cast::<u8>(), core::mem::size_of_val(dst))
+	};
+	random.random_bytes(bytes);
+
+	for i in dst {
+		*i = u64::from_le(*i);
+	}
+}
+
 /// construct a `u64` from two `u32`.
 /// 
 /// ```
@@ -167,25 +462,187 @@ pub const fn u8_compose_u16(x: u8, y: u8) -> u16 {
 	(x as u16) << 8 | y as u16
 }
 
+// returns the full 256bit product of `x * y`, as `(high, low)`.
+#[inline]
+pub(crate) const fn u128_widening_mul(x: u128, y: u128) -> (u128, u128) {
+	let (x0, x1) = (x as u64 as u128, x >> 64);
+	let (y0, y1) = (y as u64 as u128, y >> 64);
+
+	let p00 = x0 * y0;
+	let p01 = x0 * y1;
+	let p10 = x1 * y0;
+	let p11 = x1 * y1;
+
+	let mid = (p00 >> 64) + (p01 as u64 as u128) + (p10 as u64 as u128);
+
+	let lo = (p00 as u64 as u128) | (mid << 64);
+	let hi = p11 + (p01 >> 64) + (p10 >> 64) + (mid >> 64);
+
+	(hi, lo)
+}
+
+/// scale a `f64` in `[0, 1]` to the full range of `u64`.
+/// 
+/// inputs outside of `[0, 1]` are clamped, and `NaN` becomes `0`.
+/// 
+/// ```
+/// # use prrng::common::f64_to_u64;
+/// assert_eq!(f64_to_u64(0.0), 0);
+/// assert_eq!(f64_to_u64(1.0 - f64::EPSILON), u64::MAX - 0xfff);
+/// assert_eq!(f64_to_u64(1.0), u64::MAX);
+/// 
+/// assert_eq!(f64_to_u64(-1.0), 0);
+/// assert_eq!(f64_to_u64(2.0), u64::MAX);
+/// assert_eq!(f64_to_u64(f64::NAN), 0);
+/// ```
+#[inline(always)]
+pub const fn f64_to_u64(x: f64) -> u64 {
+	// `as` saturates, and turns `NaN` into `0`
+	(x * u64::MAX as f64) as u64
+}
+
+/// scale a `f64` in `[0, 1]` to the full range of `u32`.
+/// 
+/// inputs outside of `[0, 1]` are clamped, and `NaN` becomes `0`.
+/// 
+/// ```
+/// # use prrng::common::f64_to_u32;
+/// assert_eq!(f64_to_u32(0.0), 0);
+/// assert_eq!(f64_to_u32(1.0 - f64::EPSILON), u32::MAX - 1);
+/// assert_eq!(f64_to_u32(1.0), u32::MAX);
+/// 
+/// assert_eq!(f64_to_u32(-1.0), 0);
+/// assert_eq!(f64_to_u32(2.0), u32::MAX);
+/// assert_eq!(f64_to_u32(f64::NAN), 0);
+/// ```
 #[inline(always)]
-pub(crate) const fn f64_to_u32(x: f64) -> u32 {
+pub const fn f64_to_u32(x: f64) -> u32 {
+	// `as` saturates, and turns `NaN` into `0`
 	(x * u32::MAX as f64) as u32
 }
 
+/// scale a `f64` in `[0, 1]` to the full range of `u16`.
+/// 
+/// inputs outside of `[0, 1]` are clamped, and `NaN` becomes `0`.
+/// 
+/// ```
+/// # use prrng::common::f64_to_u16;
+/// assert_eq!(f64_to_u16(0.0), 0);
+/// assert_eq!(f64_to_u16(1.0 - f64::EPSILON), u16::MAX - 1);
+/// assert_eq!(f64_to_u16(1.0), u16::MAX);
+/// 
+/// assert_eq!(f64_to_u16(-1.0), 0);
+/// assert_eq!(f64_to_u16(2.0), u16::MAX);
+/// assert_eq!(f64_to_u16(f64::NAN), 0);
+/// ```
 #[inline(always)]
-pub(crate) const fn u64_normalize_f64(x: u64) -> f64 {
+pub const fn f64_to_u16(x: f64) -> u16 {
+	// `as` saturates, and turns `NaN` into `0`
+	(x * u16::MAX as f64) as u16
+}
+
+/// scale a `f64` in `[0, 1]` to the full range of `u8`.
+/// 
+/// inputs outside of `[0, 1]` are clamped, and `NaN` becomes `0`.
+/// 
+/// ```
+/// # use prrng::common::f64_to_u8;
+/// assert_eq!(f64_to_u8(0.0), 0);
+/// assert_eq!(f64_to_u8(1.0 - f64::EPSILON), u8::MAX - 1);
+/// assert_eq!(f64_to_u8(1.0), u8::MAX);
+/// 
+/// assert_eq!(f64_to_u8(-1.0), 0);
+/// assert_eq!(f64_to_u8(2.0), u8::MAX);
+/// assert_eq!(f64_to_u8(f64::NAN), 0);
+/// ```
+#[inline(always)]
+pub const fn f64_to_u8(x: f64) -> u8 {
+	// `as` saturates, and turns `NaN` into `0`
+	(x * u8::MAX as f64) as u8
+}
+
+/// scale a `f32` in `[0, 1]` to the full range of `u32`.
+/// 
+/// inputs outside of `[0, 1]` are clamped, and `NaN` becomes `0`.
+/// 
+/// ```
+/// # use prrng::common::f32_to_u32;
+/// assert_eq!(f32_to_u32(0.0), 0);
+/// assert_eq!(f32_to_u32(1.0 - f32::EPSILON), u32::MAX - 0x1ff);
+/// assert_eq!(f32_to_u32(1.0), u32::MAX);
+/// 
+/// assert_eq!(f32_to_u32(-1.0), 0);
+/// assert_eq!(f32_to_u32(2.0), u32::MAX);
+/// assert_eq!(f32_to_u32(f32::NAN), 0);
+/// ```
+#[inline(always)]
+pub const fn f32_to_u32(x: f32) -> u32 {
+	// `as` saturates, and turns `NaN` into `0`
+	(x * u32::MAX as f32) as u32
+}
+
+/// construct a `f64` in `[0, 1)` from the low 52 bits of `x`.
+/// 
+/// ```
+/// # use prrng::common::u64_normalize_f64;
+/// assert_eq!(u64_normalize_f64(0), 0.0);
+/// assert_eq!(u64_normalize_f64(1 << 51), 0.5);
+/// assert_eq!(u64_normalize_f64(u64::MAX), 1.0 - f64::EPSILON);
+/// 
+/// // the high 12 bits are ignored
+/// assert_eq!(u64_normalize_f64(0xfff0_0000_0000_0000), 0.0);
+/// ```
+#[inline(always)]
+pub const fn u64_normalize_f64(x: u64) -> f64 {
 	let x = x & 0x00_0f_ff_ff_ff_ff_ff_ff;
 	let x = x | 0x3f_f0_00_00_00_00_00_00;
 	f64::from_bits(x) - 1.0
 }
 
+/// construct a `f64` in `(0, 1)` from the low 52 bits of `x`.
+/// 
+/// this is [`u64_normalize_f64()`], shifted up by half a step, so neither
+/// `0` nor `1` can be returned. the results are still evenly spaced.
+/// 
+/// ```
+/// # use prrng::common::u64_normalize_f64_open;
+/// assert_eq!(u64_normalize_f64_open(0), f64::EPSILON / 2.0);
+/// assert_eq!(u64_normalize_f64_open(1 << 51), 0.5 + f64::EPSILON / 2.0);
+/// assert_eq!(u64_normalize_f64_open(u64::MAX), 1.0 - f64::EPSILON / 2.0);
+/// ```
 #[inline(always)]
-pub(crate) const fn u32_normalize_f32(x: u32) -> f32 {
+pub const fn u64_normalize_f64_open(x: u64) -> f64 {
+	let x = x & 0x00_0f_ff_ff_ff_ff_ff_ff;
+	(x as f64 + 0.5) * f64::EPSILON
+}
+
+/// construct a `f32` in `[0, 1)` from the low 23 bits of `x`.
+/// 
+/// ```
+/// # use prrng::common::u32_normalize_f32;
+/// assert_eq!(u32_normalize_f32(0), 0.0);
+/// assert_eq!(u32_normalize_f32(1 << 22), 0.5);
+/// assert_eq!(u32_normalize_f32(u32::MAX), 1.0 - f32::EPSILON);
+/// 
+/// // the high 9 bits are ignored
+/// assert_eq!(u32_normalize_f32(0xff80_0000), 0.0);
+/// ```
+#[inline(always)]
+pub const fn u32_normalize_f32(x: u32) -> f32 {
 	let x = x & 0x00_7f_ff_ff;
 	let x = x | 0x3f_80_00_00;
 	f32::from_bits(x) - 1.0
 }
 
+#[inline(always)]
+pub(crate) const fn u128_or_1(x: u128) -> u128 {
+	if x == 0 {
+		1
+	} else {
+		x
+	}
+}
+
 #[inline(always)]
 pub(crate) const fn u64_or_1(x: u64) -> u64 {
 	if x == 0 {
@@ -225,7 +682,7 @@ pub(crate) const fn u8_or_1(x: u8) -> u8 {
 
 #[cfg(test)]
 mod test {
-    use crate::common::{f64_to_u32, u32_normalize_f32, u64_normalize_f64};
+	use crate::common::{bounded_u32, bounded_u64, bytes_from_u128, bytes_from_u64, f32_to_u32, fill_u32_slice, fill_u32_slice_from_bytes, fill_u64_slice, fill_u64_slice_from_bytes, f64_to_u32, f64_to_u64, u128_split_u64, u128_widening_mul, u32_normalize_f32, u64_compose_u128, u64_normalize_f64};
 
 	#[test]
 	fn test_private() {
@@ -237,6 +694,150 @@ mod test {
 
 		assert_eq!(f64_to_u32(0.0f64), 0u32);
 		assert_eq!(f64_to_u32(1.0f64), u32::MAX);
+
+		assert_eq!(u128_widening_mul(0, u128::MAX), (0, 0));
+		assert_eq!(u128_widening_mul(1 << 64, 1 << 64), (1, 0));
+		assert_eq!(u128_widening_mul(u128::MAX, u128::MAX), (u128::MAX - 1, 1));
+		assert_eq!(u128_widening_mul(u64::MAX as u128, 3), (0, u64::MAX as u128 * 3));
 	}
-}
 
+	#[test]
+	fn test_normalize_round_trip() {
+		let mut x = 0x853c49e6748fea9bu64;
+		for _ in 0..1024 {
+			x = x.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+
+			let y = u64_normalize_f64(x);
+			assert!((0.0..1.0).contains(&y));
+			assert_eq!(f64_to_u64(y), (x & 0x000f_ffff_ffff_ffff) << 12);
+
+			let y = u32_normalize_f32(x as u32);
+			assert!((0.0..1.0).contains(&y));
+			assert_eq!(f32_to_u32(y), (x as u32 & 0x007f_ffff) << 9);
+		}
+	}
+
+	#[test]
+	fn test_u128() {
+		let x = 0x0123456789abcdef_fedcba9876543210u128;
+
+		let (hi, lo) = u128_split_u64(x);
+		assert_eq!(u64_compose_u128(hi, lo), x);
+
+		let mut buf = [0u8; 16];
+		bytes_from_u128(|| x, &mut buf);
+		assert_eq!(buf, x.to_le_bytes());
+	}
+
+	#[test]
+	fn test_fill() {
+		use crate::{XorShift32, XorShift64};
+
+		for len in [0, 1, 2, 7, 64] {
+			let mut a = XorShift32::new(1);
+			let mut b = XorShift32::new(1);
+			let mut x = [0u32; 64];
+			let mut y = [0u32; 64];
+			fill_u32_slice(&mut a, &mut x[..len]);
+			fill_u32_slice_from_bytes(&mut b, &mut y[..len]);
+			assert_eq!(x, y);
+			assert_eq!(a.get(), b.get());
+
+			let mut a = XorShift64::new(1);
+			let mut b = XorShift64::new(1);
+			let mut x = [0u64; 64];
+			let mut y = [0u64; 64];
+			fill_u64_slice(&mut a, &mut x[..len]);
+			fill_u64_slice_from_bytes(&mut b, &mut y[..len]);
+			assert_eq!(x, y);
+			assert_eq!(a.get(), b.get());
+		}
+	}
+
+	#[test]
+	fn test_bytes_u64() {
+		use crate::{SplitMix64, XorShift128p, XorShift256ss, Xoshiro512ss};
+
+		fn check<R: crate::RandomImpl + Clone>(rng: R) {
+			for len in [0, 1, 7, 31, 32, 33, 4096] {
+				let mut a = rng.clone();
+				let mut b = rng.clone();
+				let mut x = [0u8; 4096];
+				let mut y = [0u8; 4096];
+				bytes_from_u64(&mut a, &mut x[..len]);
+				b.random_bytes(&mut y[..len]);
+				assert_eq!(x, y);
+				assert_eq!(a.random_u64(), b.random_u64());
+			}
+		}
+
+		check(SplitMix64::new(1));
+		check(XorShift128p::new([1, 2]));
+		check(XorShift256ss::new([1, 2, 3, 4]));
+		check(Xoshiro512ss::new([1, 2, 3, 4, 5, 6, 7, 8]));
+	}
+
+	struct Count(u64);
+
+	impl crate::RandomImpl for Count {
+		fn random_u64(&mut self) -> u64 {
+			self.0 += 1;
+			self.0 - 1
+		}
+
+		fn random_u32(&mut self) -> u32 {
+			self.random_u64() as u32
+		}
+
+		fn random_bytes(&mut self, dst: &mut [u8]) {
+			crate::common::bytes_from_u64(self, dst);
+		}
+	}
+
+	#[test]
+	fn test_bounded_exhaustive() {
+		// counting through every masked value accepts each value below
+		// `bound` exactly once, in order
+		for bound in 1..=300 {
+			let mut rng = Count(0);
+			for i in 0..bound {
+				assert_eq!(bounded_u32(&mut rng, bound), i);
+			}
+
+			let mut rng = Count(0);
+			for i in 0..bound as u64 {
+				assert_eq!(bounded_u64(&mut rng, bound as u64), i);
+			}
+		}
+
+		// `u32::MAX` is rejected, then `0` is accepted
+		let mut rng = Count(u32::MAX as u64);
+		assert_eq!(bounded_u32(&mut rng, u32::MAX), 0);
+	}
+
+	#[test]
+	#[should_panic = "`bound` must not be 0"]
+	fn test_bounded_zero() {
+		bounded_u32(&mut Count(0), 0);
+	}
+
+	#[test]
+	fn test_bounded_distribution() {
+		use crate::{Random, SplitMix64};
+
+		let mut a = SplitMix64::new(1);
+		let mut b = SplitMix64::new(2);
+
+		let mut x = [0u32; 7];
+		let mut y = [0u32; 7];
+		for _ in 0..70000 {
+			x[bounded_u32(&mut a, 7) as usize] += 1;
+			y[b.random_u32_bound(7) as usize] += 1;
+		}
+
+		for (x, y) in x.iter().zip(y.iter()) {
+			assert!((9500..10500).contains(x), "{x}");
+			assert!((9500..10500).contains(y), "{y}");
+		}
+	}
+}
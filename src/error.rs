@@ -0,0 +1,35 @@
+/// reasons a `try_new` constructor can reject its input.
+/// 
+/// the infallible constructors (usually `new`) quietly adjust these inputs
+/// into something usable instead, and the `new_raw` constructors do not
+/// check anything at all.
+/// 
+/// ```
+/// use prrng::{Error, XorShift32};
+/// 
+/// assert_eq!(XorShift32::try_new(0).unwrap_err(), Error::ZeroSeed);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Error {
+	/// a seed of `0` would only ever produce `0`s, or otherwise get stuck.
+	ZeroSeed,
+	/// a seed is outside of the range the generator is defined for, usually
+	/// because it is not less than a modulus.
+	SeedOutOfRange,
+	/// a parameter other than the seed is invalid, such as an even
+	/// increment for a generator that requires an odd one.
+	InvalidParameter,
+}
+
+impl core::fmt::Display for Error {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		match self {
+			Self::ZeroSeed => write!(f, "seed must not be 0"),
+			Self::SeedOutOfRange => write!(f, "seed is out of range"),
+			Self::InvalidParameter => write!(f, "invalid parameter"),
+		}
+	}
+}
+
+#[cfg(feature = "error")]
+impl core::error::Error for Error {}
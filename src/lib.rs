@@ -2,15 +2,37 @@
 
 #![no_std]
 
+#[cfg(any(test, feature = "alloc"))]
+extern crate alloc;
+
+#[cfg(any(test, feature = "std"))]
+extern crate std;
+
 #[cfg(test)]
 mod test;
 
 pub mod common;
 
+mod math;
+
+pub mod noise;
+
+pub mod quasi;
+
+#[cfg(any(test, feature = "stats"))]
+pub mod stats;
+
 mod random;
 #[doc(inline)]
 pub use random::*;
 
+#[cfg(feature = "derive")]
+pub use prrng_derive::FromRandom;
+
+mod error;
+#[doc(inline)]
+pub use error::*;
+
 
 mod utility;
 
@@ -26,12 +48,82 @@ pub use utility::crush::*;
 #[doc(inline)]
 pub use utility::buffer::*;
 
+#[doc(inline)]
+pub use utility::debias::*;
+
+#[doc(inline)]
+pub use utility::combine::*;
+
+#[doc(inline)]
+pub use utility::interleave::*;
+
+#[doc(inline)]
+pub use utility::reseeding::*;
+
+#[doc(inline)]
+pub use utility::decimate::*;
+
+#[doc(inline)]
+pub use utility::whiten::*;
+
+#[doc(inline)]
+pub use utility::counting::*;
+
+#[doc(inline)]
+pub use utility::recording::*;
+
+#[doc(inline)]
+pub use utility::replay::*;
+
+#[doc(inline)]
+pub use utility::mock::*;
+
+#[doc(inline)]
+pub use utility::chars::*;
+
+#[doc(inline)]
+pub use utility::bounded::*;
+
+#[doc(inline)]
+pub use utility::normal::*;
+
+#[doc(inline)]
+pub use utility::walk::*;
+
+#[doc(inline)]
+pub use utility::shuffle_bag::*;
+
+#[doc(inline)]
+pub use utility::bitreader::*;
+
+#[doc(inline)]
+pub use utility::bytestream::*;
+
+#[doc(inline)]
+pub use utility::jitter::*;
+
+#[doc(inline)]
+pub use utility::global::*;
+
+#[doc(inline)]
+pub use utility::poisson_disk::*;
+
+#[doc(inline)]
+pub use utility::sample::*;
+
+#[cfg(any(test, feature = "std"))]
+#[doc(inline)]
+pub use utility::time::*;
+
 
 mod algorithm;
 
 #[doc(inline)]
 pub use algorithm::wichhill::*;
 
+#[doc(inline)]
+pub use algorithm::xoroshiro64::*;
+
 #[doc(inline)]
 pub use algorithm::xorshift32::*;
 
@@ -44,9 +136,15 @@ pub use algorithm::xorshift128p::*;
 #[doc(inline)]
 pub use algorithm::xoshiro256ss::*;
 
+#[doc(inline)]
+pub use algorithm::xoshiro512ss::*;
+
 #[doc(inline)]
 pub use algorithm::chacha::*;
 
+#[doc(inline)]
+pub use algorithm::chacharng::*;
+
 #[doc(inline)]
 pub use algorithm::collatzweyl::*;
 
@@ -56,9 +154,25 @@ pub use algorithm::mtwister::*;
 #[doc(inline)]
 pub use algorithm::splitmix64::*;
 
+#[doc(inline)]
+pub use algorithm::rc4::*;
+
+#[doc(inline)]
+pub use algorithm::sponge::*;
+
+#[cfg(all(any(test, feature = "rdrand"), target_arch = "x86_64"))]
+#[doc(inline)]
+pub use algorithm::rdrand::*;
+
 #[doc(inline)]
 pub use algorithm::pcg32::*;
 
+#[doc(inline)]
+pub use algorithm::pcg16::*;
+
+#[doc(inline)]
+pub use algorithm::pcg8::*;
+
 #[doc(inline)]
 pub use algorithm::lcg;
 
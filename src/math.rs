@@ -0,0 +1,249 @@
+// no_std has no float math, so what is needed is approximated here.
+
+use core::f64::consts::{FRAC_PI_2, FRAC_PI_4, LN_2, PI, SQRT_2};
+
+pub(crate) fn sqrt(x: f64) -> f64 {
+	if x <= 0.0 {
+		return 0.0;
+	}
+	if x == f64::INFINITY {
+		return x;
+	}
+	// halving the exponent gives a good first guess
+	let mut y = f64::from_bits((x.to_bits() >> 1) + 0x1ff8_0000_0000_0000);
+	for _ in 0..6 {
+		y = 0.5 * (y + x / y);
+	}
+	y
+}
+
+pub(crate) fn ln(x: f64) -> f64 {
+	if x <= 0.0 {
+		return f64::NEG_INFINITY;
+	}
+	if x == f64::INFINITY {
+		return x;
+	}
+
+	let (x, offset) = if x < f64::MIN_POSITIVE {
+		// subnormal
+		(x * (1u64 << 54) as f64, -54.0)
+	} else {
+		(x, 0.0)
+	};
+
+	// x = m * 2^e, with m in [sqrt(1/2), sqrt(2))
+	let bits = x.to_bits();
+	let mut e = ((bits >> 52) & 0x7ff) as i64 - 1023;
+	let mut m = f64::from_bits((bits & 0x000f_ffff_ffff_ffff) | 0x3ff0_0000_0000_0000);
+	if m > SQRT_2 {
+		m *= 0.5;
+		e += 1;
+	}
+
+	// ln(m) = 2 * atanh((m - 1) / (m + 1))
+	let s = (m - 1.0) / (m + 1.0);
+	let s2 = s * s;
+	let mut sum = 0.0;
+	let mut term = s;
+	for i in 0..14 {
+		sum += term / (2 * i + 1) as f64;
+		term *= s2;
+	}
+
+	2.0 * sum + (e as f64 + offset) * LN_2
+}
+
+pub(crate) fn exp(x: f64) -> f64 {
+	if x < -745.0 {
+		return 0.0;
+	}
+	if x > 709.0 {
+		return f64::INFINITY;
+	}
+
+	// x = k * ln(2) + r, with |r| <= ln(2) / 2
+	let k = (x / LN_2 + if x < 0.0 { -0.5 } else { 0.5 }) as i64;
+	let r = x - k as f64 * LN_2;
+
+	let mut sum = 1.0;
+	let mut term = 1.0;
+	for i in 1..16 {
+		term *= r / i as f64;
+		sum += term;
+	}
+
+	// 2^k, split in two so either half is a normal float
+	let k1 = k / 2;
+	let k2 = k - k1;
+	sum * f64::from_bits(((k1 + 1023) as u64) << 52) * f64::from_bits(((k2 + 1023) as u64) << 52)
+}
+
+// returns `ln(n!)`.
+pub(crate) fn ln_factorial(n: u64) -> f64 {
+	// exact, since every factorial up to 18! fits in a f64 mantissa
+	if n <= 18 {
+		let mut x = 1.0;
+		for i in 2..=n {
+			x *= i as f64;
+		}
+		return ln(x);
+	}
+
+	// stirling's series
+	let n = n as f64;
+	let r = 1.0 / n;
+	let r2 = r * r;
+	n * ln(n) - n + 0.5 * ln(core::f64::consts::TAU * n)
+		+ r * (1.0 / 12.0 - r2 * (1.0 / 360.0 - r2 * (1.0 / 1260.0 - r2 / 1680.0)))
+}
+
+// returns `(sin(x), cos(x))`. only meant for small `x`, since the
+// reduction loses precision as `x` grows.
+pub(crate) fn sin_cos(x: f64) -> (f64, f64) {
+	// x = k * pi/2 + r, with |r| <= pi/4
+	let k = (x / FRAC_PI_2 + if x < 0.0 { -0.5 } else { 0.5 }) as i64;
+	let r = x - k as f64 * FRAC_PI_2;
+	let r2 = r * r;
+
+	let mut sin = 0.0;
+	let mut cos = 0.0;
+	let mut s = r;
+	let mut c = 1.0;
+	for i in 0..10 {
+		sin += s;
+		cos += c;
+		s *= -r2 / ((2 * i + 2) * (2 * i + 3)) as f64;
+		c *= -r2 / ((2 * i + 1) * (2 * i + 2)) as f64;
+	}
+
+	match k & 3 {
+		0 => (sin, cos),
+		1 => (cos, -sin),
+		2 => (-sin, -cos),
+		_ => (-cos, sin),
+	}
+}
+
+// returns the angle of `(x, y)`, within `[-pi, pi]`.
+pub(crate) fn atan2(y: f64, x: f64) -> f64 {
+	if x == 0.0 && y == 0.0 {
+		return 0.0;
+	}
+
+	// atan of `a` in `[0, 1]`
+	let (ax, ay) = (x.abs(), y.abs());
+	let a = ax.min(ay) / ax.max(ay);
+
+	// atan(a) = pi/4 + atan((a - 1) / (a + 1)), so |t| <= tan(pi/8)
+	let (t, offset) = if a > 0.41421356237309503 {
+		((a - 1.0) / (a + 1.0), FRAC_PI_4)
+	} else {
+		(a, 0.0)
+	};
+	let t2 = t * t;
+	let mut sum = 0.0;
+	let mut term = t;
+	for i in 0..22 {
+		sum += term / (2 * i + 1) as f64;
+		term *= -t2;
+	}
+	let mut r = sum + offset;
+
+	if ay > ax {
+		r = FRAC_PI_2 - r;
+	}
+	if x < 0.0 {
+		r = PI - r;
+	}
+	if y < 0.0 { -r } else { r }
+}
+
+
+#[cfg(test)]
+mod test {
+	use crate::math::{atan2, exp, ln, ln_factorial, sin_cos, sqrt};
+	use core::f64::consts::{FRAC_1_SQRT_2, FRAC_PI_4, LN_2, PI};
+
+	fn close(a: f64, b: f64, eps: f64) -> bool {
+		(a - b).abs() <= eps * b.abs().max(1.0)
+	}
+
+	#[test]
+	fn test_sqrt_ln() {
+		for x in [0.0001, 0.5, 1.0, 2.0, 3.0, 100.0, 12345.678] {
+			assert!(close(sqrt(x) * sqrt(x), x, 1e-14), "{x}");
+		}
+		assert_eq!(sqrt(0.0), 0.0);
+
+		assert!(close(ln(2.0), LN_2, 1e-15));
+		assert!(close(ln(1e-310), -713.8013788281542, 1e-12));
+		assert_eq!(ln(1.0), 0.0);
+		assert_eq!(ln(0.0), f64::NEG_INFINITY);
+	}
+
+	#[test]
+	fn test_exp() {
+		for x in [-700.0, -20.0, -1.5, -0.25, 0.0, 0.3, 1.0, 2.5, 50.0, 700.0] {
+			assert!(close(ln(exp(x)), x, 1e-14), "{x}");
+		}
+		assert_eq!(exp(0.0), 1.0);
+		assert!(close(exp(1.0), core::f64::consts::E, 1e-15));
+		assert!(close(exp(-740.0), 4.1995579896506e-322, 1e-2));
+		assert_eq!(exp(710.0), f64::INFINITY);
+		assert_eq!(exp(-800.0), 0.0);
+	}
+
+	#[test]
+	fn test_ln_factorial() {
+		let mut x = 1.0f64;
+		for n in 0..=30 {
+			if n > 0 {
+				x *= n as f64;
+			}
+			assert!(close(ln_factorial(n), ln(x), 1e-14), "{n}");
+		}
+		// ln(1000!)
+		assert!(close(ln_factorial(1000), 5912.128178488163, 1e-14));
+	}
+
+	#[test]
+	fn test_sin_cos() {
+		let cases = [
+			(0.0, 0.0, 1.0),
+			(FRAC_PI_4, FRAC_1_SQRT_2, FRAC_1_SQRT_2),
+			(1.0, 0.8414709848078965, 0.5403023058681398),
+			(2.0, 0.9092974268256817, -0.4161468365471424),
+			(PI, 0.0, -1.0),
+			(4.0, -0.7568024953079282, -0.6536436208636119),
+			(5.5, -0.7055403255703919, 0.70866977429126),
+			(-1.0, -0.8414709848078965, 0.5403023058681398),
+		];
+		for (x, s, c) in cases {
+			let (sin, cos) = sin_cos(x);
+			assert!(close(sin, s, 1e-15), "sin({x}) = {sin}");
+			assert!(close(cos, c, 1e-15), "cos({x}) = {cos}");
+		}
+	}
+
+	#[test]
+	fn test_atan2() {
+		let cases = [
+			(0.0, 1.0, 0.0),
+			(1.0, 1.0, FRAC_PI_4),
+			(1.0, 0.0, PI / 2.0),
+			(0.0, -1.0, PI),
+			(-1.0, -1.0, -3.0 * FRAC_PI_4),
+			(0.5, 2.0, 0.24497866312686414),
+			(3.0, -0.2, 1.6373644905707205),
+			(-1e-9, 1.0, -1e-9),
+		];
+		for (y, x, a) in cases {
+			assert!(close(atan2(y, x), a, 1e-15), "atan2({y}, {x}) = {}", atan2(y, x));
+		}
+		for x in [0.1, 1.0, 2.0, 3.0, -2.5] {
+			let (sin, cos) = sin_cos(x);
+			assert!(close(atan2(sin, cos), x, 1e-14), "{x}");
+		}
+	}
+}
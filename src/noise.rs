@@ -0,0 +1,212 @@
+//! stateless, position-addressable randomness.
+//! 
+//! every function here is a pure hash of a seed and some coordinates, so the
+//! value at any position can be computed directly, without iterating a
+//! sequential stream. this is useful for procedural generation, where the
+//! contents of a tile should not depend on the order tiles are visited in.
+//! 
+//! each coordinate is folded in with a
+//! [SplitMix64](crate::SplitMix64) style finalizer, so neighbouring
+//! coordinates produce unrelated values.
+//! 
+//! ```
+//! use prrng::noise::{hash2d, hash2d_f64};
+//! 
+//! let seed = 1234;
+//! 
+//! assert_eq!(hash2d(seed, 3, -7), hash2d(seed, 3, -7));
+//! assert_ne!(hash2d(seed, 3, -7), hash2d(seed, 3, -6));
+//! 
+//! let height = hash2d_f64(seed, 3, -7);
+//! assert!((0.0..1.0).contains(&height));
+//! ```
+
+// splitmix64's finalizer
+#[inline]
+const fn mix(mut x: u64) -> u64 {
+	x = (x ^ (x >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+	x = (x ^ (x >> 27)).wrapping_mul(0x94d049bb133111eb);
+	x ^ (x >> 31)
+}
+
+#[inline]
+const fn combine(h: u64, v: i64) -> u64 {
+	mix(h.wrapping_add(0x9e3779b97f4a7c15) ^ v as u64)
+}
+
+/// hash `seed` and the 2d coordinates `x`, `y` into a `u64`.
+#[inline]
+pub const fn hash2d(seed: u64, x: i64, y: i64) -> u64 {
+	combine(combine(seed, x), y)
+}
+
+/// hash `seed` and the 3d coordinates `x`, `y`, `z` into a `u64`.
+#[inline]
+pub const fn hash3d(seed: u64, x: i64, y: i64, z: i64) -> u64 {
+	combine(hash2d(seed, x, y), z)
+}
+
+/// [`hash2d()`], normalized to a `f64` in `[0, 1)`.
+#[inline]
+pub const fn hash2d_f64(seed: u64, x: i64, y: i64) -> f64 {
+	crate::common::u64_normalize_f64(hash2d(seed, x, y))
+}
+
+/// [`hash3d()`], normalized to a `f64` in `[0, 1)`.
+#[inline]
+pub const fn hash3d_f64(seed: u64, x: i64, y: i64, z: i64) -> f64 {
+	crate::common::u64_normalize_f64(hash3d(seed, x, y, z))
+}
+
+/// constructs seeded rngs for individual cells.
+/// 
+/// this is for when a cell needs more than a single value. the returned rng
+/// is seeded with the hash of the cell's coordinates, so it is independent
+/// of every other cell's rng.
+/// 
+/// ```
+/// use prrng::noise::CellRandom;
+/// use prrng::Random;
+/// 
+/// let cells = CellRandom::new(1234);
+/// 
+/// let mut rng = cells.splitmix64(3, -7);
+/// let trees = rng.random_u32_bound(5);
+/// let rocks = rng.random_u32_bound(3);
+/// 
+/// // the same cell always gets the same values
+/// let mut rng = cells.splitmix64(3, -7);
+/// assert_eq!(rng.random_u32_bound(5), trees);
+/// assert_eq!(rng.random_u32_bound(3), rocks);
+/// ```
+#[derive(Clone)]
+pub struct CellRandom {
+	seed: u64,
+}
+
+impl CellRandom {
+	#[inline]
+	pub const fn new(seed: u64) -> Self {
+		Self {
+			seed,
+		}
+	}
+
+	/// the seed every cell is hashed with.
+	#[inline]
+	pub const fn seed(&self) -> u64 {
+		self.seed
+	}
+
+	/// returns a [`crate::SplitMix64`] for the 2d cell `x`, `y`.
+	#[inline]
+	pub const fn splitmix64(&self, x: i64, y: i64) -> crate::SplitMix64 {
+		crate::SplitMix64::new(hash2d(self.seed, x, y))
+	}
+
+	/// returns a [`crate::SplitMix64`] for the 3d cell `x`, `y`, `z`.
+	#[inline]
+	pub const fn splitmix64_3d(&self, x: i64, y: i64, z: i64) -> crate::SplitMix64 {
+		crate::SplitMix64::new(hash3d(self.seed, x, y, z))
+	}
+
+	/// returns a [`crate::XorShift64`] for the 2d cell `x`, `y`.
+	#[inline]
+	pub const fn xorshift64(&self, x: i64, y: i64) -> crate::XorShift64 {
+		crate::XorShift64::new(hash2d(self.seed, x, y))
+	}
+
+	/// returns a [`crate::XorShift64`] for the 3d cell `x`, `y`, `z`.
+	#[inline]
+	pub const fn xorshift64_3d(&self, x: i64, y: i64, z: i64) -> crate::XorShift64 {
+		crate::XorShift64::new(hash3d(self.seed, x, y, z))
+	}
+}
+
+impl core::fmt::Debug for CellRandom {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		write!(f, "CellRandom")
+	}
+}
+
+
+#[cfg(test)]
+mod test {
+	use crate::noise::{hash2d, hash2d_f64, hash3d, CellRandom};
+	use crate::Random;
+
+	#[test]
+	fn test_determinism() {
+		for seed in [0, 1, 1234, u64::MAX] {
+			for (x, y) in [(0, 0), (1, -1), (i64::MIN, i64::MAX)] {
+				assert_eq!(hash2d(seed, x, y), hash2d(seed, x, y));
+				assert_eq!(hash3d(seed, x, y, 5), hash3d(seed, x, y, 5));
+			}
+		}
+
+		assert_ne!(hash2d(0, 0, 0), 0);
+		assert_ne!(hash2d(0, 1, 2), hash2d(0, 2, 1));
+		assert_ne!(hash2d(0, 1, 2), hash2d(1, 1, 2));
+		assert_ne!(hash2d(0, 1, 2), hash3d(0, 1, 2, 0));
+
+		let cells = CellRandom::new(5);
+		assert_eq!(cells.xorshift64_3d(1, 2, 3).get(), cells.xorshift64_3d(1, 2, 3).get());
+		assert_ne!(cells.xorshift64(1, 2).get(), cells.xorshift64(2, 1).get());
+		let mut a = cells.splitmix64(1, 2);
+		let mut b = cells.splitmix64(1, 2);
+		for _ in 0..16 {
+			assert_eq!(a.get(), b.get());
+		}
+	}
+
+	// pearson correlation between each cell and the cell at an offset
+	fn correlation(dx: i64, dy: i64) -> f64 {
+		let n = 256;
+		let (mut sa, mut sb, mut sab, mut saa, mut sbb) = (0.0, 0.0, 0.0, 0.0, 0.0);
+		for x in 0..n {
+			for y in 0..n {
+				let a = hash2d_f64(7, x, y);
+				let b = hash2d_f64(7, x + dx, y + dy);
+				sa += a;
+				sb += b;
+				sab += a * b;
+				saa += a * a;
+				sbb += b * b;
+			}
+		}
+		let n = (n * n) as f64;
+		let cov = sab / n - sa / n * sb / n;
+		let va = saa / n - sa / n * sa / n;
+		let vb = sbb / n - sb / n * sb / n;
+		cov / crate::math::sqrt(va * vb)
+	}
+
+	#[test]
+	fn test_correlation() {
+		for (dx, dy) in [(1, 0), (0, 1), (1, 1), (-1, 1)] {
+			let r = correlation(dx, dy);
+			assert!(r.abs() < 0.02, "({dx}, {dy}): {r}");
+		}
+	}
+
+	#[test]
+	fn test_avalanche() {
+		let mut rng = crate::SplitMix64::new(1);
+		let n = 4096;
+		let mut flips = [0u64; 3];
+		for _ in 0..n {
+			let seed = rng.get();
+			let (x, y, z) = (rng.get() as i64, rng.get() as i64, rng.get() as i64);
+			let bit = 1 << rng.random_u32_bound(64);
+			let h = hash3d(seed, x, y, z);
+			flips[0] += (h ^ hash3d(seed, x ^ bit, y, z)).count_ones() as u64;
+			flips[1] += (h ^ hash3d(seed, x, y ^ bit, z)).count_ones() as u64;
+			flips[2] += (h ^ hash3d(seed, x, y, z ^ bit)).count_ones() as u64;
+		}
+
+		for f in flips {
+			let avg = f as f64 / n as f64;
+			assert!((avg - 32.0).abs() < 0.5, "{avg}");
+		}
+	}
+}
@@ -0,0 +1,385 @@
+//! low-discrepancy ("quasi-random") sequences.
+//! 
+//! these are **not** random number generators. every value is a fixed
+//! function of its index, and the sequence is designed to cover `[0, 1)`
+//! as evenly as possible, with none of the clumps and gaps that independent
+//! uniform values have. that makes quasi-monte carlo integration converge
+//! much faster than it does with a [`crate::Random`], roughly `1 / n`
+//! instead of `1 / sqrt(n)`, but the values are anything but independent,
+//! and fail every statistical test. for that reason, nothing here
+//! implements [`crate::Random`].
+//! 
+//! ```
+//! use prrng::quasi::HaltonNd;
+//! 
+//! // estimate the area of a quarter circle, pi / 4
+//! let mut points = HaltonNd::<2>::new();
+//! let n = 4096;
+//! let inside = (0..n)
+//!     .map(|_| points.get())
+//!     .filter(|[x, y]| x * x + y * y < 1.0)
+//!     .count();
+//! 
+//! let pi = 4.0 * inside as f64 / n as f64;
+//! assert!((pi - core::f64::consts::PI).abs() < 0.01);
+//! ```
+
+// the largest `f64` below `1`
+const ONE_BELOW: f64 = 1.0 - f64::EPSILON / 2.0;
+
+// the digits of `index` in `base`, mirrored around the decimal point.
+// with `scramble`, every digit position gets its own random permutation of
+// the digits, and the (otherwise zero) digits past the end of `index` are
+// filled in until they stop mattering.
+fn radical_inverse(base: u32, mut index: u64, scramble: Option<u64>) -> f64 {
+	let b = base as u64;
+	let step = 1.0 / base as f64;
+
+	let mut x = 0.0;
+	let mut weight = step;
+	let mut position = 0;
+	while index != 0 || (scramble.is_some() && weight > f64::EPSILON) {
+		let mut digit = index % b;
+		if let Some(seed) = scramble {
+			// `digit * m + s`, which is a permutation for a prime `base`,
+			// since `m` is not `0`
+			let h = crate::noise::hash2d(seed, position, 0);
+			let m = 1 + (h % (b - 1));
+			let s = (h >> 32) % b;
+			digit = (digit * m + s) % b;
+		}
+
+		x += digit as f64 * weight;
+		weight *= step;
+		index /= b;
+		position += 1;
+	}
+
+	// rounding can reach `1` after enough digits of `base - 1`
+	x.min(ONE_BELOW)
+}
+
+/// the [halton sequence](https://en.wikipedia.org/wiki/Halton_sequence) in
+/// a single dimension, also known as the van der corput sequence. yields
+/// `f64` values within `[0, 1)`.
+/// 
+/// the value at index `i` is the
+/// [radical inverse](https://en.wikipedia.org/wiki/Van_der_Corput_sequence)
+/// of `i` in `base`: its digits, mirrored around the decimal point. each new
+/// value lands in the largest gap left by the ones before it.
+/// 
+/// [`Self::get()`] starts at index `1`, skipping the `0` at index `0`.
+/// 
+/// ```
+/// use prrng::quasi::Halton;
+/// 
+/// let mut halton = Halton::new(2);
+/// 
+/// assert_eq!(halton.get(), 0.5);
+/// assert_eq!(halton.get(), 0.25);
+/// assert_eq!(halton.get(), 0.75);
+/// assert_eq!(halton.get(), 0.125);
+/// 
+/// assert_eq!(Halton::new(3).at(4), 4.0 / 9.0);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Halton {
+	base: u32,
+	index: u64,
+}
+
+impl Halton {
+	/// construct a new `Halton`, in `base`.
+	/// 
+	/// any `base` works on its own. when combining several sequences into
+	/// points, use a different prime for each, see [`HaltonNd`].
+	/// 
+	/// ## panics
+	/// 
+	/// panics if `base` is less than `2`. see [`Self::try_new()`] for a
+	/// constructor that doesn't panic.
+	#[inline]
+	#[track_caller]
+	pub const fn new(base: u32) -> Self {
+		assert!(base >= 2, "`base` must be at least 2");
+		Self {
+			base,
+			index: 1,
+		}
+	}
+
+	/// construct a new `Halton`, returning
+	/// [`crate::Error::InvalidParameter`] if `base` is less than `2`.
+	#[inline]
+	pub const fn try_new(base: u32) -> Result<Self, crate::Error> {
+		if base < 2 {
+			return Err(crate::Error::InvalidParameter);
+		}
+		Ok(Self::new(base))
+	}
+
+	/// the base of the sequence.
+	#[inline]
+	pub const fn base(&self) -> u32 {
+		self.base
+	}
+
+	/// the index of the next value returned by [`Self::get()`].
+	#[inline]
+	pub const fn index(&self) -> u64 {
+		self.index
+	}
+
+	/// returns the value at `index`, without changing `self`.
+	#[inline]
+	pub fn at(&self, index: u64) -> f64 {
+		radical_inverse(self.base, index, None)
+	}
+
+	/// returns the next value.
+	#[inline]
+	pub fn get(&mut self) -> f64 {
+		let x = self.at(self.index);
+		self.index = self.index.wrapping_add(1);
+		x
+	}
+}
+
+impl Iterator for Halton {
+	type Item = f64;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		Some(self.get())
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		(usize::MAX, None)
+	}
+}
+
+impl core::iter::FusedIterator for Halton {}
+
+/// the [halton sequence](https://en.wikipedia.org/wiki/Halton_sequence) in
+/// `D` dimensions. yields `[f64; D]` points within `[0, 1)^D`.
+/// 
+/// every dimension is a [`Halton`] sequence, in the first `D` primes
+/// (`2`, `3`, `5`, `7`, ...). all of them use the same index, so
+/// [`Self::get()`] also starts at index `1`.
+/// 
+/// in higher dimensions, neighbouring large primes are strongly correlated
+/// for the first many points, which shows up as stripes when two of them are
+/// plotted against each other. [`Self::new_scrambled()`] fixes this by
+/// permuting the digits of each dimension randomly, which keeps the sequence
+/// low-discrepancy. the rng is only used to pick a seed for that. the points
+/// are still deterministic, and still not random.
+/// 
+/// ```
+/// use prrng::XorShift64;
+/// use prrng::quasi::HaltonNd;
+/// 
+/// let mut halton = HaltonNd::<3>::new();
+/// assert_eq!(halton.get(), [0.5, 1.0 / 3.0, 0.2]);
+/// 
+/// let mut scrambled = HaltonNd::<16>::new_scrambled(&mut XorShift64::new(1));
+/// let point = scrambled.get();
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct HaltonNd<const D: usize> {
+	bases: [u32; D],
+	index: u64,
+	scramble: Option<u64>,
+}
+
+impl<const D: usize> HaltonNd<D> {
+	/// construct a new, unscrambled `HaltonNd`.
+	#[inline]
+	pub const fn new() -> Self {
+		let mut bases = [0; D];
+		let mut i = 0;
+		let mut candidate = 2;
+		while i < D {
+			let mut prime = true;
+			let mut j = 0;
+			while j < i && bases[j] * bases[j] <= candidate {
+				if candidate % bases[j] == 0 {
+					prime = false;
+					break;
+				}
+				j += 1;
+			}
+			if prime {
+				bases[i] = candidate;
+				i += 1;
+			}
+			candidate += 1;
+		}
+
+		Self {
+			bases,
+			index: 1,
+			scramble: None,
+		}
+	}
+
+	/// construct a new `HaltonNd`, with the digits of each dimension
+	/// scrambled by a seed drawn from `random`.
+	#[inline]
+	pub fn new_scrambled(random: &mut impl crate::Random) -> Self {
+		Self {
+			scramble: Some(random.random_u64()),
+			..Self::new()
+		}
+	}
+
+	/// the base of each dimension.
+	#[inline]
+	pub const fn bases(&self) -> [u32; D] {
+		self.bases
+	}
+
+	/// the index of the next point returned by [`Self::get()`].
+	#[inline]
+	pub const fn index(&self) -> u64 {
+		self.index
+	}
+
+	/// returns the point at `index`, without changing `self`.
+	#[inline]
+	pub fn at(&self, index: u64) -> [f64; D] {
+		core::array::from_fn(|i| {
+			let scramble = self.scramble.map(|x| crate::noise::hash2d(x, i as i64, 0));
+			radical_inverse(self.bases[i], index, scramble)
+		})
+	}
+
+	/// returns the next point.
+	#[inline]
+	pub fn get(&mut self) -> [f64; D] {
+		let x = self.at(self.index);
+		self.index = self.index.wrapping_add(1);
+		x
+	}
+}
+
+impl<const D: usize> Default for HaltonNd<D> {
+	#[inline]
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<const D: usize> Iterator for HaltonNd<D> {
+	type Item = [f64; D];
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		Some(self.get())
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		(usize::MAX, None)
+	}
+}
+
+impl<const D: usize> core::iter::FusedIterator for HaltonNd<D> {}
+
+
+#[cfg(test)]
+mod test {
+	use crate::quasi::{Halton, HaltonNd};
+	use crate::{Random, XorShift64};
+
+	// star discrepancy, estimated over every box `[0, a) x [0, b)` with
+	// corners on the coordinates of the points
+	fn discrepancy(points: &[[f64; 2]]) -> f64 {
+		let n = points.len() as f64;
+		let mut worst = 0.0f64;
+		for &[a, _] in points {
+			for &[_, b] in points {
+				let inside = points.iter().filter(|p| p[0] < a && p[1] < b).count() as f64;
+				let closed = points.iter().filter(|p| p[0] <= a && p[1] <= b).count() as f64;
+				worst = worst.max((inside / n - a * b).abs()).max((closed / n - a * b).abs());
+			}
+		}
+		worst
+	}
+
+	#[test]
+	fn test_radical_inverse() {
+		let base2 = [0.0, 0.5, 0.25, 0.75, 0.125, 0.625, 0.375, 0.875, 0.0625];
+		let base3 = [0.0, 1.0, 2.0, 1.0 / 3.0, 4.0 / 3.0, 7.0 / 3.0, 2.0 / 3.0, 5.0 / 3.0, 8.0 / 3.0];
+		for i in 0..9 {
+			assert_eq!(Halton::new(2).at(i), base2[i as usize]);
+			assert!((Halton::new(3).at(i) - base3[i as usize] / 3.0).abs() < 1e-15, "{i}");
+		}
+
+		// base 10 reads the digits backwards
+		assert!((Halton::new(10).at(1234) - 0.4321).abs() < 1e-15);
+
+		let mut halton = Halton::new(2);
+		assert!(halton.by_ref().take(8).eq(base2[1..].iter().copied()));
+		assert_eq!(halton.index(), 9);
+
+		assert!(Halton::new(2).at(u64::MAX) < 1.0);
+		assert_eq!(Halton::try_new(1), Err(crate::Error::InvalidParameter));
+		assert_eq!(Halton::try_new(4).map(|x| x.base()), Ok(4));
+	}
+
+	#[test]
+	fn test_nd() {
+		assert_eq!(HaltonNd::<8>::new().bases(), [2, 3, 5, 7, 11, 13, 17, 19]);
+		assert_eq!(HaltonNd::<100>::new().bases()[99], 541);
+
+		let mut halton = HaltonNd::<3>::new();
+		for i in 1..100 {
+			let point = halton.get();
+			for (x, base) in point.into_iter().zip([2, 3, 5]) {
+				assert_eq!(x, Halton::new(base).at(i));
+			}
+		}
+	}
+
+	#[test]
+	fn test_scrambled() {
+		let a = HaltonNd::<4>::new_scrambled(&mut XorShift64::new(1));
+		let b = HaltonNd::<4>::new_scrambled(&mut XorShift64::new(1));
+		let c = HaltonNd::<4>::new_scrambled(&mut XorShift64::new(2));
+		assert!(a == b);
+		assert_ne!(a.at(1), c.at(1));
+		assert_ne!(a.at(1), HaltonNd::<4>::new().at(1));
+
+		// still one point in every interval, in every dimension
+		for (d, base) in a.bases().into_iter().enumerate() {
+			let n = base.pow(2) as u64;
+			let mut seen = [false; 49];
+			for i in 0..n {
+				let x = a.at(i)[d];
+				assert!((0.0..1.0).contains(&x));
+				let cell = (x * n as f64) as usize;
+				assert!(!seen[cell], "{base}: {i}");
+				seen[cell] = true;
+			}
+		}
+	}
+
+	#[test]
+	fn test_discrepancy() {
+		let mut halton = HaltonNd::<2>::new();
+		let points: [[f64; 2]; 256] = core::array::from_fn(|_| halton.get());
+		let low = discrepancy(&points);
+
+		let mut scrambled = HaltonNd::<2>::new_scrambled(&mut XorShift64::new(1));
+		let points: [[f64; 2]; 256] = core::array::from_fn(|_| scrambled.get());
+		let scrambled = discrepancy(&points);
+
+		let mut rng = XorShift64::new(1);
+		let points: [[f64; 2]; 256] = core::array::from_fn(|_| [rng.random_f64(), rng.random_f64()]);
+		let uniform = discrepancy(&points);
+
+		assert!(low < 0.5 * uniform, "{low} {uniform}");
+		assert!(scrambled < 0.5 * uniform, "{scrambled} {uniform}");
+	}
+}
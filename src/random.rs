@@ -2,6 +2,51 @@
 /// random number generation.
 /// 
 /// see [`Random`] for more information.
+/// 
+/// ## width policy
+/// 
+/// every generator in this crate has a native width, the width of the value
+/// its `get()` method returns. the exceptions are [`crate::WichHill`] and
+/// [`crate::WichHill2006`], whose `get()` returns an `f64`. their native
+/// widths are those of [`crate::WichHill::get_u32()`] and
+/// [`crate::WichHill2006::get_u64()`] instead. the methods of this trait
+/// relate to that native stream the same way for every generator:
+/// 
+/// - a value narrower than the native width is a single fresh draw,
+///   truncated to its low bits.
+/// - a value wider than the native width is composed from successive draws,
+///   with the first draw in the most significant bits, like
+///   [`crate::common::u32_compose_u64()`].
+/// - [`Self::random_bytes()`] is the little-endian serialization of
+///   successive draws. any bytes of the last draw that do not fit are
+///   thrown away.
+/// 
+/// on top of this, [`Random::random_u128()`] is always two
+/// [`Self::random_u64()`] calls, composed with
+/// [`crate::common::u64_compose_u128()`], and [`Random::random_f64()`] and
+/// [`Random::random_f32()`] always go through
+/// [`crate::common::u64_normalize_f64()`] and
+/// [`crate::common::u32_normalize_f32()`].
+/// 
+/// ```
+/// use prrng::RandomImpl;
+/// use prrng::XorShift32;
+/// 
+/// let mut a = XorShift32::new(1);
+/// let mut b = a.clone();
+/// 
+/// let (x, y) = (b.get(), b.get());
+/// assert_eq!(a.random_u64(), (x as u64) << 32 | y as u64);
+/// 
+/// let mut bytes = [0; 6];
+/// a.random_bytes(&mut bytes);
+/// let (x, y) = (b.get(), b.get());
+/// assert_eq!(bytes[..4], x.to_le_bytes());
+/// assert_eq!(bytes[4..], y.to_le_bytes()[..2]);
+/// ```
+/// 
+/// types implementing this outside of this crate are encouraged, but not
+/// required, to follow this too.
 pub trait RandomImpl {
 	/// returns a new `u64`.
 	/// 
@@ -20,6 +65,47 @@ pub trait RandomImpl {
 	/// consider using [`crate::common::bytes_from_u32()`] or
 	/// [`crate::common::bytes_from_u64()`] when implementing this.
 	fn random_bytes(&mut self, dst: &mut [u8]);
+
+	/// fills an uninitialized buffer with new values, returning it as an
+	/// initialized slice.
+	/// 
+	/// this writes the same bytes that [`Self::random_bytes()`] would, without
+	/// `dst` having to be zeroed first. by default, `random_bytes()` is called
+	/// on a small buffer on the stack, 256 bytes at a time, each of which is
+	/// copied into `dst`. generators that can write into `dst` directly
+	/// override this.
+	/// 
+	/// ```
+	/// # extern crate std;
+	/// # use std::boxed::Box;
+	/// use prrng::{RandomImpl, XorShift64};
+	/// 
+	/// let mut rng = XorShift64::new(1);
+	/// 
+	/// // rather than `vec![0; 4096].into_boxed_slice()` and `random_bytes()`
+	/// let mut buf = Box::<[u8]>::new_uninit_slice(4096);
+	/// rng.random_bytes_uninit(&mut buf);
+	/// let buf = unsafe {
+	///     // safety: `random_bytes_uninit()` initialized all of `buf`
+	///     buf.assume_init()
+	/// };
+	/// ```
+	fn random_bytes_uninit<'a>(&mut self, dst: &'a mut [core::mem::MaybeUninit<u8>]) -> &'a mut [u8] {
+		let mut stage = [0; 256];
+		for chunk in dst.chunks_mut(stage.len()) {
+			let stage = &mut stage[..chunk.len()];
+			self.random_bytes(stage);
+			for (o, i) in chunk.iter_mut().zip(stage.iter()) {
+				o.write(*i);
+			}
+		}
+		unsafe {
+			// safety:
+			// every byte of `dst` was written above, and `MaybeUninit<u8>`
+			// has the same layout as `u8`.
+			&mut *(dst as *mut [core::mem::MaybeUninit<u8>] as *mut [u8])
+		}
+	}
 }
 
 mod private {
@@ -78,11 +164,114 @@ pub trait Random: RandomImpl {
 		T::from_random(self)
 	}
 
+	/// returns a new random value `T`, or `None` if the values drawn don't
+	/// make a valid `T`.
+	/// 
+	/// this makes a single attempt. see [`Self::random_retry()`] to make
+	/// several, and [`TryFromRandom`] for what can fail.
+	/// 
+	/// ```
+	/// use prrng::Random;
+	/// use prrng::XorShift64;
+	/// use core::num::NonZero;
+	/// 
+	/// let mut rng = XorShift64::new(1);
+	/// 
+	/// let a: Option<char> = rng.random_try();
+	/// let b = rng.random_try::<NonZero<u32>>();
+	/// ```
+	#[inline]
+	fn random_try<T: TryFromRandom>(&mut self) -> Option<T> where Self: Sized {
+		T::try_from_random(self)
+	}
+
+	/// returns a new random value `T`, making up to `attempts` attempts, or
+	/// `None` if every one of them failed.
+	/// 
+	/// every failed attempt consumes values from `self`, so this is
+	/// [rejection sampling](https://en.wikipedia.org/wiki/Rejection_sampling)
+	/// with a cap on how long it can take.
+	/// 
+	/// ```
+	/// use prrng::Random;
+	/// use prrng::XorShift64;
+	/// 
+	/// let mut rng = XorShift64::new(1);
+	/// 
+	/// // about half of all attempts fail, so 64 practically never do
+	/// let c: char = rng.random_retry(64).unwrap();
+	/// ```
+	fn random_retry<T: TryFromRandom>(&mut self, attempts: usize) -> Option<T> where Self: Sized {
+		for _ in 0..attempts {
+			if let Some(x) = T::try_from_random(self) {
+				return Some(x);
+			}
+		}
+		None
+	}
+
+	/// returns a new random value `T`, constrained by `bound`.
+	/// 
+	/// what `bound` means depends on `T`, see [`FromRandomBounded`]. for the
+	/// integers, it is an exclusive maximum.
+	/// 
+	/// ```
+	/// use prrng::Random;
+	/// use prrng::XorShift64;
+	/// use core::time::Duration;
+	/// 
+	/// let mut rng = XorShift64::new(1);
+	/// 
+	/// let items = ["sword", "shield", "potion"];
+	/// let item = items[rng.random_bounded::<usize>(&items.len())];
+	/// 
+	/// let x: f64 = rng.random_bounded(&(-1.0..1.0));
+	/// let delay: Duration = rng.random_bounded(&Duration::from_millis(250));
+	/// ```
+	/// 
+	/// ## panics
+	/// 
+	/// panics under the same conditions as the method `T` delegates to, like
+	/// an integer `bound` of `0`.
+	#[inline]
+	#[track_caller]
+	fn random_bounded<T: FromRandomBounded>(&mut self, bound: &T::Bound) -> T where Self: Sized {
+		T::from_random_bounded(self, bound)
+	}
+
+	/// returns a new value `T`, drawn from the distribution `sampler`.
+	/// 
+	/// unlike [`Self::random()`], which makes a `T` out of nothing but
+	/// `self`, a [`crate::Sample`] carries its own parameters, like a range
+	/// or a table of weights, and can be reused across any number of
+	/// generators.
+	/// 
+	/// ```
+	/// use prrng::{Bernoulli, Random, XorShift64};
+	/// 
+	/// let mut rng = XorShift64::new(1);
+	/// let crit = Bernoulli::new(0.1);
+	/// 
+	/// let damage = if rng.sample_from(&crit) { 20 } else { 10 };
+	/// ```
+	#[inline]
+	fn sample_from<T, S: crate::Sample<T>>(&mut self, sampler: &S) -> T where Self: Sized {
+		sampler.sample(self)
+	}
+
 	/// returns a new `f64`.
 	fn random_f64(&mut self) -> f64 {
 		crate::common::u64_normalize_f64(self.random_u64())
 	}
 
+	/// returns a new `f64` in `(0, 1)`, never `0` or `1`.
+	/// 
+	/// this is useful for functions with a singularity at either end, like
+	/// `ln(x)`. see [`crate::common::u64_normalize_f64_open()`].
+	fn random_f64_open(&mut self) -> f64 {
+		crate::common::u64_normalize_f64_open(self.random_u64())
+	}
+
 	/// returns a new `f32`.
 	fn random_f32(&mut self) -> f32 {
 		crate::common::u32_normalize_f32(self.random_u32())
@@ -103,6 +292,37 @@ pub trait Random: RandomImpl {
 		self.random_u32() as u8
 	}
 
+	/// returns a new `u64`, with only the low `n` bits random, and the rest
+	/// `0`.
+	/// 
+	/// this draws a single `u32` if `n <= 32`, a single `u64` otherwise, and
+	/// nothing at all if `n` is `0`. any bits that are not returned are
+	/// wasted. the returned bits are taken from the top of the drawn value,
+	/// since the low bits of some generators are weak.
+	/// see [`crate::BitReader`] for a version that does not waste bits.
+	/// 
+	/// ```
+	/// use prrng::Random;
+	/// use prrng::XorShift64;
+	/// 
+	/// let mut rng = XorShift64::new(1);
+	/// 
+	/// assert!(rng.random_bits(3) < 8);
+	/// assert_eq!(rng.random_bits(0), 0);
+	/// ```
+	/// 
+	/// ## panics
+	/// 
+	/// panics if `n` is more than `64`.
+	fn random_bits(&mut self, n: u32) -> u64 {
+		assert!(n <= 64, "`n` must not be more than 64");
+		match n {
+			0 => 0,
+			1..=32 => (self.random_u32() >> (32 - n)) as u64,
+			_ => self.random_u64() >> (64 - n),
+		}
+	}
+
 	/// returns a new `bool`.
 	fn random_bool(&mut self) -> bool {
 		self.random_u32() & 1 == 1
@@ -116,28 +336,153 @@ pub trait Random: RandomImpl {
 		}
 	}
 
-	/// fill an uninitiaized buffer with random values `T`.
-	/// by the end of this method, `dst` will be fully initialized.
-	fn random_fill_uninit<T: FromRandom>(&mut self, dst: &mut [core::mem::MaybeUninit<T>]) where Self: Sized {
-		for i in dst {
+	/// fill a buffer with random `f64`s.
+	/// 
+	/// this produces the same values as `random_fill::<f64>()`, but draws
+	/// values in batches and normalizes them in a separate loop, which
+	/// optimizes better.
+	/// 
+	/// ```
+	/// # use prrng::XorShift64;
+	/// use prrng::Random;
+	/// 
+	/// let mut rng = XorShift64::new(1);
+	/// 
+	/// let mut samples = [0.0; 1000];
+	/// rng.random_fill_f64(&mut samples);
+	/// assert!(samples.iter().all(|x| (0.0..1.0).contains(x)));
+	/// ```
+	fn random_fill_f64(&mut self, dst: &mut [f64]) {
+		let (chunks, extra) = dst.as_chunks_mut::<16>();
+		for chunk in chunks {
+			let mut raw = [0u64; 16];
+			for x in &mut raw {
+				*x = self.random_u64();
+			}
+			for (o, x) in chunk.iter_mut().zip(raw) {
+				*o = crate::common::u64_normalize_f64(x);
+			}
+		}
+		for o in extra {
+			*o = crate::common::u64_normalize_f64(self.random_u64());
+		}
+	}
+
+	/// fill a buffer with random `f32`s.
+	/// 
+	/// this produces the same values as `random_fill::<f32>()`.
+	/// see [`Self::random_fill_f64()`].
+	fn random_fill_f32(&mut self, dst: &mut [f32]) {
+		let (chunks, extra) = dst.as_chunks_mut::<16>();
+		for chunk in chunks {
+			let mut raw = [0u32; 16];
+			for x in &mut raw {
+				*x = self.random_u32();
+			}
+			for (o, x) in chunk.iter_mut().zip(raw) {
+				*o = crate::common::u32_normalize_f32(x);
+			}
+		}
+		for o in extra {
+			*o = crate::common::u32_normalize_f32(self.random_u32());
+		}
+	}
+
+	/// fill an uninitiaized buffer with random values `T`, returning it as an
+	/// initialized slice.
+	/// 
+	/// by the end of this method, `dst` will be fully initialized. if
+	/// [`FromRandom::from_random()`] panics, values that were already written
+	/// are leaked.
+	/// 
+	/// note that the values in `dst` are not dropped when `dst` is, as with
+	/// any `MaybeUninit`.
+	/// 
+	/// ```
+	/// # use prrng::XorShift64;
+	/// use prrng::Random;
+	/// use core::mem::MaybeUninit;
+	/// 
+	/// let mut rng = XorShift64::new(1);
+	/// 
+	/// let mut buf = [MaybeUninit::<u64>::uninit(); 16];
+	/// let values: &mut [u64] = rng.random_fill_uninit(&mut buf);
+	/// assert_eq!(values.len(), 16);
+	/// ```
+	fn random_fill_uninit<'a, T: FromRandom>(&mut self, dst: &'a mut [core::mem::MaybeUninit<T>]) -> &'a mut [T] where Self: Sized {
+		for i in dst.iter_mut() {
 			*i = core::mem::MaybeUninit::new(self.random());
 		}
+		unsafe {
+			// safety:
+			// every element of `dst` was initialized above, and
+			// `MaybeUninit<T>` has the same layout as `T`.
+			&mut *(dst as *mut [core::mem::MaybeUninit<T>] as *mut [T])
+		}
+	}
+
+	/// returns an array of random values `T`.
+	/// 
+	/// this is the same as `rng.random::<[T; N]>()`, but the array is filled
+	/// in place with [`Self::random_fill_uninit()`], instead of being built
+	/// from `N` values.
+	/// 
+	/// ```
+	/// # use prrng::XorShift64;
+	/// use prrng::Random;
+	/// 
+	/// let mut rng = XorShift64::new(1);
+	/// 
+	/// let table: [u32; 256] = rng.random_array_uninit();
+	/// ```
+	fn random_array_uninit<T: FromRandom, const N: usize>(&mut self) -> [T; N] where Self: Sized {
+		let mut arr = [const { core::mem::MaybeUninit::<T>::uninit() }; N];
+		self.random_fill_uninit(&mut arr);
+		unsafe {
+			// safety:
+			// `random_fill_uninit()` initialized every element of `arr`, and
+			// `[MaybeUninit<T>; N]` has the same layout as `[T; N]`.
+			// `MaybeUninit` never drops its value, so reading it out here
+			// does not cause a double drop.
+			(&raw const arr).cast::<[T; N]>().read()
+		}
 	}
 
 	/// returns a new `u128`, uniformly distributed within `0 .. bound`.
-	#[inline]
+	/// 
+	/// this uses [lemire's method](https://arxiv.org/abs/1805.10941) with a
+	/// 256bit widening multiplication, which avoids a slow `u128` modulo in
+	/// almost every case. powers of two are masked directly.
+	/// the exact values returned for a given rng may change between minor
+	/// versions.
+	/// 
+	/// ## panics
+	/// 
+	/// panics if `bound` is `0`.
 	fn random_u128_bound(&mut self, bound: u128) -> u128 {
-		let threshold = bound.wrapping_neg() % bound;
-		loop {
-			let x = self.random_u128();
-			if x >= threshold {
-				return x % bound;
+		assert!(bound != 0, "`bound` must not be 0");
+
+		if bound.is_power_of_two() {
+			return self.random_u128() & (bound - 1);
+		}
+
+		let (mut hi, mut lo) = crate::common::u128_widening_mul(self.random_u128(), bound);
+		if lo < bound {
+			let threshold = bound.wrapping_neg() % bound;
+			while lo < threshold {
+				(hi, lo) = crate::common::u128_widening_mul(self.random_u128(), bound);
 			}
 		}
+		hi
 	}
 
-	/// returns a new `u128`, uniformly distributed within `0 .. bound`.
+	/// returns a new `u64`, uniformly distributed within `0 .. bound`.
+	/// 
+	/// ## panics
+	/// 
+	/// panics if `bound` is `0`.
 	fn random_u64_bound(&mut self, bound: u64) -> u64 {
+		assert!(bound != 0, "`bound` must not be 0");
 		let threshold = bound.wrapping_neg() % bound;
 		loop {
 			let x = self.random_u64();
@@ -147,8 +492,13 @@ pub trait Random: RandomImpl {
 		}
 	}
 
-	/// returns a new `u128`, uniformly distributed within `0 .. bound`.
+	/// returns a new `u32`, uniformly distributed within `0 .. bound`.
+	/// 
+	/// ## panics
+	/// 
+	/// panics if `bound` is `0`.
 	fn random_u32_bound(&mut self, bound: u32) -> u32 {
+		assert!(bound != 0, "`bound` must not be 0");
 		let threshold = bound.wrapping_neg() % bound;
 		loop {
 			let x = self.random_u32();
@@ -158,8 +508,13 @@ pub trait Random: RandomImpl {
 		}
 	}
 
-	/// returns a new `u128`, uniformly distributed within `0 .. bound`.
+	/// returns a new `u16`, uniformly distributed within `0 .. bound`.
+	/// 
+	/// ## panics
+	/// 
+	/// panics if `bound` is `0`.
 	fn random_u16_bound(&mut self, bound: u16) -> u16 {
+		assert!(bound != 0, "`bound` must not be 0");
 		let threshold = bound.wrapping_neg() % bound;
 		loop {
 			let x = self.random_u16();
@@ -169,8 +524,13 @@ pub trait Random: RandomImpl {
 		}
 	}
 
-	/// returns a new `u128`, uniformly distributed within `0 .. bound`.
+	/// returns a new `u8`, uniformly distributed within `0 .. bound`.
+	/// 
+	/// ## panics
+	/// 
+	/// panics if `bound` is `0`.
 	fn random_u8_bound(&mut self, bound: u8) -> u8 {
+		assert!(bound != 0, "`bound` must not be 0");
 		let threshold = bound.wrapping_neg() % bound;
 		loop {
 			let x = self.random_u8();
@@ -181,374 +541,2654 @@ pub trait Random: RandomImpl {
 	}
 
 	/// returns a new `f64`, uniformly distributed within `range`.
+	/// the returned value is always less than `range.end`.
+	/// 
+	/// if `range` is empty (`start == end`), this returns `start` without
+	/// consuming any values.
+	/// 
+	/// ## panics
+	/// 
+	/// panics if `range.start > range.end`, or if either is `NaN` or infinite.
+	/// see [`Self::try_random_range()`] for a version that returns `None` instead.
 	#[inline]
+	#[track_caller]
 	fn random_range(&mut self, range: core::ops::Range<f64>) -> f64 {
-		range.start + self.random_f64() * (range.end - range.start)
-	}
+		assert!(!range.start.is_nan() && !range.end.is_nan(), "`range` must not contain NaN");
+		assert!(range.start <= range.end, "`range` must not be inverted");
+		assert!(range.start.is_finite() && range.end.is_finite(), "`range` must be finite");
 
-	/// consume `self`, wrapping it in an iterator [`crate::Iter`]. its [`Iterator::next()`] returns `T`.
-	#[inline]
-	fn random_into_iter<T: crate::FromRandom>(self) -> crate::Iter<T, Self> where Self: Sized {
-		crate::Iter::new(self)
-	}
+		if range.start == range.end {
+			return range.start;
+		}
 
-	/// wrap `&mut self` in an iterator [`crate::Iter`]. its [`Iterator::next()`] returns `T`.
-	#[inline]
-	fn random_iter<T: crate::FromRandom>(&mut self) -> crate::Iter<T, &mut Self> where Self: Sized {
-		crate::Iter::new(self)
+		let u = self.random_f64();
+		let span = range.end - range.start;
+		let x = if span.is_finite() {
+			range.start + u * span
+		} else {
+			// the span of something like `-f64::MAX .. f64::MAX` overflows,
+			// but a weighted average of the ends can't
+			(range.start * (1.0 - u) + range.end * u).max(range.start)
+		};
+
+		// rounding may push `x` onto `end`
+		if x >= range.end {
+			range.end.next_down()
+		} else {
+			x
+		}
 	}
 
-	/// consume `self`, wrapping it in a [`crate::buffer::Buffer`] with size `N`.
+	/// returns a new `f64`, uniformly distributed within `range`.
+	/// 
+	/// returns `None` if `range.start > range.end`, or if either is `NaN` or
+	/// infinite. otherwise, this is identical to [`Self::random_range()`].
+	/// 
+	/// ```
+	/// use prrng::Random;
+	/// use prrng::XorShift64;
+	/// 
+	/// let mut rng = XorShift64::new(1);
+	/// 
+	/// assert!(rng.try_random_range(0.0..1.0).is_some());
+	/// assert_eq!(rng.try_random_range(1.0..1.0), Some(1.0));
+	/// assert_eq!(rng.try_random_range(5.0..1.0), None);
+	/// assert_eq!(rng.try_random_range(f64::NAN..1.0), None);
+	/// assert_eq!(rng.try_random_range(0.0..f64::INFINITY), None);
+	/// ```
 	#[inline]
-	fn random_into_buffer<T: FromRandom, const N: usize>(self)
-		-> crate::Buffer<T, N, Self> where Self: Sized
-	{
-		crate::Buffer::new(self)
+	fn try_random_range(&mut self, range: core::ops::Range<f64>) -> Option<f64> {
+		if !range.start.is_finite() || !range.end.is_finite() || range.start > range.end {
+			None
+		} else {
+			Some(self.random_range(range))
+		}
 	}
 
-	/// wrap `&mut self` in a [`crate::buffer::Buffer`] with size `N`.
+	/// returns a new `f64` from a
+	/// [pareto distribution](https://en.wikipedia.org/wiki/Pareto_distribution)
+	/// with minimum `scale` and tail index `shape`.
+	/// 
+	/// the returned value is always at least `scale`. smaller `shape`s give
+	/// heavier tails: with a `shape` of `1` or less, the mean is infinite.
+	/// 
+	/// ```
+	/// use prrng::Random;
+	/// use prrng::XorShift64;
+	/// 
+	/// let mut rng = XorShift64::new(1);
+	/// 
+	/// // file sizes of at least 4 kilobytes
+	/// let size = rng.random_pareto(4096.0, 1.5);
+	/// assert!(size >= 4096.0);
+	/// ```
+	/// 
+	/// ## panics
+	/// 
+	/// panics if `scale` or `shape` is not positive, or is `NaN`.
 	#[inline]
-	fn random_buffer<T: FromRandom, const N: usize>(&mut self)
-		-> crate::Buffer<T, N, &mut Self> where Self: Sized
-	{
-		crate::Buffer::new(self)
-	}
+	#[track_caller]
+	fn random_pareto(&mut self, scale: f64, shape: f64) -> f64 {
+		assert!(scale > 0.0, "`scale` must be positive");
+		assert!(shape > 0.0, "`shape` must be positive");
 
-	/// consume `self`, wrapping it in a [`crate::buffer::Buffer8`] with size `N`.
-	#[inline]
-	fn random_into_buffer8<const N: usize>(self)
-		-> crate::Buffer8<N, Self> where Self: Sized
-	{
-		crate::Buffer8::new(self)
-	}
+		// inverse cdf. `1 - x` is in `(0, 1]`, avoiding `ln(0)`
+		let u = 1.0 - self.random_f64();
+		let x = scale * crate::math::exp(-crate::math::ln(u) / shape);
 
-	/// wrap `&mut self` in a [`crate::buffer::Buffer8`] with size `N`.
-	#[inline]
-	fn random_buffer8<const N: usize>(&mut self)
-		-> crate::Buffer8<N, &mut Self> where Self: Sized
-	{
-		crate::Buffer8::new(self)
+		// rounding in `exp()` could land just below `scale`
+		x.max(scale)
 	}
 
-	/// consume `self`, wrapping it in a [`crate::Crush`], where `N` is how many
-	/// hashes are run per value.
+	/// returns a new `f64` from a
+	/// [log-normal distribution](https://en.wikipedia.org/wiki/Log-normal_distribution),
+	/// whose logarithm is normally distributed with mean `mu` and standard
+	/// deviation `sigma`.
+	/// 
+	/// the normal value comes from the same box-muller transform as
+	/// [`crate::NormalIter`], but the second value of each pair is thrown
+	/// away. when drawing many values with `std` available, mapping
+	/// [`Self::random_normal_iter()`] through `f64::exp` wastes less.
+	/// 
+	/// ```
+	/// use prrng::Random;
+	/// use prrng::XorShift64;
+	/// 
+	/// let mut rng = XorShift64::new(1);
+	/// 
+	/// // request latencies, with a median of e^3 milliseconds
+	/// let latency = rng.random_log_normal(3.0, 0.5);
+	/// assert!(latency > 0.0);
+	/// ```
+	/// 
+	/// ## panics
+	/// 
+	/// panics if `sigma` is not positive, or if `mu` or `sigma` is `NaN`.
 	#[inline]
-	fn random_into_crush<const N: usize>(self, hasher: impl core::hash::Hasher)
-		-> crate::Crush<N, Self, impl core::hash::Hasher> where Self: Sized
-	{
-		crate::Crush::new(self, hasher)
-	}
+	#[track_caller]
+	fn random_log_normal(&mut self, mu: f64, sigma: f64) -> f64 {
+		assert!(!mu.is_nan(), "`mu` must not be NaN");
+		assert!(sigma > 0.0, "`sigma` must be positive");
 
-	/// wrap `&mut self` in a [`crate::Crush`], where `N` is how many
-	/// hashes are run per value.
-	#[inline]
-	fn random_crush<const N: usize>(&mut self, hasher: impl core::hash::Hasher)
-		-> crate::Crush<N, &mut Self, impl core::hash::Hasher> where Self: Sized
-	{
-		crate::Crush::new(self, hasher)
+		let (z, _) = crate::utility::normal::standard_normal_pair(self);
+		crate::math::exp(mu + sigma * z)
 	}
-}
-
-impl<T: RandomImpl> Random for T {
-	fn __random_sealed(_: private::Seal) where Self: Sized {}
-}
 
-impl<T: RandomImpl> RandomImpl for &mut T {
+	/// returns a new `f64` from a
+	/// [cauchy distribution](https://en.wikipedia.org/wiki/Cauchy_distribution)
+	/// centered on `median`, with half of all values within `scale` of it.
+	/// 
+	/// the cauchy distribution has no mean or variance: the average of many
+	/// values does not settle down, no matter how many are drawn. test
+	/// against the median and quartiles instead.
+	/// 
+	/// ```
+	/// use prrng::Random;
+	/// use prrng::XorShift64;
+	/// 
+	/// let mut rng = XorShift64::new(1);
+	/// 
+	/// let x = rng.random_cauchy(10.0, 2.0);
+	/// assert!(x.is_finite());
+	/// ```
+	/// 
+	/// ## panics
+	/// 
+	/// panics if `scale` is not positive, or if `median` or `scale` is
+	/// `NaN`.
 	#[inline]
-	fn random_u64(&mut self) -> u64 {
-		(*self).random_u64()
+	#[track_caller]
+	fn random_cauchy(&mut self, median: f64, scale: f64) -> f64 {
+		assert!(!median.is_nan(), "`median` must not be NaN");
+		assert!(scale > 0.0, "`scale` must be positive");
+
+		// `u` is never `0` or `1`, so this is never exactly at the pole
+		let u = self.random_f64_open();
+		let (sin, cos) = crate::math::sin_cos(core::f64::consts::PI * (u - 0.5));
+		median + scale * (sin / cos)
 	}
 
-	#[inline]
+	/// returns a new `f64` from a
+	/// [gamma distribution](https://en.wikipedia.org/wiki/Gamma_distribution)
+	/// with shape `shape` and scale `scale`. the mean is `shape * scale`,
+	/// and the variance is `shape * scale * scale`.
+	/// 
+	/// this uses
+	/// [marsaglia and tsang's method](https://doi.org/10.1145/358407.358414),
+	/// which draws normal values with the same box-muller transform as
+	/// [`crate::NormalIter`], and rarely rejects one. shapes below `1` draw
+	/// one more uniform value, to scale a `shape + 1` value down.
+	/// 
+	/// ```
+	/// use prrng::Random;
+	/// use prrng::XorShift64;
+	/// 
+	/// let mut rng = XorShift64::new(1);
+	/// 
+	/// let x = rng.random_gamma(2.0, 0.5);
+	/// assert!(x > 0.0);
+	/// ```
+	/// 
+	/// ## panics
+	/// 
+	/// panics if `shape` or `scale` is not positive, or is `NaN`.
+	#[track_caller]
+	fn random_gamma(&mut self, shape: f64, scale: f64) -> f64 {
+		assert!(shape > 0.0, "`shape` must be positive");
+		assert!(scale > 0.0, "`scale` must be positive");
+
+		// gamma(a) = gamma(a + 1) * u^(1 / a)
+		let (a, boost) = if shape < 1.0 {
+			let u = self.random_f64_open();
+			(shape + 1.0, crate::math::exp(crate::math::ln(u) / shape))
+		} else {
+			(shape, 1.0)
+		};
+
+		let d = a - 1.0 / 3.0;
+		let c = 1.0 / crate::math::sqrt(9.0 * d);
+		loop {
+			let (z0, z1) = crate::utility::normal::standard_normal_pair(self);
+			for x in [z0, z1] {
+				let v = 1.0 + c * x;
+				if v <= 0.0 {
+					continue;
+				}
+				let v = v * v * v;
+				let u = self.random_f64_open();
+
+				let x2 = x * x;
+				// the squeeze accepts most values without a `ln()`
+				if u < 1.0 - 0.0331 * x2 * x2
+					|| crate::math::ln(u) < 0.5 * x2 + d * (1.0 - v + crate::math::ln(v))
+				{
+					return d * v * boost * scale;
+				}
+			}
+		}
+	}
+
+	/// returns a new `f64` from a
+	/// [beta distribution](https://en.wikipedia.org/wiki/Beta_distribution)
+	/// with shapes `alpha` and `beta`. the result is always within `[0, 1]`,
+	/// and the mean is `alpha / (alpha + beta)`.
+	/// 
+	/// this is usually `x / (x + y)`, with `x` and `y` from
+	/// [`Self::random_gamma()`]. when both `alpha` and `beta` are at most
+	/// `1`, the gamma values can get small enough to lose all precision, so
+	/// [jöhnk's algorithm](https://en.wikipedia.org/wiki/Beta_distribution#Random_variate_generation)
+	/// is used instead. `alpha == beta == 1` is just
+	/// [`Self::random_f64()`].
+	/// 
+	/// ```
+	/// use prrng::Random;
+	/// use prrng::XorShift64;
+	/// 
+	/// let mut rng = XorShift64::new(1);
+	/// 
+	/// let x = rng.random_beta(2.0, 5.0);
+	/// assert!((0.0..=1.0).contains(&x));
+	/// ```
+	/// 
+	/// ## panics
+	/// 
+	/// panics if `alpha` or `beta` is not positive, or is `NaN`.
+	#[track_caller]
+	fn random_beta(&mut self, alpha: f64, beta: f64) -> f64 {
+		assert!(alpha > 0.0, "`alpha` must be positive");
+		assert!(beta > 0.0, "`beta` must be positive");
+
+		if alpha == 1.0 && beta == 1.0 {
+			return self.random_f64();
+		}
+
+		if alpha <= 1.0 && beta <= 1.0 {
+			// jöhnk
+			loop {
+				let ln_x = crate::math::ln(self.random_f64_open()) / alpha;
+				let ln_y = crate::math::ln(self.random_f64_open()) / beta;
+				let x = crate::math::exp(ln_x);
+				let y = crate::math::exp(ln_y);
+				if x + y > 1.0 {
+					continue;
+				}
+				if x + y > 0.0 {
+					return (x / (x + y)).clamp(0.0, 1.0);
+				}
+
+				// both underflowed, so divide in log space instead
+				let max = ln_x.max(ln_y);
+				let (x, y) = (crate::math::exp(ln_x - max), crate::math::exp(ln_y - max));
+				return (x / (x + y)).clamp(0.0, 1.0);
+			}
+		}
+
+		let x = self.random_gamma(alpha, 1.0);
+		let y = self.random_gamma(beta, 1.0);
+		(x / (x + y)).clamp(0.0, 1.0)
+	}
+
+	/// fill `dst` with a sample from a
+	/// [dirichlet distribution](https://en.wikipedia.org/wiki/Dirichlet_distribution)
+	/// with concentrations `alphas`. every value is within `[0, 1]`, and
+	/// they sum to `1`, up to rounding. the mean of `dst[i]` is
+	/// `alphas[i] / alphas.iter().sum()`.
+	/// 
+	/// each value is a [`Self::random_gamma()`] value, divided by their sum.
+	/// with tiny `alphas`, every gamma value can underflow to `0`, leaving
+	/// nothing to divide. in that case, one value is set to `1` and the rest
+	/// to `0`, picking `dst[i]` with probability proportional to
+	/// `alphas[i]`, which is what the distribution approaches as `alphas`
+	/// shrink.
+	/// 
+	/// this does not allocate.
+	/// 
+	/// ```
+	/// use prrng::Random;
+	/// use prrng::XorShift64;
+	/// 
+	/// let mut rng = XorShift64::new(1);
+	/// 
+	/// let mut weights = [0.0; 3];
+	/// rng.random_dirichlet(&[1.0, 2.0, 3.0], &mut weights);
+	/// assert!((weights.iter().sum::<f64>() - 1.0).abs() < 1e-12);
+	/// ```
+	/// 
+	/// ## panics
+	/// 
+	/// panics if `alphas` and `dst` have different lengths, or if any of
+	/// `alphas` is not positive, or is `NaN`.
+	#[track_caller]
+	fn random_dirichlet(&mut self, alphas: &[f64], dst: &mut [f64]) {
+		assert!(alphas.len() == dst.len(), "`alphas` and `dst` must be the same length");
+		assert!(alphas.iter().all(|&a| a > 0.0), "`alphas` must be positive");
+
+		let mut sum = 0.0;
+		for (o, &a) in dst.iter_mut().zip(alphas) {
+			*o = self.random_gamma(a, 1.0);
+			sum += *o;
+		}
+
+		if sum == 0.0 && !dst.is_empty() {
+			let total: f64 = alphas.iter().sum();
+			let mut pick = self.random_f64() * total;
+			let mut vertex = dst.len() - 1;
+			for (i, &a) in alphas.iter().enumerate() {
+				if pick < a {
+					vertex = i;
+					break;
+				}
+				pick -= a;
+			}
+			dst.fill(0.0);
+			dst[vertex] = 1.0;
+			return;
+		}
+
+		// the second pass fixes most of the rounding from the first
+		for _ in 0..2 {
+			for o in dst.iter_mut() {
+				*o = (*o / sum).min(1.0);
+			}
+			sum = dst.iter().sum();
+		}
+	}
+
+	/// returns a new angle from a
+	/// [von mises distribution](https://en.wikipedia.org/wiki/Von_Mises_distribution),
+	/// the circular analogue of a normal distribution, centered on the
+	/// direction `mu` with concentration `kappa`. the result is always within
+	/// `(-pi, pi]`, whatever `mu` is.
+	/// 
+	/// a `kappa` of `0` is uniform on the circle, and larger `kappa`s
+	/// concentrate the angle around `mu`, roughly like a normal distribution
+	/// with a variance of `1 / kappa`.
+	/// 
+	/// this uses [best and fisher's](https://doi.org/10.2307/2346732)
+	/// rejection method. a `kappa` above `1e6`, where that method runs out of
+	/// precision, draws a wrapped normal value instead, which is
+	/// indistinguishable at that point.
+	/// 
+	/// ```
+	/// use prrng::Random;
+	/// use prrng::XorShift64;
+	/// 
+	/// let mut rng = XorShift64::new(1);
+	/// 
+	/// // wind blowing mostly north east
+	/// let heading = rng.random_von_mises(core::f64::consts::FRAC_PI_4, 4.0);
+	/// assert!(-core::f64::consts::PI < heading && heading <= core::f64::consts::PI);
+	/// ```
+	/// 
+	/// ## panics
+	/// 
+	/// panics if `mu` is not finite, or if `kappa` is negative or `NaN`.
+	#[track_caller]
+	fn random_von_mises(&mut self, mu: f64, kappa: f64) -> f64 {
+		use core::f64::consts::{PI, TAU};
+
+		assert!(mu.is_finite(), "`mu` must be finite");
+		assert!(kappa >= 0.0, "`kappa` must not be negative");
+
+		if kappa < 1e-8 {
+			// uniform within `(-pi, pi]`
+			return PI * (1.0 - 2.0 * self.random_f64());
+		}
+
+		let theta = if kappa > 1e6 {
+			let (z, _) = crate::utility::normal::standard_normal_pair(self);
+			z / crate::math::sqrt(kappa)
+		} else {
+			let s = if kappa < 1e-5 {
+				// the series of the exact expression, which cancels badly here
+				1.0 / kappa + kappa
+			} else {
+				let r = 1.0 + crate::math::sqrt(1.0 + 4.0 * kappa * kappa);
+				let rho = (r - crate::math::sqrt(2.0 * r)) / (2.0 * kappa);
+				(1.0 + rho * rho) / (2.0 * rho)
+			};
+
+			// `w` is the cosine of the angle
+			let w = loop {
+				let (_, z) = crate::math::sin_cos(PI * self.random_f64());
+				let w = (1.0 + s * z) / (s + z);
+				let y = kappa * (s - w);
+				let v = self.random_f64_open();
+				if y * (2.0 - y) >= v || crate::math::ln(y / v) + 1.0 >= y {
+					break w;
+				}
+			};
+
+			let theta = crate::math::atan2(crate::math::sqrt((1.0 - w) * (1.0 + w)), w);
+			if self.random_bool() { theta } else { -theta }
+		};
+
+		// wrap into `(-pi, pi]`
+		let x = (mu + theta + PI) % TAU;
+		let x = if x <= 0.0 { x + TAU } else { x };
+		x - PI
+	}
+
+	/// returns the number of successes in `draws` draws without replacement,
+	/// from `total` items of which `successes` are successes. this follows a
+	/// [hypergeometric distribution](https://en.wikipedia.org/wiki/Hypergeometric_distribution),
+	/// with a mean of `draws * successes / total`.
+	/// 
+	/// the result is always within
+	/// `draws.saturating_sub(total - successes) ..= draws.min(successes)`.
+	/// 
+	/// small cases are sampled by inverting the cdf. larger cases, with more
+	/// than 30 draws (or failures, or items left behind), use stadlober's
+	/// ratio of uniforms method, so the cost doesn't grow with `draws`.
+	/// parameters above `2^53` lose precision in that method.
+	/// 
+	/// ```
+	/// use prrng::Random;
+	/// use prrng::XorShift64;
+	/// 
+	/// let mut rng = XorShift64::new(1);
+	/// 
+	/// // aces in a 5 card hand
+	/// let aces = rng.random_hypergeometric(52, 4, 5);
+	/// assert!(aces <= 4);
+	/// ```
+	/// 
+	/// ## panics
+	/// 
+	/// panics if `successes > total` or `draws > total`.
+	#[track_caller]
+	fn random_hypergeometric(&mut self, total: u64, successes: u64, draws: u64) -> u64 {
+		assert!(successes <= total, "`successes` must not be more than `total`");
+		assert!(draws <= total, "`draws` must not be more than `total`");
+
+		// reduce to `draws <= total / 2` and `successes <= total / 2`, by
+		// counting what is left behind, or the failures, instead
+		let left = draws > total - draws;
+		let n = if left { total - draws } else { draws };
+		let failures = successes > total - successes;
+		let k = if failures { total - successes } else { successes };
+
+		let x = if n == 0 || k == 0 {
+			0
+		} else if n <= 30 {
+			hypergeometric_inversion(self, total, k, n)
+		} else {
+			hypergeometric_hrua(self, total, k, n)
+		};
+
+		let x = if failures { n - x } else { x };
+		if left { successes - x } else { x }
+	}
+
+	/// returns the sum of `count` dice with `sides` sides, each uniformly
+	/// distributed within `1 ..= sides`.
+	/// 
+	/// ```
+	/// use prrng::Random;
+	/// use prrng::XorShift64;
+	/// 
+	/// let mut rng = XorShift64::new(1);
+	/// 
+	/// let x = rng.roll(3, 6);
+	/// assert!((3..=18).contains(&x));
+	/// ```
+	/// 
+	/// ## panics
+	/// 
+	/// panics if `sides` is `0`.
+	fn roll(&mut self, count: u32, sides: u32) -> u64 {
+		assert!(sides != 0, "`sides` must not be 0");
+		let mut sum = 0;
+		for _ in 0..count {
+			sum += self.random_u32_bound(sides) as u64 + 1;
+		}
+		sum
+	}
+
+	/// returns the sum of `count` exploding dice with `sides` sides.
+	/// 
+	/// whenever a die rolls `sides`, it is rolled again and added on. each die
+	/// explodes at most 100 times, so this always terminates, even if `sides`
+	/// is `1`. the sum saturates at `u64::MAX`.
+	/// 
+	/// ## panics
+	/// 
+	/// panics if `sides` is `0`.
+	fn roll_exploding(&mut self, count: u32, sides: u32) -> u64 {
+		assert!(sides != 0, "`sides` must not be 0");
+		let mut sum = 0u64;
+		for _ in 0..count {
+			for _ in 0..=100 {
+				let x = self.random_u32_bound(sides) + 1;
+				sum = sum.saturating_add(x as u64);
+				if x != sides {
+					break;
+				}
+			}
+		}
+		sum
+	}
+
+	/// returns the sum of the highest `keep` of `count` dice with `sides`
+	/// sides.
+	/// 
+	/// the dice that are kept (or dropped, whichever is fewer) are tracked in
+	/// a fixed buffer, so at most 64 dice can be kept or dropped.
+	/// 
+	/// ```
+	/// use prrng::Random;
+	/// use prrng::XorShift64;
+	/// 
+	/// let mut rng = XorShift64::new(1);
+	/// 
+	/// // 4d6, drop the lowest
+	/// let x = rng.roll_keep_highest(4, 6, 3);
+	/// assert!((3..=18).contains(&x));
+	/// ```
+	/// 
+	/// ## panics
+	/// 
+	/// panics if `sides` is `0`, if `keep > count`, or if both `keep` and
+	/// `count - keep` are more than `64`.
+	fn roll_keep_highest(&mut self, count: u32, sides: u32, keep: u32) -> u64 {
+		assert!(sides != 0, "`sides` must not be 0");
+		assert!(keep <= count, "`keep` must not be more than `count`");
+
+		let drop = count - keep;
+		let highest = keep <= drop;
+		let len = if highest { keep } else { drop } as usize;
+		assert!(len <= 64, "`keep` or `count - keep` must not be more than 64");
+
+		// `buf[..n]` holds the most extreme dice seen so far, least extreme
+		// first. extreme means highest when keeping, and lowest when dropping.
+		let more = |a: u32, b: u32| if highest { a > b } else { a < b };
+		let mut buf = [0u32; 64];
+		let mut n = 0;
+		let mut sum = 0u64;
+		for _ in 0..count {
+			let x = self.random_u32_bound(sides) + 1;
+			sum += x as u64;
+
+			if n < len {
+				buf[n] = x;
+				let mut i = n;
+				n += 1;
+				while i > 0 && more(buf[i - 1], buf[i]) {
+					buf.swap(i - 1, i);
+					i -= 1;
+				}
+			} else if len != 0 && more(x, buf[0]) {
+				buf[0] = x;
+				let mut i = 0;
+				while i + 1 < n && more(buf[i], buf[i + 1]) {
+					buf.swap(i, i + 1);
+					i += 1;
+				}
+			}
+		}
+
+		let extreme = buf[..n].iter().map(|&x| x as u64).sum::<u64>();
+		if highest {
+			extreme
+		} else {
+			sum - extreme
+		}
+	}
+
+	/// fill `dst` with a uniformly distributed permutation of
+	/// `0 .. dst.len()`.
+	/// 
+	/// ```
+	/// use prrng::Random;
+	/// use prrng::XorShift64;
+	/// 
+	/// let mut rng = XorShift64::new(1);
+	/// 
+	/// let mut rows = [0; 5];
+	/// rng.random_permutation(&mut rows);
+	/// 
+	/// let mut sorted = rows;
+	/// sorted.sort();
+	/// assert_eq!(sorted, [0, 1, 2, 3, 4]);
+	/// ```
+	fn random_permutation(&mut self, dst: &mut [usize]) {
+		for (i, x) in dst.iter_mut().enumerate() {
+			*x = i;
+		}
+		// fisher-yates
+		for i in (1..dst.len()).rev() {
+			let j = self.random_u64_bound(i as u64 + 1) as usize;
+			dst.swap(i, j);
+		}
+	}
+
+	/// fill `dst` with a uniformly distributed derangement of
+	/// `0 .. dst.len()`: a permutation where no index maps to itself.
+	/// 
+	/// this generates permutations with [`Self::random_permutation()`] until
+	/// one is a derangement. about `1 / e` of all permutations are, so this
+	/// takes about `e ≈ 2.72` attempts on average.
+	/// 
+	/// ## panics
+	/// 
+	/// panics if `dst.len()` is `1`, which has no derangements.
+	fn random_derangement(&mut self, dst: &mut [usize]) {
+		assert!(dst.len() != 1, "`dst` must not have a length of 1");
+		loop {
+			self.random_permutation(dst);
+			if dst.iter().enumerate().all(|(i, &x)| i != x) {
+				return;
+			}
+		}
+	}
+
+	/// fill `dst` with a uniformly distributed sample of the items of `iter`,
+	/// without replacement, in a single pass.
+	/// 
+	/// this uses [algorithm R](https://en.wikipedia.org/wiki/Reservoir_sampling#Simple:_Algorithm_R),
+	/// so it consumes one random value for every item after the first
+	/// `dst.len()`. the order of the sample is not random.
+	/// 
+	/// returns the number of items written, which is less than `dst.len()`
+	/// if `iter` ran out first. if `dst` is empty, `iter` is not consumed.
+	/// 
+	/// ```
+	/// use prrng::Random;
+	/// use prrng::XorShift64;
+	/// 
+	/// let mut rng = XorShift64::new(1);
+	/// 
+	/// let mut sample = [0; 4];
+	/// assert_eq!(rng.sample_reservoir(0..1000, &mut sample), 4);
+	/// assert!(sample.iter().all(|&x| x < 1000));
+	/// 
+	/// assert_eq!(rng.sample_reservoir(0..2, &mut sample), 2);
+	/// assert_eq!(sample[..2], [0, 1]);
+	/// ```
+	fn sample_reservoir<T, I: IntoIterator<Item = T>>(&mut self, iter: I, dst: &mut [T]) -> usize where Self: Sized {
+		if dst.is_empty() {
+			return 0;
+		}
+
+		let mut n = 0;
+		for item in iter {
+			if n < dst.len() {
+				dst[n] = item;
+			} else {
+				let j = self.random_u64_bound(n as u64 + 1);
+				if j < dst.len() as u64 {
+					dst[j as usize] = item;
+				}
+			}
+			n += 1;
+		}
+		n.min(dst.len())
+	}
+
+	/// fill `dst` with `dst.len()` distinct values uniformly sampled from
+	/// `0 .. n`.
+	/// 
+	/// this uses robert floyd's algorithm, which consumes exactly one random
+	/// value per output, no matter how close `dst.len()` is to `n`. checking
+	/// whether a value was already picked scans `dst`, so this takes
+	/// `O(dst.len()^2)` time, and is meant for small `dst`. for large
+	/// samples, taking the front of [`Random::random_permutation()`] (when
+	/// `n` fits in memory) or drawing into a hash set is faster.
+	/// 
+	/// the values are left in the order they were picked in. every set of
+	/// values is equally likely, but the order is not uniformly random: later
+	/// values are more likely to be large.
+	/// 
+	/// ```
+	/// use prrng::Random;
+	/// use prrng::XorShift64;
+	/// 
+	/// let mut rng = XorShift64::new(1);
+	/// 
+	/// let mut rows = [0; 8];
+	/// rng.sample_indices_floyd(1_000_000_000, &mut rows);
+	/// assert!(rows.iter().all(|&x| x < 1_000_000_000));
+	/// ```
+	/// 
+	/// ## panics
+	/// 
+	/// panics if `dst.len()` is more than `n`.
+	fn sample_indices_floyd(&mut self, n: u64, dst: &mut [u64]) {
+		let k = dst.len() as u64;
+		assert!(k <= n, "`dst.len()` must not be more than `n`");
+
+		for (i, j) in (n - k..n).enumerate() {
+			let t = self.random_u64_bound(j + 1);
+			dst[i] = if dst[..i].contains(&t) { j } else { t };
+		}
+	}
+
+	/// fill `dst` with stratified samples within `[0, 1)`.
+	/// 
+	/// `[0, 1)` is split into `dst.len()` equal strata, and `dst[i]` is
+	/// uniformly distributed within the `i`th one. this covers `[0, 1)` more
+	/// evenly than independent values, without the regular pattern of
+	/// `i / n`, which makes it a common choice for sampling pixels or
+	/// integrating.
+	/// 
+	/// ```
+	/// use prrng::Random;
+	/// use prrng::XorShift64;
+	/// 
+	/// let mut rng = XorShift64::new(1);
+	/// 
+	/// let mut samples = [0.0; 4];
+	/// rng.stratified_1d(&mut samples);
+	/// assert!(samples[0] < 0.25);
+	/// assert!(0.75 <= samples[3] && samples[3] < 1.0);
+	/// ```
+	fn stratified_1d(&mut self, dst: &mut [f64]) {
+		let n = dst.len();
+		for (i, x) in dst.iter_mut().enumerate() {
+			*x = stratum(i, n, self.random_f64());
+		}
+	}
+
+	/// fill `dst` with stratified samples within `[0, 1) x [0, 1)`.
+	/// 
+	/// the square is split into a grid of `nx` by `ny` equal cells, and
+	/// `dst[y * nx + x]` is uniformly distributed within cell `(x, y)`.
+	/// 
+	/// ```
+	/// use prrng::Random;
+	/// use prrng::XorShift64;
+	/// 
+	/// let mut rng = XorShift64::new(1);
+	/// 
+	/// let mut samples = [(0.0, 0.0); 4 * 3];
+	/// rng.stratified_2d(&mut samples, 4, 3);
+	/// 
+	/// // the cell at `x = 1`, `y = 2`
+	/// let (x, y) = samples[2 * 4 + 1];
+	/// assert!(0.25 <= x && x < 0.5);
+	/// assert!(2.0 / 3.0 <= y && y < 1.0);
+	/// ```
+	/// 
+	/// ## panics
+	/// 
+	/// panics if `dst.len()` is not `nx * ny`.
+	#[track_caller]
+	fn stratified_2d(&mut self, dst: &mut [(f64, f64)], nx: usize, ny: usize) {
+		assert!(nx.checked_mul(ny) == Some(dst.len()), "`dst.len()` must be `nx * ny`");
+		for (i, p) in dst.iter_mut().enumerate() {
+			*p = (stratum(i % nx, nx, self.random_f64()), stratum(i / nx, ny, self.random_f64()));
+		}
+	}
+
+	/// fill `dst` with a
+	/// [latin hypercube sample](https://en.wikipedia.org/wiki/Latin_hypercube_sampling)
+	/// of `[0, 1) x [0, 1)`.
+	/// 
+	/// both coordinates are stratified like [`Self::stratified_1d()`], so
+	/// every one of the `dst.len()` columns, and every row, holds exactly one
+	/// sample. the `y`s are then shuffled between samples, so the two
+	/// coordinates aren't correlated. unlike [`Self::stratified_2d()`], this
+	/// works for any number of samples, but the samples are only stratified
+	/// along each axis, not in both at once.
+	/// 
+	/// `dst` is in order of `x`.
+	/// 
+	/// ```
+	/// use prrng::Random;
+	/// use prrng::XorShift64;
+	/// 
+	/// let mut rng = XorShift64::new(1);
+	/// 
+	/// let mut samples = [(0.0, 0.0); 7];
+	/// rng.latin_hypercube_2d(&mut samples);
+	/// ```
+	fn latin_hypercube_2d(&mut self, dst: &mut [(f64, f64)]) {
+		let n = dst.len();
+		for (i, p) in dst.iter_mut().enumerate() {
+			*p = (stratum(i, n, self.random_f64()), stratum(i, n, self.random_f64()));
+		}
+		// fisher-yates, over only the `y`s
+		for i in (1..n).rev() {
+			let j = self.random_u64_bound(i as u64 + 1) as usize;
+			let y = dst[i].1;
+			dst[i].1 = dst[j].1;
+			dst[j].1 = y;
+		}
+	}
+
+	/// consume `self`, wrapping it in an iterator [`crate::Iter`]. its [`Iterator::next()`] returns `T`.
+	#[inline]
+	fn random_into_iter<T: crate::FromRandom>(self) -> crate::Iter<T, Self> where Self: Sized {
+		crate::Iter::new(self)
+	}
+
+	/// wrap `&mut self` in an iterator [`crate::Iter`]. its [`Iterator::next()`] returns `T`.
+	#[inline]
+	fn random_iter<T: crate::FromRandom>(&mut self) -> crate::Iter<T, &mut Self> where Self: Sized {
+		crate::Iter::new(self)
+	}
+
+	/// wrap `&mut self` in a [`crate::BoundedIter`], an iterator of `u64`s
+	/// within `0 .. bound`.
+	/// 
+	/// ## panics
+	/// 
+	/// panics if `bound` is `0`.
+	#[inline]
+	fn random_iter_bound(&mut self, bound: u64) -> crate::BoundedIter<'_, Self> where Self: Sized {
+		crate::BoundedIter::new(self, bound)
+	}
+
+	/// wrap `&mut self` in a [`crate::BoundedIter32`], an iterator of `u32`s
+	/// within `0 .. bound`.
+	/// 
+	/// ## panics
+	/// 
+	/// panics if `bound` is `0`.
+	#[inline]
+	fn random_iter_bound32(&mut self, bound: u32) -> crate::BoundedIter32<'_, Self> where Self: Sized {
+		crate::BoundedIter32::new(self, bound)
+	}
+
+	/// wrap `&mut self` in a [`crate::NormalIter`], an iterator of normally
+	/// distributed `f64`s.
+	/// 
+	/// ## panics
+	/// 
+	/// panics if `std_dev` is negative or NaN.
+	#[inline]
+	fn random_normal_iter(&mut self, mean: f64, std_dev: f64) -> crate::NormalIter<&mut Self> where Self: Sized {
+		crate::NormalIter::new(self, mean, std_dev)
+	}
+
+	/// wrap `&mut self` in a [`crate::SampleIter`], an iterator of values
+	/// drawn from `sampler`.
+	/// 
+	/// `sampler` is taken by value, but `&S` is a [`crate::Sample`] too.
+	#[inline]
+	fn sample_iter<T, S: crate::Sample<T>>(&mut self, sampler: S) -> crate::SampleIter<'_, T, S, Self> where Self: Sized {
+		crate::SampleIter::new(self, sampler)
+	}
+
+	/// wrap `&mut self` in a [`crate::Walk`], an iterator of a random walk
+	/// from `start`, moving by up to `step` each item.
+	/// 
+	/// ## panics
+	/// 
+	/// panics if `step` is negative, or is not finite.
+	#[inline]
+	#[track_caller]
+	fn random_walk(&mut self, start: f64, step: f64) -> crate::Walk<'_, Self> where Self: Sized {
+		crate::Walk::new(self, start, step)
+	}
+
+	/// wrap `&mut self` in a [`crate::WalkI64`], an iterator of a random walk
+	/// from `start`, moving by `1` up or down each item.
+	#[inline]
+	fn random_walk_i64(&mut self, start: i64) -> crate::WalkI64<'_, Self> where Self: Sized {
+		crate::WalkI64::new(self, start)
+	}
+
+	/// wrap `&mut self` in a [`crate::Chars`], an iterator of `char`s from
+	/// [`crate::ALPHANUMERIC`].
+	#[inline]
+	fn random_chars_alphanumeric(&mut self) -> crate::Chars<'static, &mut Self> where Self: Sized {
+		crate::Chars::alphanumeric(self)
+	}
+
+	/// wrap `&mut self` in a [`crate::Chars`], an iterator of `char`s from
+	/// [`crate::HEX`].
+	#[inline]
+	fn random_chars_hex(&mut self) -> crate::Chars<'static, &mut Self> where Self: Sized {
+		crate::Chars::hex(self)
+	}
+
+	/// wrap `&mut self` in a [`crate::Chars`], an iterator of `char`s from
+	/// `charset`.
+	/// 
+	/// ## panics
+	/// 
+	/// panics if `charset` is empty.
+	#[inline]
+	fn random_chars_from<'a>(&mut self, charset: &'a [char]) -> crate::Chars<'a, &mut Self> where Self: Sized {
+		crate::Chars::new(self, charset)
+	}
+
+	/// consume `self`, wrapping it in a [`crate::buffer::Buffer`] with size `N`.
+	#[inline]
+	fn random_into_buffer<T: FromRandom, const N: usize>(self)
+		-> crate::Buffer<T, N, Self> where Self: Sized
+	{
+		crate::Buffer::new(self)
+	}
+
+	/// wrap `&mut self` in a [`crate::buffer::Buffer`] with size `N`.
+	#[inline]
+	fn random_buffer<T: FromRandom, const N: usize>(&mut self)
+		-> crate::Buffer<T, N, &mut Self> where Self: Sized
+	{
+		crate::Buffer::new(self)
+	}
+
+	/// consume `self`, wrapping it in a [`crate::buffer::Buffer8`] with size `N`.
+	#[inline]
+	fn random_into_buffer8<const N: usize>(self)
+		-> crate::Buffer8<N, Self> where Self: Sized
+	{
+		crate::Buffer8::new(self)
+	}
+
+	/// wrap `&mut self` in a [`crate::buffer::Buffer8`] with size `N`.
+	#[inline]
+	fn random_buffer8<const N: usize>(&mut self)
+		-> crate::Buffer8<N, &mut Self> where Self: Sized
+	{
+		crate::Buffer8::new(self)
+	}
+
+	/// consume `self`, wrapping it in a [`crate::Crush`], where `N` is how many
+	/// hashes are run per value.
+	#[inline]
+	fn random_into_crush<const N: usize>(self, hasher: impl core::hash::BuildHasher)
+		-> crate::Crush<N, Self, impl core::hash::BuildHasher> where Self: Sized
+	{
+		crate::Crush::new(self, hasher)
+	}
+
+	/// wrap `&mut self` in a [`crate::Crush`], where `N` is how many
+	/// hashes are run per value.
+	#[inline]
+	fn random_crush<const N: usize>(&mut self, hasher: impl core::hash::BuildHasher)
+		-> crate::Crush<N, &mut Self, impl core::hash::BuildHasher> where Self: Sized
+	{
+		crate::Crush::new(self, hasher)
+	}
+
+	/// consume `self`, wrapping it in a [`crate::Crush32`], where `N` is how many
+	/// hashes are run per value.
+	#[inline]
+	fn random_into_crush32<const N: usize>(self, hasher: impl core::hash::BuildHasher)
+		-> crate::Crush32<N, Self, impl core::hash::BuildHasher> where Self: Sized
+	{
+		crate::Crush32::new(self, hasher)
+	}
+
+	/// wrap `&mut self` in a [`crate::Crush32`], where `N` is how many
+	/// hashes are run per value.
+	#[inline]
+	fn random_crush32<const N: usize>(&mut self, hasher: impl core::hash::BuildHasher)
+		-> crate::Crush32<N, &mut Self, impl core::hash::BuildHasher> where Self: Sized
+	{
+		crate::Crush32::new(self, hasher)
+	}
+
+	/// consume `self`, wrapping it in a [`crate::Decimate`], where `K` is how
+	/// many values are discarded per value.
+	#[inline]
+	fn random_into_decimate<const K: usize>(self) -> crate::Decimate<K, Self> where Self: Sized {
+		crate::Decimate::new(self)
+	}
+
+	/// wrap `&mut self` in a [`crate::Decimate`], where `K` is how many values
+	/// are discarded per value.
+	#[inline]
+	fn random_decimate<const K: usize>(&mut self) -> crate::Decimate<K, &mut Self> where Self: Sized {
+		crate::Decimate::new(self)
+	}
+
+	/// consume `self` and `other`, wrapping them in a [`crate::Combine`].
+	#[inline]
+	fn random_combine<B: Random>(self, other: B) -> crate::Combine<Self, B> where Self: Sized {
+		crate::Combine::new(self, other)
+	}
+}
+
+impl<T: RandomImpl> Random for T {
+	fn __random_sealed(_: private::Seal) where Self: Sized {}
+}
+
+impl<T: RandomImpl> RandomImpl for &mut T {
+	#[inline]
+	fn random_u64(&mut self) -> u64 {
+		(*self).random_u64()
+	}
+
+	#[inline]
 	fn random_u32(&mut self) -> u32 {
 		(*self).random_u32()
 	}
 
 	#[inline]
-	fn random_bytes(&mut self, dst: &mut [u8]) {
-		(*self).random_bytes(dst);
+	fn random_bytes(&mut self, dst: &mut [u8]) {
+		(*self).random_bytes(dst);
+	}
+
+	#[inline]
+	fn random_bytes_uninit<'a>(&mut self, dst: &'a mut [core::mem::MaybeUninit<u8>]) -> &'a mut [u8] {
+		(*self).random_bytes_uninit(dst)
+	}
+}
+
+impl RandomImpl for &mut dyn RandomImpl {
+	#[inline]
+	fn random_u64(&mut self) -> u64 {
+		(*self).random_u64()
+	}
+
+	#[inline]
+	fn random_u32(&mut self) -> u32 {
+		(*self).random_u32()
+	}
+
+	fn random_bytes(&mut self, dst: &mut [u8]) {
+		(*self).random_bytes(dst);
+	}
+
+	fn random_bytes_uninit<'a>(&mut self, dst: &'a mut [core::mem::MaybeUninit<u8>]) -> &'a mut [u8] {
+		(*self).random_bytes_uninit(dst)
+	}
+}
+
+/// randomized constructor.
+/// 
+/// this trait defines a constructor [`FromRandom::from_random()`], that
+/// takes a [`Random`] and returns a fully initialized `Self`.
+/// this is used with `Random`'s generic [`Random::random()`] method.
+/// 
+/// `FromRandom` is already implemented for most of Rust's primitives, arrays,
+/// and tuples up to a length of 8. it is also implemented for most generators
+/// in this crate, constructing them with seeds from another rng.
+/// 
+/// ```
+/// use prrng::Random;
+/// use prrng::SplitMix64;
+/// use prrng::XorShift64;
+/// 
+/// let mut seeder = SplitMix64::new(1);
+/// let rng: XorShift64 = seeder.random();
+/// ```
+/// 
+/// with the `derive` feature, `#[derive(FromRandom)]` implements it for
+/// structs and enums whose fields all implement it. an enum picks its
+/// variant uniformly, or by `#[from_random(weight = n)]`.
+/// 
+/// ```
+/// # #[cfg(feature = "derive")] {
+/// use prrng::{FromRandom, Random, XorShift64};
+/// 
+/// #[derive(FromRandom)]
+/// enum Tile {
+///     #[from_random(weight = 8)]
+///     Grass,
+///     Water { depth: u8 },
+///     Ore(u16, bool),
+/// }
+/// 
+/// let map: [[Tile; 16]; 16] = XorShift64::new(1).random();
+/// # }
+/// ```
+pub trait FromRandom {
+	fn from_random(random: &mut impl Random) -> Self;
+}
+
+impl FromRandom for f64 {
+	fn from_random(random: &mut impl Random) -> Self {
+		crate::common::u64_normalize_f64(random.random_u64())
+	}
+}
+
+impl FromRandom for f32 {
+	fn from_random(random: &mut impl Random) -> Self {
+		crate::common::u32_normalize_f32(random.random_u32())
+	}
+}
+
+impl FromRandom for u128 {
+	fn from_random(random: &mut impl Random) -> Self {
+		crate::common::u64_compose_u128(random.random_u64(), random.random_u64())
+	}
+}
+
+impl FromRandom for i128 {
+	fn from_random(random: &mut impl Random) -> Self {
+		crate::common::u64_compose_u128(random.random_u64(), random.random_u64()).cast_signed()
+	}
+}
+
+impl FromRandom for u64 {
+	fn from_random(random: &mut impl Random) -> Self {
+		random.random_u64()
+	}
+}
+
+impl FromRandom for i64 {
+	fn from_random(random: &mut impl Random) -> Self {
+		random.random_u64().cast_signed()
+	}
+}
+
+impl FromRandom for u32 {
+	fn from_random(random: &mut impl Random) -> Self {
+		random.random_u32()
+	}
+}
+
+impl FromRandom for i32 {
+	fn from_random(random: &mut impl Random) -> Self {
+		random.random_u32().cast_signed()
+	}
+}
+
+impl FromRandom for u16 {
+	fn from_random(random: &mut impl Random) -> Self {
+		random.random_u16()
+	}
+}
+
+impl FromRandom for i16 {
+	fn from_random(random: &mut impl Random) -> Self {
+		random.random_u16().cast_signed()
+	}
+}
+
+impl FromRandom for u8 {
+	fn from_random(random: &mut impl Random) -> Self {
+		random.random_u8()
+	}
+}
+
+impl FromRandom for i8 {
+	fn from_random(random: &mut impl Random) -> Self {
+		random.random_u8().cast_signed()
+	}
+}
+
+impl FromRandom for bool {
+	fn from_random(random: &mut impl Random) -> Self {
+		random.random_bool()
+	}
+}
+
+impl<const N: usize, T: FromRandom> FromRandom for [T; N] {
+	fn from_random(random: &mut impl Random) -> Self {
+		core::array::from_fn(|_| random.random())
+	}
+}
+
+/// `None` half of the time, and a random `T` otherwise.
+/// 
+/// this draws a `bool` first, and only draws a `T` if it is `true`. this
+/// means the number of values consumed depends on the result, so a later
+/// draw from the same rng may be shifted depending on whether this returned
+/// `None`.
+impl<T: FromRandom> FromRandom for Option<T> {
+	fn from_random(random: &mut impl Random) -> Self {
+		if random.random_bool() {
+			Some(random.random())
+		} else {
+			None
+		}
+	}
+}
+
+/// `Less`, `Equal` or `Greater`, each with equal probability.
+impl FromRandom for core::cmp::Ordering {
+	fn from_random(random: &mut impl Random) -> Self {
+		// both consume a `u32` per attempt, but `u8` rejects far more often
+		match random.random_u32_bound(3) {
+			0 => core::cmp::Ordering::Less,
+			1 => core::cmp::Ordering::Equal,
+			_ => core::cmp::Ordering::Greater,
+		}
+	}
+}
+
+impl FromRandom for () {
+	fn from_random(_: &mut impl Random) -> Self {}
+}
+
+impl<A: FromRandom> FromRandom for (A,) {
+	fn from_random(random: &mut impl Random) -> Self {
+		(random.random(),)
+	}
+}
+
+impl<
+	A: FromRandom,
+	B: FromRandom,
+	> FromRandom for (A, B) {
+	fn from_random(random: &mut impl Random) -> Self {
+		(
+			random.random(),
+			random.random(),
+		)
+	}
+}
+
+impl<
+	A: FromRandom,
+	B: FromRandom,
+	C: FromRandom,
+	> FromRandom for (A, B, C) {
+	fn from_random(random: &mut impl Random) -> Self {
+		(
+			random.random(),
+			random.random(),
+			random.random(),
+		)
+	}
+}
+
+impl<
+	A: FromRandom,
+	B: FromRandom,
+	C: FromRandom,
+	D: FromRandom,
+	> FromRandom for (A, B, C, D) {
+	fn from_random(random: &mut impl Random) -> Self {
+		(
+			random.random(),
+			random.random(),
+			random.random(),
+			random.random(),
+		)
+	}
+}
+
+impl<
+	A: FromRandom,
+	B: FromRandom,
+	C: FromRandom,
+	D: FromRandom,
+	E: FromRandom,
+	> FromRandom for (A, B, C, D, E) {
+	fn from_random(random: &mut impl Random) -> Self {
+		(
+			random.random(),
+			random.random(),
+			random.random(),
+			random.random(),
+			random.random(),
+		)
+	}
+}
+
+impl<
+	A: FromRandom,
+	B: FromRandom,
+	C: FromRandom,
+	D: FromRandom,
+	E: FromRandom,
+	F: FromRandom,
+	> FromRandom for (A, B, C, D, E, F) {
+	fn from_random(random: &mut impl Random) -> Self {
+		(
+			random.random(),
+			random.random(),
+			random.random(),
+			random.random(),
+			random.random(),
+			random.random(),
+		)
+	}
+}
+
+impl<
+	A: FromRandom,
+	B: FromRandom,
+	C: FromRandom,
+	D: FromRandom,
+	E: FromRandom,
+	F: FromRandom,
+	G: FromRandom,
+	> FromRandom for (A, B, C, D, E, F, G) {
+	fn from_random(random: &mut impl Random) -> Self {
+		(
+			random.random(),
+			random.random(),
+			random.random(),
+			random.random(),
+			random.random(),
+			random.random(),
+			random.random(),
+		)
+	}
+}
+
+impl<
+	A: FromRandom,
+	B: FromRandom,
+	C: FromRandom,
+	D: FromRandom,
+	E: FromRandom,
+	F: FromRandom,
+	G: FromRandom,
+	H: FromRandom,
+	> FromRandom for (A, B, C, D, E, F, G, H) {
+	fn from_random(random: &mut impl Random) -> Self {
+		(
+			random.random(),
+			random.random(),
+			random.random(),
+			random.random(),
+			random.random(),
+			random.random(),
+			random.random(),
+			random.random(),
+		)
+	}
+}
+
+/// fallible randomized constructor.
+/// 
+/// this is [`FromRandom`], for types where the values drawn don't always
+/// make a valid `Self`, such as a `char` from an arbitrary `u32`.
+/// [`TryFromRandom::try_from_random()`] makes a single attempt, returning
+/// `None` if it failed. this is used with [`Random::random_try()`] and
+/// [`Random::random_retry()`].
+/// 
+/// every [`FromRandom`] type also implements `TryFromRandom`, never
+/// failing, so generic code can take either.
+/// 
+/// ```
+/// use prrng::{Random, TryFromRandom};
+/// use prrng::XorShift64;
+/// 
+/// enum Suit {
+///     Clubs,
+///     Diamonds,
+///     Hearts,
+///     Spades,
+/// }
+/// 
+/// // a discriminant from a raw byte, which is usually out of range
+/// impl TryFromRandom for Suit {
+///     fn try_from_random(random: &mut impl Random) -> Option<Self> {
+///         match random.random_u8() {
+///             0 => Some(Suit::Clubs),
+///             1 => Some(Suit::Diamonds),
+///             2 => Some(Suit::Hearts),
+///             3 => Some(Suit::Spades),
+///             _ => None,
+///         }
+///     }
+/// }
+/// 
+/// let mut rng = XorShift64::new(1);
+/// let suit: Option<Suit> = rng.random_retry(1000);
+/// ```
+pub trait TryFromRandom: Sized {
+	fn try_from_random(random: &mut impl Random) -> Option<Self>;
+}
+
+impl<T: FromRandom> TryFromRandom for T {
+	#[inline]
+	fn try_from_random(random: &mut impl Random) -> Option<Self> {
+		Some(random.random())
+	}
+}
+
+/// the low 21 bits of a `u32`, if they are a valid `char`.
+/// 
+/// this fails a little under half of the time, for values past
+/// `char::MAX` and for surrogates. every valid `char` is equally likely.
+impl TryFromRandom for char {
+	#[inline]
+	fn try_from_random(random: &mut impl Random) -> Option<Self> {
+		char::from_u32(random.random_u32() & 0x1f_ffff)
+	}
+}
+
+macro_rules! impl_nonzero {
+	($($t:ty),*) => {$(
+		/// a random value, if it isn't `0`.
+		impl TryFromRandom for core::num::NonZero<$t> {
+			#[inline]
+			fn try_from_random(random: &mut impl Random) -> Option<Self> {
+				core::num::NonZero::new(random.random())
+			}
+		}
+	)*};
+}
+
+impl_nonzero!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
+
+/// randomized constructor, constrained by a runtime parameter.
+/// 
+/// this is [`FromRandom`], for types generated within some `Bound`, like an
+/// index below a length. this is used with [`Random::random_bounded()`],
+/// giving generic code one method for what is otherwise
+/// [`Random::random_u32_bound()`], [`Random::random_range()`], and so on.
+/// 
+/// ```
+/// use prrng::{FromRandomBounded, Random};
+/// use prrng::XorShift64;
+/// 
+/// fn pick<T: FromRandomBounded>(rng: &mut impl Random, bound: &T::Bound) -> [T; 2] {
+///     [rng.random_bounded(bound), rng.random_bounded(bound)]
+/// }
+/// 
+/// let mut rng = XorShift64::new(1);
+/// let dice: [u8; 2] = pick(&mut rng, &6);
+/// let point: [f64; 2] = pick(&mut rng, &(0.0..10.0));
+/// ```
+pub trait FromRandomBounded: Sized {
+	type Bound;
+
+	fn from_random_bounded(random: &mut impl Random, bound: &Self::Bound) -> Self;
+}
+
+/// within `0 .. bound`, as [`Random::random_u8_bound()`].
+impl FromRandomBounded for u8 {
+	type Bound = u8;
+
+	#[inline]
+	fn from_random_bounded(random: &mut impl Random, bound: &u8) -> Self {
+		random.random_u8_bound(*bound)
+	}
+}
+
+/// within `0 .. bound`, as [`Random::random_u16_bound()`].
+impl FromRandomBounded for u16 {
+	type Bound = u16;
+
+	#[inline]
+	fn from_random_bounded(random: &mut impl Random, bound: &u16) -> Self {
+		random.random_u16_bound(*bound)
 	}
 }
 
-impl RandomImpl for &mut dyn RandomImpl {
+/// within `0 .. bound`, as [`Random::random_u32_bound()`].
+impl FromRandomBounded for u32 {
+	type Bound = u32;
+
 	#[inline]
-	fn random_u64(&mut self) -> u64 {
-		(*self).random_u64()
+	fn from_random_bounded(random: &mut impl Random, bound: &u32) -> Self {
+		random.random_u32_bound(*bound)
 	}
+}
+
+/// within `0 .. bound`, as [`Random::random_u64_bound()`].
+impl FromRandomBounded for u64 {
+	type Bound = u64;
 
 	#[inline]
-	fn random_u32(&mut self) -> u32 {
-		(*self).random_u32()
+	fn from_random_bounded(random: &mut impl Random, bound: &u64) -> Self {
+		random.random_u64_bound(*bound)
 	}
+}
 
-	fn random_bytes(&mut self, dst: &mut [u8]) {
-		(*self).random_bytes(dst);
+/// within `0 .. bound`, as [`Random::random_u128_bound()`].
+impl FromRandomBounded for u128 {
+	type Bound = u128;
+
+	#[inline]
+	fn from_random_bounded(random: &mut impl Random, bound: &u128) -> Self {
+		random.random_u128_bound(*bound)
 	}
 }
 
-/// randomized constructor.
-/// 
-/// this trait defines a constructor [`FromRandom::from_random()`], that
-/// takes a [`Random`] and returns a fully initialized `Self`.
-/// this is used with `Random`'s generic [`Random::random()`] method.
-/// 
-/// `FromRandom` is already implemented for most of Rust's primitives, arrays,
-/// and tuples up to a length of 8.
-pub trait FromRandom {
-	fn from_random(random: &mut impl Random) -> Self;
+/// within `0 .. bound`, as [`Random::random_u64_bound()`], so the same on
+/// every platform.
+impl FromRandomBounded for usize {
+	type Bound = usize;
+
+	#[inline]
+	fn from_random_bounded(random: &mut impl Random, bound: &usize) -> Self {
+		random.random_u64_bound(*bound as u64) as usize
+	}
 }
 
-impl FromRandom for f64 {
-	fn from_random(random: &mut impl Random) -> Self {
-		crate::common::u64_normalize_f64(random.random_u64())
+/// within `bound`, as [`Random::random_range()`].
+impl FromRandomBounded for f64 {
+	type Bound = core::ops::Range<f64>;
+
+	#[inline]
+	#[track_caller]
+	fn from_random_bounded(random: &mut impl Random, bound: &core::ops::Range<f64>) -> Self {
+		random.random_range(bound.clone())
 	}
 }
 
-impl FromRandom for f32 {
-	fn from_random(random: &mut impl Random) -> Self {
-		crate::common::u32_normalize_f32(random.random_u32())
+/// within `0 .. bound`, to the nanosecond.
+/// 
+/// this is [`Random::random_u64_bound()`] over the nanoseconds of `bound`,
+/// or [`Random::random_u128_bound()`] if `bound` is too long for that, over
+/// about 584 years.
+impl FromRandomBounded for core::time::Duration {
+	type Bound = core::time::Duration;
+
+	fn from_random_bounded(random: &mut impl Random, bound: &core::time::Duration) -> Self {
+		const NANOS_PER_SEC: u128 = 1_000_000_000;
+
+		let nanos = match u64::try_from(bound.as_nanos()) {
+			Ok(bound) => random.random_u64_bound(bound) as u128,
+			Err(_) => random.random_u128_bound(bound.as_nanos()),
+		};
+		core::time::Duration::new((nanos / NANOS_PER_SEC) as u64, (nanos % NANOS_PER_SEC) as u32)
 	}
 }
 
-impl FromRandom for u128 {
-	fn from_random(random: &mut impl Random) -> Self {
-		crate::common::u64_compose_u128(random.random_u64(), random.random_u64())
+
+// a value within the `i`th of `n` equal strata of `[0, 1)`, from `u` in
+// `[0, 1)`.
+#[inline]
+fn stratum(i: usize, n: usize, u: f64) -> f64 {
+	let x = (i as f64 + u) / n as f64;
+	// `i + u` can round up to `i + 1`
+	let end = (i + 1) as f64 / n as f64;
+	if x < end { x } else { end.next_down() }
+}
+
+// hypergeometric sample by inverting the cdf, for `n <= 30`. expects
+// `k <= total / 2` and `n <= total / 2`, so the support starts at `0`.
+fn hypergeometric_inversion(random: &mut (impl Random + ?Sized), total: u64, k: u64, n: u64) -> u64 {
+	let (total_f, k_f, n_f) = (total as f64, k as f64, n as f64);
+
+	// p(0), every factor is at least about 1/2
+	let mut p = 1.0;
+	for i in 0..n {
+		p *= (total_f - k_f - i as f64) / (total_f - i as f64);
+	}
+
+	let max = n.min(k);
+	let mut u = random.random_f64();
+	let mut x = 0;
+	while u >= p && x < max {
+		u -= p;
+		let x_f = x as f64;
+		p *= (k_f - x_f) * (n_f - x_f) / ((x_f + 1.0) * (total_f - k_f - n_f + x_f + 1.0));
+		x += 1;
 	}
+	x
 }
 
-impl FromRandom for i128 {
-	fn from_random(random: &mut impl Random) -> Self {
-		crate::common::u64_compose_u128(random.random_u64(), random.random_u64()).cast_signed()
+// hypergeometric sample with the ratio of uniforms method, "hrua" from
+// stadlober (1989). expects `k <= total / 2` and `n <= total / 2`.
+fn hypergeometric_hrua(random: &mut (impl Random + ?Sized), total: u64, k: u64, n: u64) -> u64 {
+	use crate::math::{ln, ln_factorial, sqrt};
+
+	// 2 * sqrt(2 / e), and 3 - 2 * sqrt(3 / e)
+	const D1: f64 = 1.7155277699214135;
+	const D2: f64 = 0.8989161620588988;
+
+	let (total_f, k_f, n_f) = (total as f64, k as f64, n as f64);
+	let bad = total - k;
+
+	let p = k_f / total_f;
+	let mean = n_f * p + 0.5;
+	let variance = (total_f - n_f) * n_f * p * (1.0 - p) / (total_f - 1.0);
+	let width = D1 * sqrt(variance + 0.5) + D2;
+
+	let mode = ((n_f + 1.0) * (k_f + 1.0) / (total_f + 2.0)) as u64;
+	let ln_pmf = |x: u64| -> f64 {
+		-(ln_factorial(x) + ln_factorial(k - x) + ln_factorial(n - x) + ln_factorial(bad - n + x))
+	};
+	let ln_mode = ln_pmf(mode);
+
+	// values past this are too unlikely to matter
+	let end = ((n.min(k) + 1) as f64).min((mean + 16.0 * sqrt(variance + 0.5)) as u64 as f64);
+
+	loop {
+		let x = random.random_f64_open();
+		let y = random.random_f64();
+		let w = mean + width * (y - 0.5) / x;
+		if !(0.0..end).contains(&w) {
+			continue;
+		}
+
+		let z = w as u64;
+		let t = ln_pmf(z) - ln_mode;
+
+		// quick accept, and quick reject, before the exact test
+		if x * (4.0 - x) - 3.0 <= t {
+			return z;
+		}
+		if x * (x - t) >= 1.0 {
+			continue;
+		}
+		if 2.0 * ln(x) <= t {
+			return z;
+		}
+	}
+}
+
+
+#[cfg(test)]
+mod test {
+	use crate::{Random, RandomImpl};
+
+	#[test]
+	fn test_main() {
+		let mut rng = crate::Static::new(|| 0.5);
+
+		let _x: (i16, u64) = rng.random();
+	}
+
+	#[test]
+	fn test_dyn() {
+		let object: &mut dyn crate::Random = &mut crate::Static::new(|| 0.0);
+
+		object.random_f64();
+		object.random_f32();
+		object.random_u64();
+		object.random_u32();
+
+		let _object: &mut dyn crate::Random = &mut crate::Static::new(|| 0.0).random_iter::<()>();
+	}
+
+	#[test]
+	fn test_u128_bound() {
+		let mut rng = crate::XorShift64::new(1);
+
+		for _ in 0..64 {
+			assert_eq!(rng.random_u128_bound(1), 0);
+			assert!(rng.random_u128_bound((1 << 127) + 1) <= 1 << 127);
+			assert!(rng.random_u128_bound(u128::MAX) < u128::MAX);
+			assert!(rng.random_u128_bound(1 << 100) < 1 << 100);
+		}
+
+		let mut counts = [0u32; 5];
+		for _ in 0..10000 {
+			counts[rng.random_u128_bound(5) as usize] += 1;
+		}
+		for i in counts {
+			assert!(i > 1800 && i < 2200);
+		}
+	}
+
+	#[test]
+	#[should_panic(expected = "`bound` must not be 0")]
+	fn test_zero_bound() {
+		let mut rng = crate::XorShift64::new(1);
+		rng.random_u32_bound(0);
+	}
+
+	#[test]
+	fn test_range() {
+		let mut rng = crate::Counting::new(crate::XorShift64::new(1));
+
+		assert_eq!(rng.random_range(2.0..2.0), 2.0);
+		assert_eq!(rng.u64_calls(), 0);
+
+		for _ in 0..64 {
+			let x = rng.random_range(-3.0..5.0);
+			assert!((-3.0..5.0).contains(&x));
+		}
+
+		assert_eq!(rng.try_random_range(5.0..1.0), None);
+		assert_eq!(rng.try_random_range(0.0..f64::NAN), None);
+	}
+
+	#[test]
+	fn test_pareto_log_normal() {
+		let mut rng = crate::XorShift64::new(1);
+		let n = 100000;
+
+		// about half of the values should be below the analytic median
+		for (scale, shape) in [(1.0, 1.0), (4096.0, 1.5), (0.5, 8.0)] {
+			let median = scale * crate::math::exp(core::f64::consts::LN_2 / shape);
+			let mut below = 0;
+			for _ in 0..n {
+				let x = rng.random_pareto(scale, shape);
+				assert!(x >= scale);
+				below += (x < median) as u32;
+			}
+			let ratio = below as f64 / n as f64;
+			assert!((ratio - 0.5).abs() < 0.01, "pareto {scale} {shape}: {ratio}");
+		}
+
+		for (mu, sigma) in [(0.0, 1.0), (3.0, 0.5), (-2.0, 2.0)] {
+			let median = crate::math::exp(mu);
+			let mut below = 0;
+			for _ in 0..n {
+				let x = rng.random_log_normal(mu, sigma);
+				assert!(x > 0.0);
+				below += (x < median) as u32;
+			}
+			let ratio = below as f64 / n as f64;
+			assert!((ratio - 0.5).abs() < 0.01, "log normal {mu} {sigma}: {ratio}");
+		}
+
+		// `random_f64()` returning `0` gives exactly `scale`
+		let mut rng = crate::StaticU64::new(|| 0);
+		assert_eq!(rng.random_pareto(3.0, 2.0), 3.0);
+		assert!(rng.random_log_normal(0.0, 1.0).is_finite());
+	}
+
+	#[test]
+	#[should_panic(expected = "`shape` must be positive")]
+	fn test_pareto_shape() {
+		crate::XorShift64::new(1).random_pareto(1.0, 0.0);
+	}
+
+	#[test]
+	#[should_panic(expected = "`sigma` must be positive")]
+	fn test_log_normal_sigma() {
+		crate::XorShift64::new(1).random_log_normal(0.0, f64::NAN);
+	}
+
+	#[test]
+	fn test_cauchy() {
+		let mut rng = crate::XorShift64::new(1);
+		let n = 100000;
+
+		// the quartiles are at `median - scale` and `median + scale`
+		for (median, scale) in [(0.0, 1.0), (10.0, 2.0), (-5.0, 0.01)] {
+			let mut below = [0u32; 3];
+			for _ in 0..n {
+				let x = rng.random_cauchy(median, scale);
+				assert!(x.is_finite());
+				for (b, q) in below.iter_mut().zip([median - scale, median, median + scale]) {
+					*b += (x < q) as u32;
+				}
+			}
+			for (b, expect) in below.into_iter().zip([0.25, 0.5, 0.75]) {
+				let ratio = b as f64 / n as f64;
+				assert!((ratio - expect).abs() < 0.01, "cauchy {median} {scale}: {ratio}");
+			}
+		}
+
+		// the most extreme `u`s still give finite values
+		for x in [0, u64::MAX] {
+			let mut rng = crate::StaticU64::new(move || x);
+			assert!(rng.random_cauchy(0.0, 1.0).is_finite());
+		}
+	}
+
+	#[test]
+	#[should_panic(expected = "`scale` must be positive")]
+	fn test_cauchy_scale() {
+		crate::XorShift64::new(1).random_cauchy(0.0, -1.0);
+	}
+
+	#[test]
+	fn test_gamma() {
+		let mut rng = crate::XorShift64::new(1);
+		let n = 100000;
+
+		for (shape, scale) in [(0.3, 1.0), (1.0, 2.0), (2.5, 0.5), (50.0, 0.1), (0.9, 10.0)] {
+			let mut sum = 0.0;
+			let mut sum_sq = 0.0;
+			for _ in 0..n {
+				let x = rng.random_gamma(shape, scale);
+				assert!(x > 0.0 && x.is_finite());
+				sum += x;
+				sum_sq += x * x;
+			}
+			let mean = sum / n as f64;
+			let variance = sum_sq / n as f64 - mean * mean;
+
+			let expect_mean = shape * scale;
+			let expect_variance = shape * scale * scale;
+			assert!((mean - expect_mean).abs() < 0.02 * expect_mean, "gamma {shape} {scale}: mean {mean}");
+			assert!((variance - expect_variance).abs() < 0.05 * expect_variance, "gamma {shape} {scale}: variance {variance}");
+		}
+	}
+
+	#[test]
+	#[should_panic(expected = "`shape` must be positive")]
+	fn test_gamma_shape() {
+		crate::XorShift64::new(1).random_gamma(0.0, 1.0);
+	}
+
+	#[test]
+	fn test_beta() {
+		let mut rng = crate::XorShift64::new(1);
+		let n = 50000;
+
+		for alpha in [0.01, 0.5, 1.0, 2.0, 30.0] {
+			for beta in [0.01, 0.5, 1.0, 3.0, 30.0] {
+				let mut sum = 0.0;
+				for _ in 0..n {
+					let x = rng.random_beta(alpha, beta);
+					assert!((0.0..=1.0).contains(&x), "beta {alpha} {beta}: {x}");
+					sum += x;
+				}
+				let mean = sum / n as f64;
+				let expect = alpha / (alpha + beta);
+				assert!((mean - expect).abs() < 0.01, "beta {alpha} {beta}: mean {mean}");
+			}
+		}
+
+		// tiny parameters underflow in jöhnk's algorithm, and still work
+		for _ in 0..1000 {
+			let x = rng.random_beta(1e-4, 1e-4);
+			assert!((0.0..=1.0).contains(&x));
+		}
+
+		let mut a = crate::XorShift64::new(7);
+		let mut b = crate::XorShift64::new(7);
+		for (alpha, beta) in [(0.3, 0.7), (1.0, 1.0), (4.0, 0.2)] {
+			for _ in 0..100 {
+				assert_eq!(a.random_beta(alpha, beta), b.random_beta(alpha, beta));
+			}
+		}
+	}
+
+	#[test]
+	#[should_panic(expected = "`beta` must be positive")]
+	fn test_beta_beta() {
+		crate::XorShift64::new(1).random_beta(1.0, f64::NAN);
+	}
+
+	#[test]
+	fn test_dirichlet() {
+		let mut rng = crate::XorShift64::new(1);
+		let n = 20000;
+
+		for alphas in [&[1.0, 1.0][..], &[1.0, 2.0, 3.0], &[0.1, 0.1, 0.1, 5.0], &[50.0, 0.5, 10.0]] {
+			let total: f64 = alphas.iter().sum();
+			let mut sums = [0.0; 4];
+			let mut dst = [0.0; 4];
+			let dst = &mut dst[..alphas.len()];
+			for _ in 0..n {
+				rng.random_dirichlet(alphas, dst);
+				assert!((dst.iter().sum::<f64>() - 1.0).abs() < 1e-12);
+				for (s, &x) in sums.iter_mut().zip(dst.iter()) {
+					assert!((0.0..=1.0).contains(&x));
+					*s += x;
+				}
+			}
+			for (s, &a) in sums.iter().zip(alphas) {
+				let mean = s / n as f64;
+				assert!((mean - a / total).abs() < 0.01, "dirichlet {alphas:?}: mean {mean}");
+			}
+		}
+
+		// every gamma value underflows, so a vertex is picked
+		let mut counts = [0; 2];
+		for _ in 0..1000 {
+			let mut dst = [0.0; 2];
+			rng.random_dirichlet(&[1e-300, 3e-300], &mut dst);
+			assert!(dst == [1.0, 0.0] || dst == [0.0, 1.0]);
+			counts[(dst[1] == 1.0) as usize] += 1;
+		}
+		assert!(counts[0] > 200 && counts[0] < 300, "{counts:?}");
+
+		rng.random_dirichlet(&[], &mut []);
+	}
+
+	#[test]
+	#[should_panic(expected = "`alphas` and `dst` must be the same length")]
+	fn test_dirichlet_length() {
+		crate::XorShift64::new(1).random_dirichlet(&[1.0, 1.0], &mut [0.0; 3]);
+	}
+
+	// `I1(kappa) / I0(kappa)`, the mean resultant length of a von mises
+	// distribution
+	fn von_mises_resultant(kappa: f64) -> f64 {
+		let q = kappa * kappa / 4.0;
+		let mut i0 = 0.0;
+		let mut i1 = 0.0;
+		let mut term = 1.0;
+		for m in 0..200 {
+			i0 += term;
+			i1 += term * kappa / 2.0 / (m + 1) as f64;
+			term *= q / ((m + 1) * (m + 1)) as f64;
+		}
+		i1 / i0
+	}
+
+	#[test]
+	fn test_von_mises() {
+		use crate::math::{atan2, sin_cos, sqrt};
+		use core::f64::consts::PI;
+
+		let mut rng = crate::XorShift64::new(1);
+		let n = 100000;
+
+		for kappa in [0.0, 1e-6, 0.5, 2.0, 10.0, 50.0] {
+			for mu in [0.0, 3.0, -2.0, 10.0] {
+				let mut sum_sin = 0.0;
+				let mut sum_cos = 0.0;
+				for _ in 0..n {
+					let x = rng.random_von_mises(mu, kappa);
+					assert!(-PI < x && x <= PI, "von mises {mu} {kappa}: {x}");
+					let (sin, cos) = sin_cos(x);
+					sum_sin += sin;
+					sum_cos += cos;
+				}
+
+				let resultant = sqrt(sum_sin * sum_sin + sum_cos * sum_cos) / n as f64;
+				let expect = von_mises_resultant(kappa);
+				assert!((resultant - expect).abs() < 0.01, "von mises {mu} {kappa}: resultant {resultant}, not {expect}");
+
+				if kappa >= 0.5 {
+					let (sin, cos) = sin_cos(atan2(sum_sin, sum_cos) - mu);
+					let error = atan2(sin, cos);
+					assert!(error.abs() < 0.02, "von mises {mu} {kappa}: mean off by {error}");
+				}
+			}
+		}
+
+		// either side of the wrapped normal fallback. `1 - cos(d)` is about
+		// `d^2 / 2`, and the mean of that is about `1 / (2 * kappa)`
+		for kappa in [1e5, 1e6, 2e6, 1e12] {
+			let mu = PI - 1e-4;
+			let mut sum_sq = 0.0;
+			for _ in 0..n {
+				let x = rng.random_von_mises(mu, kappa);
+				assert!(-PI < x && x <= PI, "von mises {mu} {kappa}: {x}");
+				let d = x - mu;
+				let d = if d < -PI { d + 2.0 * PI } else { d };
+				sum_sq += d * d;
+			}
+			let variance = sum_sq / n as f64 * kappa;
+			assert!((variance - 1.0).abs() < 0.02, "von mises {kappa}: variance {variance} / kappa");
+		}
+	}
+
+	#[test]
+	#[should_panic(expected = "`kappa` must not be negative")]
+	fn test_von_mises_kappa() {
+		crate::XorShift64::new(1).random_von_mises(0.0, -1.0);
+	}
+
+	fn in_stratum(x: f64, i: usize, n: usize) -> bool {
+		i as f64 / n as f64 <= x && x < (i + 1) as f64 / n as f64
+	}
+
+	#[test]
+	fn test_stratified() {
+		let mut rng = crate::XorShift64::new(1);
+
+		for n in [1, 2, 7, 100, 1000] {
+			let mut dst = [0.0; 1000];
+			rng.stratified_1d(&mut dst[..n]);
+			for (i, &x) in dst[..n].iter().enumerate() {
+				assert!(in_stratum(x, i, n), "{n}: {i} {x}");
+			}
+		}
+
+		for (nx, ny) in [(1, 1), (4, 3), (1, 10), (32, 32)] {
+			let mut dst = [(0.0, 0.0); 1024];
+			let dst = &mut dst[..nx * ny];
+			rng.stratified_2d(dst, nx, ny);
+			for (i, &(x, y)) in dst.iter().enumerate() {
+				assert!(in_stratum(x, i % nx, nx) && in_stratum(y, i / nx, ny), "{nx} {ny}: {i} {x} {y}");
+			}
+		}
+
+		for n in [0, 1, 5, 500] {
+			let mut dst = [(0.0, 0.0); 500];
+			let dst = &mut dst[..n];
+			rng.latin_hypercube_2d(dst);
+			let mut rows = [0; 500];
+			for (i, &(x, y)) in dst.iter().enumerate() {
+				assert!(in_stratum(x, i, n), "{n}: {i} {x}");
+				rows[(y * n as f64) as usize] += 1;
+			}
+			assert!(rows[..n].iter().all(|&x| x == 1), "{n}: {rows:?}");
+		}
+
+		// the `y`s are actually shuffled
+		let mut dst = [(0.0, 0.0); 100];
+		rng.latin_hypercube_2d(&mut dst);
+		assert!(dst.windows(2).any(|x| x[0].1 > x[1].1));
+
+		// the largest `u` stays inside its stratum
+		let mut rng = crate::StaticU64::new(|| u64::MAX);
+		let mut dst = [0.0; 3];
+		rng.stratified_1d(&mut dst);
+		assert!(dst.iter().enumerate().all(|(i, &x)| in_stratum(x, i, 3)), "{dst:?}");
+	}
+
+	#[test]
+	fn test_stratified_deterministic() {
+		let mut a = [(0.0, 0.0); 12];
+		let mut b = [(0.0, 0.0); 12];
+		crate::XorShift64::new(1).stratified_2d(&mut a, 3, 4);
+		crate::XorShift64::new(1).stratified_2d(&mut b, 3, 4);
+		assert_eq!(a, b);
+
+		crate::XorShift64::new(1).latin_hypercube_2d(&mut a);
+		crate::XorShift64::new(1).latin_hypercube_2d(&mut b);
+		assert_eq!(a, b);
+	}
+
+	#[test]
+	#[should_panic(expected = "`dst.len()` must be `nx * ny`")]
+	fn test_stratified_len() {
+		crate::XorShift64::new(1).stratified_2d(&mut [(0.0, 0.0); 11], 3, 4);
+	}
+
+	fn hypergeometric_pmf(total: u64, k: u64, n: u64, x: u64) -> f64 {
+		use crate::math::{exp, ln_factorial};
+		let ln_choose = |a: u64, b: u64| ln_factorial(a) - ln_factorial(b) - ln_factorial(a - b);
+		exp(ln_choose(k, x) + ln_choose(total - k, n - x) - ln_choose(total, n))
+	}
+
+	#[test]
+	fn test_hypergeometric() {
+		let mut rng = crate::XorShift64::new(1);
+
+		// inversion, ratio of uniforms, and every reduction of either
+		for (total, k, n) in [
+			(52, 4, 5), (52, 48, 5), (52, 4, 47), (20, 10, 10), (1, 1, 1),
+			(200, 50, 80), (200, 150, 80), (200, 50, 120), (200, 150, 120), (1000, 500, 500u64),
+		] {
+			let low = n.saturating_sub(total - k);
+			let high = n.min(k);
+
+			let samples = 100000;
+			let mut counts = [0u32; 1001];
+			for _ in 0..samples {
+				let x = rng.random_hypergeometric(total, k, n);
+				assert!((low..=high).contains(&x), "{total} {k} {n}: {x}");
+				counts[x as usize] += 1;
+			}
+
+			for x in low..=high {
+				let expect = hypergeometric_pmf(total, k, n, x);
+				let seen = counts[x as usize] as f64 / samples as f64;
+				assert!((seen - expect).abs() < 0.005, "{total} {k} {n}: p({x}) = {seen}, not {expect}");
+			}
+		}
+
+		// brute force urn simulation
+		let mut urn_rng = crate::XorShift64::new(2);
+		let mut urn = [0u32; 6];
+		let mut sampled = [0u32; 6];
+		for _ in 0..100000 {
+			// items `0..5` are successes
+			let mut items = [0; 12];
+			urn_rng.random_permutation(&mut items);
+			urn[items[..7].iter().filter(|&&x| x < 5).count()] += 1;
+			sampled[rng.random_hypergeometric(12, 5, 7) as usize] += 1;
+		}
+		for (a, b) in urn.iter().zip(sampled) {
+			assert!((*a as f64 - b as f64).abs() < 1000.0, "{urn:?} {sampled:?}");
+		}
+
+		// huge parameters don't loop `draws` times
+		let (total, k, n) = (1_000_000_000_000, 400_000_000_000, 100_000_000);
+		let mut sum = 0;
+		for _ in 0..1000 {
+			sum += rng.random_hypergeometric(total, k, n);
+		}
+		let mean = sum as f64 / 1000.0;
+		assert!((mean - 40_000_000.0).abs() < 1000.0, "{mean}");
+
+		assert_eq!(rng.random_hypergeometric(0, 0, 0), 0);
+		assert_eq!(rng.random_hypergeometric(10, 10, 3), 3);
+		assert_eq!(rng.random_hypergeometric(10, 3, 10), 3);
+	}
+
+	#[test]
+	#[should_panic(expected = "`draws` must not be more than `total`")]
+	fn test_hypergeometric_draws() {
+		crate::XorShift64::new(1).random_hypergeometric(10, 5, 11);
+	}
+
+	#[test]
+	fn test_range_end() {
+		// `random_f64()` returns `1.0 - f64::EPSILON`
+		let mut rng = crate::StaticU64::new(|| u64::MAX);
+		assert_eq!(rng.random_f64(), 1.0 - f64::EPSILON);
+
+		for range in [
+			0.0..1e16 + 2.0,
+			1e15..1e15 + 0.25,
+			-1.0..-1.0 + f64::EPSILON,
+			-1e300..1e300,
+			0.0..f64::MIN_POSITIVE,
+			1.0..1.0f64.next_up(),
+		] {
+			let x = rng.random_range(range.clone());
+			assert!(x < range.end);
+			assert!(x >= range.start);
+		}
+	}
+
+	#[test]
+	fn test_range_huge() {
+		// the span overflows to `inf`, but the values don't
+		let mut rng = crate::XorShift64::new(1);
+		for range in [
+			-f64::MAX..f64::MAX,
+			-f64::MAX..0.0,
+			-1.0..f64::MAX,
+			-f64::MAX..-1e300,
+		] {
+			let (mut min, mut max) = (f64::MAX, -f64::MAX);
+			for _ in 0..256 {
+				let x = rng.random_range(range.clone());
+				assert!(x.is_finite(), "{x}");
+				assert!(x >= range.start && x < range.end, "{x}");
+				min = min.min(x);
+				max = max.max(x);
+			}
+			// and spread over most of the range, halved to not overflow
+			assert!(max / 2.0 - min / 2.0 > (range.end / 2.0 - range.start / 2.0) * 0.9);
+		}
+
+		// including at either end of `random_f64()`
+		for x in [0, u64::MAX] {
+			let x = crate::StaticU64::new(|| x).random_range(-f64::MAX..f64::MAX);
+			assert!(x.is_finite() && x < f64::MAX, "{x}");
+		}
+
+		assert_eq!(rng.try_random_range(f64::NEG_INFINITY..0.0), None);
+		assert_eq!(rng.try_random_range(0.0..f64::INFINITY), None);
+	}
+
+	#[test]
+	#[should_panic(expected = "`range` must be finite")]
+	fn test_range_infinite() {
+		crate::XorShift64::new(1).random_range(0.0..f64::INFINITY);
+	}
+
+	#[test]
+	#[should_panic(expected = "`range` must be finite")]
+	fn test_range_infinite_both() {
+		crate::XorShift64::new(1).random_range(f64::NEG_INFINITY..f64::INFINITY);
+	}
+
+	#[test]
+	#[should_panic(expected = "`range` must not be inverted")]
+	fn test_range_inverted() {
+		crate::XorShift64::new(1).random_range(5.0..1.0);
+	}
+
+	#[test]
+	#[should_panic(expected = "`range` must not contain NaN")]
+	fn test_range_nan() {
+		crate::XorShift64::new(1).random_range(f64::NAN..1.0);
+	}
+
+	#[test]
+	fn test_iter() {
+		let mut rng = crate::Static::new(|| 0.0);
+
+		for i in rng.random_iter::<f64>().take(4) {
+			assert_eq!(i, 0.0);
+		}
+	}
+
+	#[test]
+	fn test_option() {
+		let mut rng = crate::XorShift64::new(1);
+		let mut some = 0;
+		for _ in 0..10000 {
+			if rng.random::<Option<u32>>().is_some() {
+				some += 1;
+			}
+		}
+		assert!((4800..5200).contains(&some), "{some}");
+
+		let mut a = crate::XorShift64::new(2);
+		let mut b = crate::XorShift64::new(2);
+		for _ in 0..64 {
+			assert_eq!(a.random::<Option<u64>>(), b.random::<Option<u64>>());
+		}
+
+		// `None` only consumes the `bool`
+		let mut rng = crate::Counting::new(crate::XorShift64::new(1));
+		for _ in 0..64 {
+			let before = rng.u32_calls() + rng.u64_calls();
+			let x = rng.random::<Option<u64>>();
+			let after = rng.u32_calls() + rng.u64_calls();
+			assert_eq!(after - before, if x.is_some() { 2 } else { 1 });
+		}
 	}
-}
 
-impl FromRandom for u64 {
-	fn from_random(random: &mut impl Random) -> Self {
-		random.random_u64()
+	#[test]
+	fn test_ordering() {
+		use core::cmp::Ordering;
+
+		let mut rng = crate::XorShift64::new(1);
+		let mut counts = [0; 3];
+		for _ in 0..3000 {
+			let [a, b]: [Ordering; 2] = rng.random();
+			let (c, _): (Ordering, u8) = rng.random();
+			for x in [a, b, c] {
+				counts[(x as i8 + 1) as usize] += 1;
+			}
+		}
+
+		for count in counts {
+			assert!((2800..3200).contains(&count), "{counts:?}");
+		}
 	}
-}
 
-impl FromRandom for i64 {
-	fn from_random(random: &mut impl Random) -> Self {
-		random.random_u64().cast_signed()
+	#[test]
+	fn test_roll() {
+		let mut rng = crate::XorShift64::new(1);
+
+		for _ in 0..1000 {
+			assert!((3..=18).contains(&rng.roll(3, 6)));
+		}
+		assert_eq!(rng.roll(0, 6), 0);
+		assert_eq!(rng.roll(5, 1), 5);
+
+		let n = 100000;
+		let sum = (0..n).map(|_| rng.roll(3, 6)).sum::<u64>();
+		let mean = sum as f64 / n as f64;
+		assert!((mean - 10.5).abs() < 0.05, "{mean}");
 	}
-}
 
-impl FromRandom for u32 {
-	fn from_random(random: &mut impl Random) -> Self {
-		random.random_u32()
+	#[test]
+	fn test_roll_exploding() {
+		let mut rng = crate::XorShift64::new(1);
+
+		// every roll explodes, until the cap
+		assert_eq!(rng.roll_exploding(3, 1), 303);
+
+		for _ in 0..1000 {
+			let x = rng.roll_exploding(2, 6);
+			assert!(x >= 2);
+		}
+		let n = 100000;
+		let sum = (0..n).map(|_| rng.roll_exploding(1, 6)).sum::<u64>();
+		let mean = sum as f64 / n as f64;
+		// 3.5 / (1 - 1/6)
+		assert!((mean - 4.2).abs() < 0.05, "{mean}");
 	}
-}
 
-impl FromRandom for i32 {
-	fn from_random(random: &mut impl Random) -> Self {
-		random.random_u32().cast_signed()
+	#[test]
+	fn test_roll_keep_highest() {
+		let mut rng = crate::XorShift64::new(1);
+
+		for count in 0..10 {
+			for keep in 0..=count {
+				for _ in 0..50 {
+					let mut copy = rng;
+					let x = rng.roll_keep_highest(count, 6, keep);
+
+					let mut dice = [0; 10];
+					for i in &mut dice[..count as usize] {
+						*i = copy.random_u32_bound(6) as u64 + 1;
+					}
+					let dice = &mut dice[..count as usize];
+					dice.sort_unstable();
+					let expect = dice[(count - keep) as usize..].iter().sum::<u64>();
+					assert_eq!(x, expect, "{count} {keep}");
+				}
+			}
+		}
+
+		// only the dropped dice are buffered
+		let x = rng.roll_keep_highest(200, 6, 199);
+		assert!((199..=1194).contains(&x));
 	}
-}
 
-impl FromRandom for u16 {
-	fn from_random(random: &mut impl Random) -> Self {
-		random.random_u16()
+	#[test]
+	#[should_panic = "`keep` must not be more than `count`"]
+	fn test_roll_keep_more() {
+		crate::XorShift64::new(1).roll_keep_highest(2, 6, 3);
 	}
-}
 
-impl FromRandom for i16 {
-	fn from_random(random: &mut impl Random) -> Self {
-		random.random_u16().cast_signed()
+	#[test]
+	#[should_panic = "`sides` must not be 0"]
+	fn test_roll_zero() {
+		crate::XorShift64::new(1).roll(2, 0);
 	}
-}
 
-impl FromRandom for u8 {
-	fn from_random(random: &mut impl Random) -> Self {
-		random.random_u8()
+	fn is_permutation(x: &[usize]) -> bool {
+		let mut seen = [false; 64];
+		x.iter().all(|&i| i < x.len() && !core::mem::replace(&mut seen[i], true))
 	}
-}
 
-impl FromRandom for i8 {
-	fn from_random(random: &mut impl Random) -> Self {
-		random.random_u8().cast_signed()
+	#[test]
+	fn test_permutation() {
+		let mut rng = crate::XorShift64::new(1);
+
+		rng.random_permutation(&mut []);
+		let mut one = [5];
+		rng.random_permutation(&mut one);
+		assert_eq!(one, [0]);
+
+		for len in 0..64 {
+			let mut buf = [0; 64];
+			rng.random_permutation(&mut buf[..len]);
+			assert!(is_permutation(&buf[..len]));
+		}
+
+		// all 24 permutations of length 4
+		let n = 48000;
+		let mut counts = [0; 256];
+		for _ in 0..n {
+			let mut p = [0; 4];
+			rng.random_permutation(&mut p);
+			counts[p[0] | p[1] << 2 | p[2] << 4 | p[3] << 6] += 1;
+		}
+		let seen = counts.iter().filter(|&&c| c != 0).count();
+		assert_eq!(seen, 24);
+		for count in counts.into_iter().filter(|&c| c != 0) {
+			assert!((1800..2200).contains(&count), "{count}");
+		}
+
+		let mut a = [0; 16];
+		let mut b = [0; 16];
+		crate::XorShift64::new(3).random_permutation(&mut a);
+		crate::XorShift64::new(3).random_permutation(&mut b);
+		assert_eq!(a, b);
 	}
-}
 
-impl FromRandom for bool {
-	fn from_random(random: &mut impl Random) -> Self {
-		random.random_bool()
+	#[test]
+	fn test_derangement() {
+		let mut rng = crate::XorShift64::new(1);
+
+		rng.random_derangement(&mut []);
+		let mut two = [0; 2];
+		rng.random_derangement(&mut two);
+		assert_eq!(two, [1, 0]);
+
+		for len in 2..64 {
+			let mut buf = [0; 64];
+			rng.random_derangement(&mut buf[..len]);
+			assert!(is_permutation(&buf[..len]));
+			assert!(buf[..len].iter().enumerate().all(|(i, &x)| i != x));
+		}
 	}
-}
 
-impl<const N: usize, T: FromRandom> FromRandom for [T; N] {
-	fn from_random(random: &mut impl Random) -> Self {
-		core::array::from_fn(|_| random.random())
+	#[test]
+	#[should_panic = "`dst` must not have a length of 1"]
+	fn test_derangement_one() {
+		crate::XorShift64::new(1).random_derangement(&mut [0]);
 	}
-}
 
-impl FromRandom for () {
-	fn from_random(_: &mut impl Random) -> Self {}
-}
+	#[test]
+	fn test_reservoir() {
+		let mut rng = crate::XorShift64::new(1);
+
+		let mut dst = [0; 8];
+		assert_eq!(rng.sample_reservoir(0..5, &mut dst), 5);
+		assert_eq!(dst, [0, 1, 2, 3, 4, 0, 0, 0]);
+		assert_eq!(rng.sample_reservoir(0..0, &mut dst), 0);
+		assert_eq!(rng.sample_reservoir(10..18, &mut dst), 8);
+		assert_eq!(dst, [10, 11, 12, 13, 14, 15, 16, 17]);
+
+		let mut iter = 0..10;
+		assert_eq!(rng.sample_reservoir(&mut iter, &mut []), 0);
+		assert_eq!(iter.len(), 10);
+
+		// every item of a population of 10 is included with probability 3/10
+		let n = 30000;
+		let mut counts = [0; 10];
+		for _ in 0..n {
+			let mut dst = [0; 3];
+			assert_eq!(rng.sample_reservoir(0..10, &mut dst), 3);
+			assert!(dst[0] != dst[1] && dst[1] != dst[2] && dst[0] != dst[2]);
+			for i in dst {
+				counts[i] += 1;
+			}
+		}
+		for count in counts {
+			assert!((8700..9300).contains(&count), "{counts:?}");
+		}
 
-impl<A: FromRandom> FromRandom for (A,) {
-	fn from_random(random: &mut impl Random) -> Self {
-		(random.random(),)
+		let mut a = [0; 4];
+		let mut b = [0; 4];
+		crate::XorShift64::new(3).sample_reservoir(0..100, &mut a);
+		crate::XorShift64::new(3).sample_reservoir(0..100, &mut b);
+		assert_eq!(a, b);
 	}
-}
 
-impl<
-	A: FromRandom,
-	B: FromRandom,
-	> FromRandom for (A, B) {
-	fn from_random(random: &mut impl Random) -> Self {
-		(
-			random.random(),
-			random.random(),
-		)
-	}
-}
+	#[test]
+	fn test_floyd() {
+		let mut rng = crate::XorShift64::new(1);
+
+		rng.sample_indices_floyd(0, &mut []);
+		rng.sample_indices_floyd(10, &mut []);
+
+		let mut all = [0; 16];
+		rng.sample_indices_floyd(16, &mut all);
+		all.sort_unstable();
+		assert!(all.iter().enumerate().all(|(i, &x)| i as u64 == x));
+
+		for _ in 0..1000 {
+			let mut dst = [0; 8];
+			rng.sample_indices_floyd(20, &mut dst);
+			for (i, x) in dst.iter().enumerate() {
+				assert!(*x < 20);
+				assert!(!dst[..i].contains(x));
+			}
+		}
 
-impl<
-	A: FromRandom,
-	B: FromRandom,
-	C: FromRandom,
-	> FromRandom for (A, B, C) {
-	fn from_random(random: &mut impl Random) -> Self {
-		(
-			random.random(),
-			random.random(),
-			random.random(),
-		)
+		// all 10 combinations of 2 out of 5
+		let n = 20000;
+		let mut counts = [0; 32];
+		for _ in 0..n {
+			let mut dst = [0; 2];
+			rng.sample_indices_floyd(5, &mut dst);
+			counts[(1 << dst[0]) | (1 << dst[1])] += 1;
+		}
+		let combinations = counts.iter().filter(|&&c| c != 0).count();
+		assert_eq!(combinations, 10);
+		for count in counts.into_iter().filter(|&c| c != 0) {
+			assert!((1800..2200).contains(&count), "{counts:?}");
+		}
 	}
-}
 
-impl<
-	A: FromRandom,
-	B: FromRandom,
-	C: FromRandom,
-	D: FromRandom,
-	> FromRandom for (A, B, C, D) {
-	fn from_random(random: &mut impl Random) -> Self {
-		(
-			random.random(),
-			random.random(),
-			random.random(),
-			random.random(),
-		)
+	#[test]
+	#[should_panic = "`dst.len()` must not be more than `n`"]
+	fn test_floyd_too_many() {
+		crate::XorShift64::new(1).sample_indices_floyd(2, &mut [0; 3]);
 	}
-}
 
-impl<
-	A: FromRandom,
-	B: FromRandom,
-	C: FromRandom,
-	D: FromRandom,
-	E: FromRandom,
-	> FromRandom for (A, B, C, D, E) {
-	fn from_random(random: &mut impl Random) -> Self {
-		(
-			random.random(),
-			random.random(),
-			random.random(),
-			random.random(),
-			random.random(),
-		)
+	#[test]
+	fn test_bits() {
+		let mut rng = crate::Counting::new(crate::XorShift64::new(1));
+
+		assert_eq!(rng.random_bits(0), 0);
+		assert_eq!(rng.u32_calls() + rng.u64_calls(), 0);
+
+		for n in 1..=64 {
+			for _ in 0..64 {
+				let x = rng.random_bits(n);
+				assert!(n == 64 || x >> n == 0);
+			}
+		}
+		assert_eq!(rng.u32_calls(), 32 * 64);
+		assert_eq!(rng.u64_calls(), 32 * 64);
+
+		let mut seen = 0;
+		for _ in 0..64 {
+			seen |= rng.random_bits(64);
+		}
+		assert_eq!(seen, u64::MAX);
+		assert!((0..64).map(|_| rng.random_bits(1)).any(|x| x == 1));
+
+		let mut a = crate::XorShift64::new(3);
+		let mut b = crate::XorShift64::new(3);
+		assert_eq!(a.random_bits(32), b.random_u32() as u64);
+		assert_eq!(a.random_bits(17), b.random_bits(17));
+		assert_eq!(a.random_bits(64), b.random_u64());
 	}
-}
 
-impl<
-	A: FromRandom,
-	B: FromRandom,
-	C: FromRandom,
-	D: FromRandom,
-	E: FromRandom,
-	F: FromRandom,
-	> FromRandom for (A, B, C, D, E, F) {
-	fn from_random(random: &mut impl Random) -> Self {
-		(
-			random.random(),
-			random.random(),
-			random.random(),
-			random.random(),
-			random.random(),
-			random.random(),
-		)
+	#[test]
+	#[should_panic = "`n` must not be more than 64"]
+	fn test_bits_too_many() {
+		crate::XorShift64::new(1).random_bits(65);
 	}
-}
 
-impl<
-	A: FromRandom,
-	B: FromRandom,
-	C: FromRandom,
-	D: FromRandom,
-	E: FromRandom,
-	F: FromRandom,
-	G: FromRandom,
-	> FromRandom for (A, B, C, D, E, F, G) {
-	fn from_random(random: &mut impl Random) -> Self {
-		(
-			random.random(),
-			random.random(),
-			random.random(),
-			random.random(),
-			random.random(),
-			random.random(),
-			random.random(),
-		)
+	// counts drops, for checking that initialized values are dropped once
+	static DROPS: core::sync::atomic::AtomicUsize = core::sync::atomic::AtomicUsize::new(0);
+
+	struct Droppy(u64);
+
+	impl Drop for Droppy {
+		fn drop(&mut self) {
+			DROPS.fetch_add(1, core::sync::atomic::Ordering::Relaxed);
+		}
 	}
-}
 
-impl<
-	A: FromRandom,
-	B: FromRandom,
-	C: FromRandom,
-	D: FromRandom,
-	E: FromRandom,
-	F: FromRandom,
-	G: FromRandom,
-	H: FromRandom,
-	> FromRandom for (A, B, C, D, E, F, G, H) {
-	fn from_random(random: &mut impl Random) -> Self {
-		(
-			random.random(),
-			random.random(),
-			random.random(),
-			random.random(),
-			random.random(),
-			random.random(),
-			random.random(),
-			random.random(),
-		)
+	impl crate::FromRandom for Droppy {
+		fn from_random(random: &mut impl Random) -> Self {
+			Self(random.random())
+		}
 	}
-}
 
+	#[test]
+	fn test_uninit() {
+		use core::mem::MaybeUninit;
+		use core::sync::atomic::Ordering;
+
+		let mut a = crate::XorShift64::new(1);
+		let mut b = crate::XorShift64::new(1);
+
+		{
+			let mut buf = [const { MaybeUninit::<Droppy>::uninit() }; 16];
+			let values = a.random_fill_uninit(&mut buf);
+			assert_eq!(values.len(), 16);
+			for x in values.iter() {
+				assert_eq!(x.0, b.random_u64());
+			}
+			assert_eq!(DROPS.load(Ordering::Relaxed), 0);
 
-#[cfg(test)]
-mod test {
-    use crate::Random;
+			unsafe {
+				core::ptr::drop_in_place(values);
+			}
+			assert_eq!(DROPS.load(Ordering::Relaxed), 16);
+		}
+		assert_eq!(DROPS.load(Ordering::Relaxed), 16);
 
-	#[test]
-	fn test_main() {
-		let mut rng = crate::Static::new(|| 0.5);
+		{
+			let values: [Droppy; 32] = a.random_array_uninit();
+			for x in &values {
+				assert_eq!(x.0, b.random_u64());
+			}
+			assert_eq!(DROPS.load(Ordering::Relaxed), 16);
+		}
+		assert_eq!(DROPS.load(Ordering::Relaxed), 48);
 
-		let _x: (i16, u64) = rng.random();
+		let _: [Droppy; 0] = a.random_array_uninit();
+		assert_eq!(DROPS.load(Ordering::Relaxed), 48);
 	}
 
 	#[test]
-	fn test_dyn() {
-		let object: &mut dyn crate::Random = &mut crate::Static::new(|| 0.0);
+	fn test_fill_float() {
+		for len in [0, 1, 15, 16, 17, 100] {
+			let mut a = crate::MTwister::new(1);
+			let mut b = crate::MTwister::new(1);
+
+			let mut x = [0.0f64; 100];
+			let mut y = [0.0f64; 100];
+			a.random_fill_f64(&mut x[..len]);
+			b.random_fill(&mut y[..len]);
+			assert_eq!(x, y);
+
+			let mut x = [0.0f32; 100];
+			let mut y = [0.0f32; 100];
+			a.random_fill_f32(&mut x[..len]);
+			b.random_fill(&mut y[..len]);
+			assert_eq!(x, y);
+
+			assert_eq!(a.get(), b.get());
+		}
 
-		object.random_f64();
-		object.random_f32();
-		object.random_u64();
-		object.random_u32();
+		let mut rng = crate::XorShift64::new(1);
+		let object: &mut dyn Random = &mut rng;
+		let mut x = [0.0; 20];
+		object.random_fill_f64(&mut x);
+		assert!(x.iter().all(|x| (0.0..1.0).contains(x)));
+	}
 
-		let _object: &mut dyn crate::Random = &mut crate::Static::new(|| 0.0).random_iter::<()>();
+	#[test]
+	fn test_try() {
+		use core::num::NonZero;
+
+		// surrogates and values past `char::MAX` fail, and bits above the
+		// low 21 are ignored
+		let mut rng = crate::StaticSeqU64::new(&[0xd800, 0x11_0000, 0x20_0041, 0x10_ffff]);
+		assert_eq!(rng.random_try::<char>(), None);
+		assert_eq!(rng.random_try::<char>(), None);
+		assert_eq!(rng.random_try::<char>(), Some('A'));
+		assert_eq!(rng.random_try::<char>(), Some(char::MAX));
+
+		let mut rng = crate::StaticSeqU64::new(&[0, 5]);
+		assert_eq!(rng.random_try::<NonZero<u32>>(), None);
+		assert_eq!(rng.random_try::<NonZero<u32>>(), NonZero::new(5));
+		assert_eq!(rng.random_try::<NonZero<u64>>(), None);
+		assert_eq!(rng.random_try::<NonZero<i64>>(), NonZero::new(5));
+
+		// truncated to the low bits, like `random()`
+		let mut rng = crate::StaticSeqU64::new(&[0x100, 0xff]);
+		assert_eq!(rng.random_try::<NonZero<u8>>(), None);
+		assert_eq!(rng.random_try::<NonZero<i8>>(), NonZero::new(-1));
+
+		// infallible types never fail, and draw the same as `random()`
+		let mut a = crate::XorShift64::new(1);
+		let mut b = crate::XorShift64::new(1);
+		for _ in 0..16 {
+			assert_eq!(a.random_try::<(u32, [i8; 3])>(), Some(b.random()));
+		}
 	}
 
 	#[test]
-	fn test_iter() {
-		let mut rng = crate::Static::new(|| 0.0);
+	fn test_retry() {
+		use core::num::NonZero;
+
+		let mut rng = crate::Counting::new(crate::StaticSeqU64::new(&[0xd800, 0xdfff, 0x41, 0, 0, 0, 9]));
+		assert_eq!(rng.random_retry::<char>(2), None);
+		assert_eq!(rng.u32_calls(), 2);
+		assert_eq!(rng.random_retry::<char>(2), Some('A'));
+		assert_eq!(rng.u32_calls(), 3);
+
+		// no attempts, no draws
+		assert_eq!(rng.random_retry::<NonZero<u64>>(0), None);
+		assert_eq!(rng.u64_calls(), 0);
+		assert_eq!(rng.random_retry::<NonZero<u64>>(4), NonZero::new(9));
+		assert_eq!(rng.u64_calls(), 4);
+
+		// a little over half of all `char` attempts succeed
+		let mut rng = crate::XorShift64::new(1);
+		let hits = (0..100000).filter(|_| rng.random_try::<char>().is_some()).count();
+		let expect = (char::MAX as u32 + 1 - 0x800) as f64 / (1 << 21) as f64;
+		assert!((hits as f64 / 100000.0 - expect).abs() < 0.01, "{hits}");
+
+		for _ in 0..100 {
+			assert!(rng.random_retry::<char>(64).is_some());
+		}
+	}
 
-		for i in rng.random_iter::<f64>().take(4) {
-			assert_eq!(i, 0.0);
+	#[test]
+	fn test_bounded() {
+		use core::time::Duration;
+
+		let mut a = crate::XorShift64::new(1);
+		let mut b = crate::XorShift64::new(1);
+
+		for bound in [1, 2, 3, 6, 100, 255] {
+			assert_eq!(a.random_bounded::<u8>(&bound), b.random_u8_bound(bound));
+		}
+		for bound in [1, 7, 1000, u16::MAX] {
+			assert_eq!(a.random_bounded::<u16>(&bound), b.random_u16_bound(bound));
+		}
+		for bound in [1, 7, 1 << 31, u32::MAX] {
+			assert_eq!(a.random_bounded::<u32>(&bound), b.random_u32_bound(bound));
+		}
+		for bound in [1, 7, 1 << 63, u64::MAX] {
+			assert_eq!(a.random_bounded::<u64>(&bound), b.random_u64_bound(bound));
+			assert_eq!(a.random_bounded::<usize>(&(bound as usize)), b.random_u64_bound(bound) as usize);
+		}
+		for bound in [1, 7, 1 << 100, u128::MAX] {
+			assert_eq!(a.random_bounded::<u128>(&bound), b.random_u128_bound(bound));
+		}
+		for range in [0.0..1.0, -5.0..5.0, 3.0..3.0, 1e300..f64::MAX] {
+			assert_eq!(a.random_bounded::<f64>(&range), b.random_range(range));
 		}
+
+		for bound in [Duration::from_nanos(1), Duration::from_millis(250), Duration::new(7, 5)] {
+			let x = a.random_bounded::<Duration>(&bound);
+			assert_eq!(x.as_nanos(), b.random_u64_bound(bound.as_nanos() as u64) as u128);
+			assert!(x < bound);
+		}
+
+		// too long for nanoseconds in a `u64`
+		for _ in 0..100 {
+			let x = a.random_bounded::<Duration>(&Duration::MAX);
+			assert_eq!(x.as_nanos(), b.random_u128_bound(Duration::MAX.as_nanos()));
+		}
+		let bound = Duration::from_secs(1 << 40);
+		assert!((0..100).all(|_| a.random_bounded::<Duration>(&bound) < bound));
 	}
-}
 
+	#[test]
+	#[should_panic = "`bound` must not be 0"]
+	fn test_bounded_zero() {
+		crate::XorShift64::new(1).random_bounded::<core::time::Duration>(&core::time::Duration::ZERO);
+	}
+}
@@ -0,0 +1,611 @@
+//! simple statistical tests, for catching catastrophic generator misuse.
+//! 
+//! these are smoke tests, not a replacement for a real test suite like
+//! [TestU01](https://simul.iro.umontreal.ca/testu01/tu01.html) or
+//! [PractRand](https://pracrand.sourceforge.net/). they are good at noticing
+//! that something is very wrong (for example, a generator seeded with `0`
+//! that only outputs `0`), and not much else.
+//! 
+//! each test returns a p-value in `[0, 1]`: the probability of a truly
+//! random source producing a result at least this extreme. very small
+//! values (below `0.0001`, say) suggest the data is not random. note that
+//! a truly random source will still occasionally produce small values.
+//! 
+//! this module requires the `stats` feature.
+//! 
+//! ```
+//! # use prrng::XorShift64;
+//! use prrng::stats::quick_check;
+//! 
+//! let mut rng = XorShift64::new(1);
+//! let report = quick_check(&mut rng, 1 << 16);
+//! assert!(report.passed(0.0001));
+//! ```
+
+use crate::math::{exp, ln, sqrt};
+
+/// the results of [`quick_check()`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StatsReport {
+	/// see [`monobit()`].
+	pub monobit: f64,
+	/// see [`chi_square_bytes()`].
+	pub chi_square: f64,
+	/// see [`runs_test()`].
+	pub runs: f64,
+}
+
+impl StatsReport {
+	/// whether every p-value is at least `alpha`.
+	pub fn passed(&self, alpha: f64) -> bool {
+		self.monobit >= alpha && self.chi_square >= alpha && self.runs >= alpha
+	}
+}
+
+/// the frequency (monobit) test: whether there are about as many `true`s as
+/// `false`s. returns a p-value.
+/// 
+/// ```
+/// use prrng::stats::monobit;
+/// 
+/// assert!(monobit([true, false].into_iter().cycle().take(1000)) > 0.5);
+/// assert!(monobit(core::iter::repeat_n(true, 1000)) < 0.0001);
+/// ```
+pub fn monobit(bits: impl Iterator<Item = bool>) -> f64 {
+	let mut state = Monobit::new();
+	for bit in bits {
+		state.push(bit);
+	}
+	state.p_value()
+}
+
+/// pearson's chi-square test over the frequency of each byte value.
+/// returns a p-value.
+/// 
+/// this needs at least a few thousand bytes to be meaningful.
+/// 
+/// ```
+/// use prrng::stats::chi_square_bytes;
+/// 
+/// let mut data = [0u8; 4096];
+/// assert!(chi_square_bytes(&data) < 0.0001);
+/// 
+/// for (i, x) in data.iter_mut().enumerate() {
+///     *x = i as u8;
+/// }
+/// // too uniform, actually
+/// assert!(chi_square_bytes(&data) > 0.9999);
+/// ```
+pub fn chi_square_bytes(data: &[u8]) -> f64 {
+	let mut state = ChiSquare::new();
+	for &byte in data {
+		state.push(byte);
+	}
+	state.p_value()
+}
+
+/// the runs test: whether the number of uninterrupted runs of identical bits
+/// is as expected. returns a p-value.
+/// 
+/// this follows NIST SP 800-22, and returns `0.0` if the bits fail the
+/// monobit precondition.
+/// 
+/// ```
+/// use prrng::stats::runs_test;
+/// 
+/// // alternating bits have far too many runs
+/// assert!(runs_test([true, false].into_iter().cycle().take(1000)) < 0.0001);
+/// ```
+pub fn runs_test(bits: impl Iterator<Item = bool>) -> f64 {
+	let mut state = Runs::new();
+	for bit in bits {
+		state.push(bit);
+	}
+	state.p_value()
+}
+
+/// draw `samples` bytes from `rng` with [`crate::RandomImpl::random_bytes()`],
+/// and run every test in this module on them.
+/// 
+/// this does not allocate.
+pub fn quick_check(rng: &mut impl crate::Random, samples: usize) -> StatsReport {
+	let mut monobit = Monobit::new();
+	let mut chi_square = ChiSquare::new();
+	let mut runs = Runs::new();
+
+	let mut buf = [0u8; 64];
+	let mut remaining = samples;
+
+	while remaining > 0 {
+		let len = remaining.min(buf.len());
+		let buf = &mut buf[..len];
+		rng.random_bytes(buf);
+
+		for &byte in buf.iter() {
+			chi_square.push(byte);
+			for i in 0..8 {
+				let bit = (byte >> i) & 1 != 0;
+				monobit.push(bit);
+				runs.push(bit);
+			}
+		}
+
+		remaining -= len;
+	}
+
+	StatsReport {
+		monobit: monobit.p_value(),
+		chi_square: chi_square.p_value(),
+		runs: runs.p_value(),
+	}
+}
+
+/// estimate the shannon entropy of `data`, in bits per byte, from the
+/// frequency of each byte value. this is between `0.0` and `8.0`.
+/// 
+/// this only looks at how often each byte occurs, not at their order, so
+/// it is an upper bound at best. small samples will underestimate.
+/// 
+/// ```
+/// use prrng::stats::shannon_entropy_bytes;
+/// 
+/// assert_eq!(shannon_entropy_bytes(&[7; 100]), 0.0);
+/// assert_eq!(shannon_entropy_bytes(&[0, 1, 2, 3]), 2.0);
+/// ```
+pub fn shannon_entropy_bytes(data: &[u8]) -> f64 {
+	let mut state = EntropyEstimator::new();
+	for &byte in data {
+		state.update(byte);
+	}
+	state.bits_per_byte()
+}
+
+/// draw `n` bytes from `rng` with [`crate::RandomImpl::random_bytes()`], and
+/// estimate their entropy. see [`shannon_entropy_bytes()`].
+/// 
+/// this does not allocate.
+pub fn estimate(rng: &mut impl crate::Random, n: usize) -> f64 {
+	let mut state = EntropyEstimator::new();
+
+	let mut buf = [0u8; 64];
+	let mut remaining = n;
+
+	while remaining > 0 {
+		let len = remaining.min(buf.len());
+		let buf = &mut buf[..len];
+		rng.random_bytes(buf);
+
+		for &byte in buf.iter() {
+			state.update(byte);
+		}
+
+		remaining -= len;
+	}
+
+	state.bits_per_byte()
+}
+
+/// a streaming version of [`shannon_entropy_bytes()`].
+/// 
+/// ```
+/// use prrng::stats::EntropyEstimator;
+/// 
+/// let mut estimator = EntropyEstimator::new();
+/// 
+/// for i in 0..=255 {
+///     estimator.update(i);
+/// }
+/// 
+/// assert!((estimator.bits_per_byte() - 8.0).abs() < 1e-9);
+/// ```
+#[derive(Clone)]
+pub struct EntropyEstimator {
+	counts: [u64; 256],
+	n: u64,
+}
+
+impl EntropyEstimator {
+	/// construct a new, empty `EntropyEstimator`.
+	pub const fn new() -> Self {
+		Self {
+			counts: [0; 256],
+			n: 0,
+		}
+	}
+
+	/// record a byte.
+	#[inline]
+	pub fn update(&mut self, byte: u8) {
+		self.counts[byte as usize] += 1;
+		self.n += 1;
+	}
+
+	/// the number of bytes recorded.
+	#[inline]
+	pub fn len(&self) -> u64 {
+		self.n
+	}
+
+	/// whether no bytes have been recorded.
+	#[inline]
+	pub fn is_empty(&self) -> bool {
+		self.n == 0
+	}
+
+	/// the current estimate, in bits per byte. this is `0.0` if nothing has
+	/// been recorded.
+	pub fn bits_per_byte(&self) -> f64 {
+		let n = self.n as f64;
+		let mut sum = 0.0;
+		for &count in &self.counts {
+			if count != 0 {
+				let p = count as f64 / n;
+				sum -= p * ln(p);
+			}
+		}
+		// `-0.0`, when there is only one byte value
+		(sum / core::f64::consts::LN_2).abs()
+	}
+}
+
+impl Default for EntropyEstimator {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl core::fmt::Debug for EntropyEstimator {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		write!(f, "EntropyEstimator({})", self.n)
+	}
+}
+
+/// find the period of `rng`, as observed through `step`, using
+/// [brent's algorithm](https://en.wikipedia.org/wiki/Cycle_detection#Brent's_algorithm).
+/// returns `None` if no cycle was found before the hare advanced `max_steps`
+/// times.
+/// 
+/// `max_steps` only limits how far the hare advances. every position is
+/// also compared against the tortoise, by stepping clones of both up to 64
+/// times, so `step` may be called up to around `129 * max_steps` times in
+/// total.
+/// 
+/// `step` should advance `rng` and return the value to observe. this works
+/// best when the value is the entire state of the generator (such as
+/// [`crate::lcg::Lcg8::seed()`]), but any output will do: two positions
+/// are considered equal when the next 64 values from each match, so a
+/// sequence that merely repeats itself for 64 values can be misreported.
+/// 
+/// this does not allocate, but clones `rng` often.
+/// 
+/// ```
+/// # use prrng::FibLFSR16;
+/// use prrng::stats::find_period;
+/// 
+/// let period = find_period(FibLFSR16::new(1), 1 << 20, |rng| rng.get() as u64);
+/// assert_eq!(period, Some(65535));
+/// 
+/// let period = find_period(FibLFSR16::new(1), 1000, |rng| rng.get() as u64);
+/// assert_eq!(period, None);
+/// ```
+pub fn find_period<R: Clone>(rng: R, max_steps: u64, mut step: impl FnMut(&mut R) -> u64) -> Option<u64> {
+	let mut tortoise = rng.clone();
+	let mut hare = rng;
+	step(&mut hare);
+
+	let mut steps = 1;
+	let mut power = 1;
+	let mut lambda = 1;
+
+	while !same_window(&tortoise, &hare, &mut step) {
+		if steps >= max_steps {
+			return None;
+		}
+
+		if power == lambda {
+			tortoise = hare.clone();
+			power *= 2;
+			lambda = 0;
+		}
+
+		step(&mut hare);
+		steps += 1;
+		lambda += 1;
+	}
+
+	Some(lambda)
+}
+
+fn same_window<R: Clone>(a: &R, b: &R, step: &mut impl FnMut(&mut R) -> u64) -> bool {
+	let mut a = a.clone();
+	let mut b = b.clone();
+	(0..64).all(|_| step(&mut a) == step(&mut b))
+}
+
+
+struct Monobit {
+	n: u64,
+	ones: u64,
+}
+
+impl Monobit {
+	fn new() -> Self {
+		Self {
+			n: 0,
+			ones: 0,
+		}
+	}
+
+	fn push(&mut self, bit: bool) {
+		self.n += 1;
+		self.ones += bit as u64;
+	}
+
+	fn p_value(&self) -> f64 {
+		if self.n == 0 {
+			return 1.0;
+		}
+		let s = (2 * self.ones) as f64 - self.n as f64;
+		erfc(s.abs() / sqrt(2.0 * self.n as f64)).min(1.0)
+	}
+}
+
+struct ChiSquare {
+	counts: [u64; 256],
+	n: u64,
+}
+
+impl ChiSquare {
+	fn new() -> Self {
+		Self {
+			counts: [0; 256],
+			n: 0,
+		}
+	}
+
+	fn push(&mut self, byte: u8) {
+		self.counts[byte as usize] += 1;
+		self.n += 1;
+	}
+
+	fn p_value(&self) -> f64 {
+		if self.n == 0 {
+			return 1.0;
+		}
+		let expected = self.n as f64 / 256.0;
+		let mut x = 0.0;
+		for &count in &self.counts {
+			let d = count as f64 - expected;
+			x += d * d / expected;
+		}
+		chi_square_sf(x, 255.0)
+	}
+}
+
+struct Runs {
+	n: u64,
+	ones: u64,
+	runs: u64,
+	last: Option<bool>,
+}
+
+impl Runs {
+	fn new() -> Self {
+		Self {
+			n: 0,
+			ones: 0,
+			runs: 0,
+			last: None,
+		}
+	}
+
+	fn push(&mut self, bit: bool) {
+		self.n += 1;
+		self.ones += bit as u64;
+		if self.last != Some(bit) {
+			self.runs += 1;
+		}
+		self.last = Some(bit);
+	}
+
+	fn p_value(&self) -> f64 {
+		if self.n == 0 {
+			return 1.0;
+		}
+		let n = self.n as f64;
+		let pi = self.ones as f64 / n;
+		if (pi - 0.5).abs() >= 2.0 / sqrt(n) {
+			return 0.0;
+		}
+		let v = self.runs as f64;
+		let q = pi * (1.0 - pi);
+		erfc((v - 2.0 * n * q).abs() / (2.0 * sqrt(2.0 * n) * q)).min(1.0)
+	}
+}
+
+
+// no_std has no float math, so these are approximated here.
+// see also `crate::math`.
+
+fn cbrt(x: f64) -> f64 {
+	if x == 0.0 {
+		0.0
+	} else if x < 0.0 {
+		-exp(ln(-x) / 3.0)
+	} else {
+		exp(ln(x) / 3.0)
+	}
+}
+
+// complementary error function, with a fractional error below 1.2e-7.
+// numerical recipes, `erfcc`
+fn erfc(x: f64) -> f64 {
+	let z = x.abs();
+	let t = 1.0 / (1.0 + 0.5 * z);
+	let poly = -1.26551223 + t * (1.00002368 + t * (0.37409196 + t * (0.09678418
+		+ t * (-0.18628806 + t * (0.27886807 + t * (-1.13520398 + t * (1.48851587
+		+ t * (-0.82215223 + t * 0.17087277))))))));
+	let ret = t * exp(-z * z + poly);
+	if x >= 0.0 {
+		ret
+	} else {
+		2.0 - ret
+	}
+}
+
+// survival function of the chi-square distribution with `k` degrees of
+// freedom, using the wilson-hilferty approximation. this is accurate
+// enough for large `k`.
+fn chi_square_sf(x: f64, k: f64) -> f64 {
+	if x <= 0.0 {
+		return 1.0;
+	}
+	let v = 2.0 / (9.0 * k);
+	let z = (cbrt(x / k) - (1.0 - v)) / sqrt(v);
+	(0.5 * erfc(z / core::f64::consts::SQRT_2)).clamp(0.0, 1.0)
+}
+
+
+#[cfg(test)]
+mod test {
+	use crate::stats::{chi_square_sf, erfc, estimate, find_period, monobit, quick_check, runs_test, shannon_entropy_bytes, EntropyEstimator};
+	use crate::Random;
+
+	fn close(a: f64, b: f64, eps: f64) -> bool {
+		(a - b).abs() <= eps * b.abs().max(1.0)
+	}
+
+	#[test]
+	fn test_math() {
+		assert!(close(erfc(0.0), 1.0, 1e-7));
+		assert!(close(erfc(1.0), 0.157299207050285, 1e-6));
+		assert!(close(erfc(-1.0), 1.842700792949715, 1e-6));
+
+		// mean of the chi-square distribution is about the median
+		assert!(close(chi_square_sf(255.0, 255.0), 0.5, 0.02));
+		assert!(chi_square_sf(400.0, 255.0) < 0.0001);
+	}
+
+	#[test]
+	fn test_bad() {
+		let mut rng = crate::StaticU64::new(|| 0);
+		let report = quick_check(&mut rng, 4096);
+		assert!(!report.passed(0.0001));
+		assert_eq!(report.runs, 0.0);
+
+		let mut rng = crate::StaticU64::new(|| 0x5555_5555_5555_5555);
+		let report = quick_check(&mut rng, 4096);
+		assert!(report.monobit > 0.5);
+		assert!(!report.passed(0.0001));
+	}
+
+	fn check(name: &str, mut rng: impl Random) {
+		let report = quick_check(&mut rng, 1 << 16);
+		assert!(report.passed(0.00001), "{name}: {report:?}");
+	}
+
+	fn check_bool(name: &str, mut rng: impl Random) {
+		let mut a = [false; 1 << 14];
+		for i in &mut a {
+			*i = rng.random_bool();
+		}
+		let p = monobit(a.iter().copied());
+		assert!(p >= 0.00001, "{name}: monobit {p}");
+		let p = runs_test(a.iter().copied());
+		assert!(p >= 0.00001, "{name}: runs {p}");
+	}
+
+	#[test]
+	fn test_generators() {
+		use crate::*;
+
+		macro_rules! all {
+			($($name:literal => $rng:expr,)*) => {
+				$(
+					check($name, $rng);
+					check_bool($name, $rng);
+				)*
+			};
+		}
+
+		all! {
+			"XorShift32" => XorShift32::new(1),
+			"XorShift64" => XorShift64::new(1),
+			"XorShift128p" => XorShift128p::new([1, 2]),
+			"XorShift256ss" => XorShift256ss::new([1, 2, 3, 4]),
+			"Xoroshiro64ss" => Xoroshiro64ss::new([1, 2]),
+			"Xoroshiro64s" => Xoroshiro64s::new([1, 2]),
+			"Xoshiro512ss" => Xoshiro512ss::new([1, 2, 3, 4, 5, 6, 7, 8]),
+			"SplitMix64" => SplitMix64::new(1),
+			"Pcg32" => Pcg32::new(1, 1),
+			"Pcg32XshRs" => Pcg32XshRs::new(1, 1),
+			"PcgRxsMXs64" => PcgRxsMXs64::new(1, 1),
+			"Pcg16" => Pcg16::new(1, 1),
+			"Pcg8" => Pcg8::new(1, 1),
+			"MTwister" => MTwister::new(5489),
+			"ChaCha" => ChaCha::new([1, 2, 3, 4, 5, 6, 7, 8], [1, 2, 3], 0),
+			"ChaChaRng" => ChaChaRng::new([1; 32]),
+			"Rc4" => Rc4::new(b"Key"),
+			"SpongeRng" => SpongeRng::new(b"Key"),
+			"CollatzWeyl64" => CollatzWeyl64::new_two(0x1234, 0x9e3779b97f4a7c15),
+			"CollatzWeyl128_64" => CollatzWeyl128_64::new_two(0x1234, 0x9e3779b97f4a7c15),
+			"CollatzWeyl128" => CollatzWeyl128::new_two(0x1234, 0x9e3779b97f4a7c15),
+			"WichHill" => WichHill::new([1, 2, 3]),
+			"WichHill2006" => WichHill2006::new([1, 2, 3, 4]),
+			"FibLFSR16" => FibLFSR16::new(1),
+			"FibLFG8" => FibLFG8::new(0x0212c845),
+		}
+
+		// `random_bool` uses the lowest bit, which alternates for a power of
+		// 2 modulus LCG, so only the bytes are checked. LCGs without full
+		// width outputs (`MINSTD`, `RANDU`, ...) are not expected to pass.
+		check("LcgSteele64", lcg::LcgSteele64::new(1));
+		check("LcgSteele128", lcg::LcgSteele128::new(1));
+		check("Mmix", lcg::Mmix::new(1));
+	}
+
+	#[test]
+	fn test_entropy() {
+		let mut rng = crate::Static::new(|| 0.0);
+		assert_eq!(estimate(&mut rng, 4096), 0.0);
+
+		let mut rng = crate::XorShift64::new(1);
+		let x = estimate(&mut rng, 1 << 18);
+		assert!(x > 7.99 && x <= 8.0, "{x}");
+
+		// each bit is set 3/4 of the time, so the entropy is
+		// 8 * -(0.75 * log2(0.75) + 0.25 * log2(0.25))
+		let mut rng = crate::SplitMix64::new(1);
+		let mut estimator = EntropyEstimator::new();
+		for _ in 0..1 << 18 {
+			estimator.update(rng.random_u8() | rng.random_u8());
+		}
+		let x = estimator.bits_per_byte();
+		assert!((x - 6.490224995673063).abs() < 0.01, "{x}");
+
+		assert_eq!(shannon_entropy_bytes(&[]), 0.0);
+		assert_eq!(shannon_entropy_bytes(&[1, 2]), 1.0);
+	}
+
+	#[test]
+	fn test_period() {
+		use crate::lcg::{Lecuyer8, RANDU};
+		use crate::FibLFSR16;
+
+		// a prime modulus multiplicative LCG skips `0`, so the period is
+		// `M - 1`.
+		let period = find_period(Lecuyer8::new(1), 1 << 12, |rng| rng.get() as u64);
+		assert_eq!(period, Some(250));
+
+		let period = find_period(FibLFSR16::new(1), 1 << 20, |rng| rng.get() as u64);
+		assert_eq!(period, Some(65535));
+
+		// the lowest bits of RANDU barely change
+		let period = find_period(RANDU::new(1), 1 << 12, |rng| rng.get() as u64 & 1);
+		assert_eq!(period, Some(1));
+		let period = find_period(RANDU::new(1), 1 << 12, |rng| rng.get() as u64 & 0xf);
+		assert_eq!(period, Some(4));
+
+		assert_eq!(find_period(RANDU::new(1), 1 << 12, |rng| rng.get() as u64), None);
+	}
+}
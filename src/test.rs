@@ -38,6 +38,16 @@ fn test_debug() {
 		"ChaCha20",
 	);
 
+	cmp(
+		crate::ChaChaRng::new([0; 32]),
+		"ChaChaRng12",
+	);
+
+	cmp(
+		crate::ChaChaRng::<20>::new_n([0; 32]),
+		"ChaChaRng20",
+	);
+
 	cmp(
 		crate::XorShift32::new(0),
 		"XorShift32",
@@ -58,12 +68,67 @@ fn test_debug() {
 		"XorShift256ss",
 	);
 
+	cmp(
+		crate::Xoroshiro64ss::new([0, 0]),
+		"Xoroshiro64ss",
+	);
+
+	cmp(
+		crate::Xoroshiro64s::new([0, 0]),
+		"Xoroshiro64s",
+	);
+
+	cmp(
+		crate::Xoshiro512ss::new([0; 8]),
+		"Xoshiro512ss",
+	);
+
 	cmp(
 		crate::WichHill::new([0, 0, 0]),
 		"WichHill",
 	);
 
 
+	cmp(
+		crate::WichHill2006::new([0, 0, 0, 0]),
+		"WichHill2006",
+	);
+
+	cmp(
+		crate::lcg::Lecuyer8::new(3),
+		"Lcg8(55, 0, 251, seed: 3)",
+	);
+
+	cmp(
+		crate::lcg::Lcg16::<5, 3, 0>::new(7),
+		"Lcg16(5, 3, 0, seed: 7)",
+	);
+
+	cmp(
+		crate::lcg::VISUAL_BASIC_6,
+		"Lcg32(1140671485, 12820163, 16777216, seed: 327680)",
+	);
+
+	cmp(
+		crate::lcg::MINSTD::new(1),
+		"Lcg32(48271, 0, 2147483647, seed: 1)",
+	);
+
+	cmp(
+		crate::lcg::LcgSchrage32::<48271, 0, 2147483647>::new(1),
+		"LcgSchrage32(48271, 0, 2147483647, seed: 1)",
+	);
+
+	cmp(
+		crate::lcg::Mmix::new(5),
+		"Lcg64(6364136223846793005, 1442695040888963407, 0, seed: 5)",
+	);
+
+	cmp(
+		crate::lcg::Lcg128::<3, 1, 0>::new(9),
+		"Lcg128(3, 1, 0, seed: 9)",
+	);
+
 	cmp(
 		crate::CollatzWeyl64::new_one(0),
 		"CollatzWeyl64",
@@ -99,6 +164,36 @@ fn test_debug() {
 		"Pcg32",
 	);
 
+	cmp(
+		crate::Pcg32XshRs::new(0, 1),
+		"Pcg32XshRs",
+	);
+
+	cmp(
+		crate::PcgRxsMXs64::new(0, 1),
+		"PcgRxsMXs64",
+	);
+
+	cmp(
+		crate::Pcg16::new(0, 1),
+		"Pcg16",
+	);
+
+	cmp(
+		crate::Pcg8::new(0, 1),
+		"Pcg8",
+	);
+
+	cmp(
+		crate::Rc4::new(b"Key"),
+		"Rc4",
+	);
+
+	cmp(
+		crate::SpongeRng::new(b"Key"),
+		"SpongeRng",
+	);
+
 	cmp(
 		crate::SplitMix64::new(0),
 		"SplitMix64",
@@ -109,6 +204,51 @@ fn test_debug() {
 		"Iter<()>(XorShift32)",
 	);
 
+	cmp(
+		crate::BoundedIter::new(&mut crate::XorShift32::new(0), 6),
+		"BoundedIter<6>(XorShift32)",
+	);
+
+	cmp(
+		crate::BoundedIter32::new(&mut crate::XorShift32::new(0), 6),
+		"BoundedIter32<6>(XorShift32)",
+	);
+
+	cmp(
+		crate::BitReader::new(crate::XorShift32::new(0)),
+		"BitReader(XorShift32, buffered: 0)",
+	);
+
+	cmp(
+		crate::JitterAccumulator::new(1000),
+		"JitterAccumulator(entropy: 0)",
+	);
+
+	cmp(
+		crate::GlobalRng::new(crate::XorShift32::new(0)),
+		"GlobalRng",
+	);
+
+	cmp(
+		crate::ByteStream::new(crate::XorShift32::new(0)),
+		"ByteStream(XorShift32, buffered: 0)",
+	);
+
+	cmp(
+		crate::noise::CellRandom::new(0),
+		"CellRandom",
+	);
+
+	cmp(
+		crate::NormalIter::new(crate::XorShift32::new(0), 0.5, 2.0),
+		"NormalIter<0.5, 2>(XorShift32)",
+	);
+
+	cmp(
+		crate::Chars::hex(crate::XorShift32::new(0)),
+		"Chars<16>(XorShift32)",
+	);
+
 	cmp(
 		crate::Buffer::<(), 4, _>::new(crate::XorShift32::new(0)),
 		"Buffer<[(); 4]>(XorShift32)",
@@ -125,6 +265,10 @@ fn test_debug() {
 		fn write(&mut self, _bytes: &[u8]) {}
 		fn finish(&self) -> u64 { 0 }
 	}
+	impl std::hash::BuildHasher for DebugHash {
+		type Hasher = DebugHash;
+		fn build_hasher(&self) -> DebugHash { DebugHash }
+	}
 
 	cmp(
 		crate::Crush::<4, _, _>::new(
@@ -134,8 +278,595 @@ fn test_debug() {
 		"Crush4(XorShift32, DebugHash)",
 	);
 
+	cmp(
+		crate::Crush32::<4, _, _>::new(
+			crate::XorShift32::new(0),
+			DebugHash,
+		),
+		"Crush32_4(XorShift32, DebugHash)",
+	);
+
+	cmp(
+		crate::Debias::new(crate::XorShift32::new(0)),
+		"Debias(XorShift32)",
+	);
+
+	cmp(
+		crate::Combine::new(crate::XorShift32::new(0), crate::XorShift64::new(0)),
+		"Combine(XorShift32, XorShift64)",
+	);
+
+	cmp(
+		crate::Interleave::new(crate::XorShift32::new(0), crate::XorShift64::new(0)),
+		"Interleave(XorShift32, XorShift64)",
+	);
+
+	cmp(
+		crate::Reseeding::new(crate::XorShift32::new(0), crate::SplitMix64::new(0), 64),
+		"Reseeding(XorShift32, SplitMix64)",
+	);
+
+	cmp(
+		crate::Decimate::<3, _>::new(crate::XorShift32::new(0)),
+		"Decimate3(XorShift32)",
+	);
+
+	cmp(
+		crate::Whiten::new(crate::XorShift32::new(0), crate::Whitening::XorFold),
+		"Whiten<XorFold>(XorShift32)",
+	);
+
+	cmp(
+		crate::Whitening::ByteSwap,
+		"ByteSwap",
+	);
+
+	cmp(
+		crate::Counting::new(crate::XorShift32::new(0)),
+		"Counting(XorShift32, u32: 0, u64: 0, bytes: 0)",
+	);
+
+	cmp(
+		crate::Recording::new(crate::XorShift32::new(0), &mut [crate::RecordedDraw::U32(0); 4]),
+		"Recording(XorShift32, 0/4)",
+	);
+
+	cmp(
+		crate::RecordedDraw::Bytes { value: 3, len: 2 },
+		"Bytes { value: 3, len: 2 }",
+	);
+
+	cmp(
+		crate::Replay::new(&[1, 2, 3]),
+		"Replay(0/3)",
+	);
+
+	cmp(
+		crate::Mock::<4>::new().then_u32(0),
+		"Mock(1/4)",
+	);
+
 	cmp(
 		crate::Static::new(|| 0.0),
 		"Static",
 	);
+
+	cmp(
+		crate::StaticU64::new(|| 0),
+		"StaticU64",
+	);
+
+	cmp(
+		crate::StaticBytes::new(|_: &mut [u8]| {}),
+		"StaticBytes",
+	);
+
+	cmp(
+		crate::StaticSeq::new(&[0.0, 0.5]),
+		"StaticSeq(0/2)",
+	);
+
+	cmp(
+		crate::StaticSeqU64::new(&[0, 1, 2]),
+		"StaticSeqU64(0/3)",
+	);
+
+	cmp(
+		crate::stats::EntropyEstimator::new(),
+		"EntropyEstimator(0)",
+	);
+}
+
+#[test]
+fn test_copy() {
+	fn copy<T: Copy>(_: T) {}
+
+	copy(crate::XorShift32::new(0));
+	copy(crate::XorShift64::new(0));
+	copy(crate::XorShift128p::new([0, 0]));
+	copy(crate::XorShift256ss::new([0, 0, 0, 0]));
+	copy(crate::Xoroshiro64ss::new([0, 0]));
+	copy(crate::Xoroshiro64s::new([0, 0]));
+	copy(crate::WichHill::new([0, 0, 0]));
+	copy(crate::WichHill2006::new([0, 0, 0, 0]));
+	copy(crate::lcg::Lecuyer8::new(3));
+	copy(crate::lcg::Lcg16::<5, 3, 0>::new(7));
+	copy(crate::lcg::MINSTD::new(1));
+	copy(crate::lcg::LcgSchrage32::<48271, 0, 2147483647>::new(1));
+	copy(crate::lcg::Mmix::new(5));
+	copy(crate::lcg::Lcg128::<3, 1, 0>::new(9));
+	copy(crate::CollatzWeyl64::new_one(0));
+	copy(crate::FibLFG8::new(0));
+	copy(crate::FibLFSR16::new(0));
+	copy(crate::Pcg32::new(0, 1));
+	copy(crate::Pcg32XshRs::new(0, 1));
+	copy(crate::PcgRxsMXs64::new(0, 1));
+	copy(crate::Pcg16::new(0, 1));
+	copy(crate::Pcg8::new(0, 1));
+	copy(crate::SpongeRng::new(b""));
+	copy(crate::SplitMix64::new(0));
+}
+
+#[test]
+fn test_send_sync() {
+	fn send_sync<T: Send + Sync>() {}
+
+	// the case from `Buffer`'s `MaybeUninit` internals
+	#[allow(dead_code)]
+	struct Wrap(Box<u64>);
+
+	impl crate::FromRandom for Wrap {
+		fn from_random(random: &mut impl crate::Random) -> Self {
+			Wrap(Box::new(random.random()))
+		}
+	}
+
+	// only ever produced, which shouldn't stop `Iter` from being either
+	#[allow(dead_code)]
+	struct NotSend(*const u8);
+
+	impl crate::FromRandom for NotSend {
+		fn from_random(_: &mut impl crate::Random) -> Self {
+			NotSend(core::ptr::null())
+		}
+	}
+
+	type Hasher = std::hash::BuildHasherDefault<std::hash::DefaultHasher>;
+
+	send_sync::<crate::ChaCha>();
+	send_sync::<crate::ChaCha<20>>();
+	send_sync::<crate::ChaChaLayout>();
+	send_sync::<crate::ChaChaRng>();
+	send_sync::<crate::ChaChaRng<20>>();
+	send_sync::<crate::XorShift32>();
+	send_sync::<crate::XorShift64>();
+	send_sync::<crate::XorShift128p>();
+	send_sync::<crate::XorShift256ss>();
+	send_sync::<crate::Xoroshiro64ss>();
+	send_sync::<crate::Xoroshiro64s>();
+	send_sync::<crate::Xoshiro512ss>();
+	send_sync::<crate::Pcg32>();
+	send_sync::<crate::Pcg32XshRs>();
+	send_sync::<crate::PcgRxsMXs64>();
+	send_sync::<crate::Pcg16>();
+	send_sync::<crate::Pcg8>();
+	send_sync::<crate::Rc4>();
+	send_sync::<crate::SpongeRng>();
+	send_sync::<crate::SplitMix64>();
+	send_sync::<crate::MTwister>();
+	send_sync::<crate::WichHill>();
+	send_sync::<crate::WichHill2006>();
+	send_sync::<crate::CollatzWeyl64>();
+	send_sync::<crate::CollatzWeyl128_64>();
+	send_sync::<crate::CollatzWeyl128>();
+	send_sync::<crate::FibLFSR16>();
+	send_sync::<crate::FibLFG8>();
+	send_sync::<crate::lcg::Lecuyer8>();
+	send_sync::<crate::lcg::Lecuyer16>();
+	send_sync::<crate::lcg::MINSTD>();
+	send_sync::<crate::lcg::LcgSchrage32<48271, 0, 2147483647>>();
+	send_sync::<crate::lcg::Mmix>();
+	send_sync::<crate::lcg::Lcg128<3, 1, 0>>();
+	#[cfg(target_arch = "x86_64")]
+	send_sync::<crate::RdRand>();
+	#[cfg(target_arch = "x86_64")]
+	send_sync::<crate::RdSeed>();
+
+	send_sync::<crate::Buffer<u64, 64, crate::XorShift64>>();
+	send_sync::<crate::Buffer<Wrap, 64, crate::XorShift64>>();
+	send_sync::<crate::Buffer8<64, crate::XorShift64>>();
+	send_sync::<crate::Iter<u64, crate::XorShift64>>();
+	send_sync::<crate::Iter<NotSend, crate::XorShift64>>();
+	send_sync::<crate::Crush<4, crate::XorShift64, Hasher>>();
+	send_sync::<crate::Crush32<4, crate::XorShift64, Hasher>>();
+	send_sync::<crate::GlobalRng<crate::XorShift64>>();
+	send_sync::<crate::BoundedIter<'static, crate::XorShift64>>();
+	send_sync::<crate::BoundedIter32<'static, crate::XorShift64>>();
+	send_sync::<crate::ByteStream<crate::XorShift64>>();
+	send_sync::<crate::BitReader<crate::XorShift64>>();
+	send_sync::<crate::Chars<'static, crate::XorShift64>>();
+	send_sync::<crate::Combine<crate::XorShift64, crate::Pcg32>>();
+	send_sync::<crate::Interleave<crate::XorShift64, crate::Pcg32>>();
+	send_sync::<crate::Counting<crate::XorShift64>>();
+	send_sync::<crate::Debias<crate::XorShift64>>();
+	send_sync::<crate::Decimate<2, crate::XorShift64>>();
+	send_sync::<crate::Whiten<crate::XorShift64>>();
+	send_sync::<crate::Whitening>();
+	send_sync::<crate::NormalIter<crate::XorShift64>>();
+	send_sync::<crate::Recording<'static, crate::XorShift64>>();
+	send_sync::<crate::RecordedDraw>();
+	send_sync::<crate::Reseeding<crate::XorShift64, crate::ChaChaRng>>();
+	send_sync::<crate::Uniform>();
+	send_sync::<crate::Bernoulli>();
+	send_sync::<crate::Normal>();
+	send_sync::<crate::UniformInt32>();
+	send_sync::<crate::UniformInt64>();
+	send_sync::<crate::WeightedAlias<4>>();
+	send_sync::<crate::SampleIter<'static, f64, crate::Normal, crate::XorShift64>>();
+	send_sync::<crate::Walk<'static, crate::XorShift64>>();
+	send_sync::<crate::WalkI64<'static, crate::XorShift64>>();
+	send_sync::<crate::Mock<'static, 4>>();
+	send_sync::<crate::Replay<'static>>();
+	send_sync::<crate::Static<fn() -> f64>>();
+	send_sync::<crate::StaticU64<fn() -> u64>>();
+	send_sync::<crate::StaticBytes<fn(&mut [u8])>>();
+	send_sync::<crate::StaticSeq<'static>>();
+	send_sync::<crate::StaticSeqU64<'static>>();
+	send_sync::<crate::JitterAccumulator>();
+	send_sync::<crate::ShuffleBag<u8, 4>>();
+	send_sync::<crate::ShuffleBagVec<u8>>();
+	send_sync::<crate::quasi::Halton>();
+	send_sync::<crate::quasi::HaltonNd<3>>();
+	send_sync::<crate::noise::CellRandom>();
+	send_sync::<crate::stats::EntropyEstimator>();
+	send_sync::<crate::stats::StatsReport>();
+	send_sync::<crate::Error>();
+}
+
+#[test]
+fn test_const() {
+	const fn generate<const N: usize>() -> ([u32; N], [u64; N], [u32; N], [u32; N]) {
+		let mut pcg = crate::Pcg32::new(1, 2);
+		let mut xorshift = crate::XorShift64::new(1);
+		let mut mt = crate::MTwister::new(5489);
+		let mut chacha = crate::ChaCha::new([1; 8], [2; 3], 0);
+
+		let mut ret = ([0; N], [0; N], [0; N], [0; N]);
+		let mut i = 0;
+		while i < N {
+			ret.0[i] = pcg.get();
+			ret.1[i] = xorshift.get();
+			ret.2[i] = mt.get();
+			ret.3[i] = chacha.get();
+			i += 1;
+		}
+		ret
+	}
+
+	const VALUES: ([u32; 64], [u64; 64], [u32; 64], [u32; 64]) = generate();
+
+	let mut pcg = crate::Pcg32::new(1, 2);
+	let mut xorshift = crate::XorShift64::new(1);
+	let mut mt = crate::MTwister::new(5489);
+	let mut chacha = crate::ChaCha::new([1; 8], [2; 3], 0);
+	for i in 0..64 {
+		assert_eq!(VALUES.0[i], pcg.get());
+		assert_eq!(VALUES.1[i], xorshift.get());
+		assert_eq!(VALUES.2[i], mt.get());
+		assert_eq!(VALUES.3[i], chacha.get());
+	}
+
+	const BLOCK: [u8; 64] = crate::ChaCha::new([0; 8], [0; 3], 0).keystream_block();
+	assert_eq!(BLOCK, crate::ChaCha::new([0; 8], [0; 3], 0).keystream_block());
+}
+
+#[test]
+fn test_eq() {
+	use crate::RandomImpl;
+
+	macro_rules! diverge {
+		($($rng:expr),* $(,)?) => {$({
+			let mut a = $rng;
+			let mut b = a.clone();
+			assert!(a == b);
+
+			a.random_u64();
+			assert!(a != b);
+
+			b.random_u64();
+			assert!(a == b);
+			assert_eq!(a.random_u64(), b.random_u64());
+		})*};
+	}
+
+	diverge!(
+		crate::XorShift32::new(1),
+		crate::XorShift64::new(1),
+		crate::XorShift128p::new([1, 2]),
+		crate::XorShift256ss::new([1, 2, 3, 4]),
+		crate::Xoroshiro64ss::new([1, 2]),
+		crate::Xoroshiro64s::new([1, 2]),
+		crate::Xoshiro512ss::new([1, 2, 3, 4, 5, 6, 7, 8]),
+		crate::Pcg32::new(1, 2),
+		crate::Pcg32XshRs::new(1, 2),
+		crate::PcgRxsMXs64::new(1, 2),
+		crate::Pcg16::new(1, 2),
+		crate::Pcg8::new(1, 2),
+		crate::Rc4::new(b"Key"),
+		crate::SpongeRng::new(b"Key"),
+		crate::SplitMix64::new(1),
+		crate::MTwister::new(5489),
+		crate::ChaCha::new([1; 8], [2; 3], 0),
+		crate::ChaChaRng::new([1; 32]),
+		crate::WichHill::new([1, 2, 3]),
+		crate::WichHill2006::new([1, 2, 3, 4]),
+		crate::CollatzWeyl64::new_one(1),
+		crate::CollatzWeyl128_64::new_one(1),
+		crate::CollatzWeyl128::new_one(1),
+		crate::FibLFSR16::new(1),
+		crate::FibLFG8::new(1),
+		crate::lcg::MINSTD::new(1),
+		crate::lcg::Mmix::new(1),
+		crate::lcg::LcgSchrage32::<48271, 0, 2147483647>::new(1),
+		crate::Buffer8::<4, _>::new(crate::XorShift64::new(1)),
+	);
+
+	// skipping ahead lands on the same state as stepping
+	let mut rng = crate::SplitMix64::new(1);
+	for _ in 0..10 {
+		rng.get();
+	}
+	assert!(rng == crate::SplitMix64::new(1u64.wrapping_add(0x9e3779b97f4a7c15u64.wrapping_mul(10))));
+
+	let mut rng = crate::ChaCha::new([1; 8], [2; 3], 0);
+	rng.fill_keystream(&mut [0; 64 * 3]);
+	assert!(rng == crate::ChaCha::new([1; 8], [2; 3], 3));
+	rng.fill_keystream(&mut [0; 10]);
+	assert!(rng != crate::ChaCha::new([1; 8], [2; 3], 3));
+
+	let mut rng = crate::lcg::MINSTD::new(1);
+	let x = rng.get();
+	assert!(rng == crate::lcg::MINSTD::new(x));
+
+	// consumed bytes of the buffer are ignored
+	let mut inner = crate::XorShift64::new(1);
+	inner.random_u64();
+	let a = crate::Buffer8::<4, _>::new(inner);
+	let mut b = crate::Buffer8::<4, _>::new(crate::XorShift64::new(1));
+	b.run();
+	while b.get_checked().is_some() {}
+	assert!(a == b);
+	b.run();
+	assert!(a != b);
+}
+
+#[test]
+fn test_try_new() {
+	use crate::Error;
+
+	assert_eq!(crate::XorShift32::try_new(0), Err(Error::ZeroSeed));
+	assert_eq!(crate::XorShift64::try_new(0), Err(Error::ZeroSeed));
+	assert_eq!(crate::XorShift128p::try_new([0, 1]), Err(Error::ZeroSeed));
+	assert_eq!(crate::XorShift128p::try_new([1, 0]), Err(Error::ZeroSeed));
+	assert_eq!(crate::XorShift256ss::try_new([1, 1, 0, 1]), Err(Error::ZeroSeed));
+	assert_eq!(crate::Xoroshiro64ss::try_new([0, 1]), Err(Error::ZeroSeed));
+	assert_eq!(crate::Xoroshiro64s::try_new([1, 0]), Err(Error::ZeroSeed));
+	assert_eq!(crate::Xoshiro512ss::try_new([1, 1, 1, 1, 1, 1, 1, 0]), Err(Error::ZeroSeed));
+	assert_eq!(crate::WichHill::try_new([1, 0, 1]), Err(Error::ZeroSeed));
+	assert_eq!(crate::WichHill::try_new([30269, 1, 1]), Err(Error::SeedOutOfRange));
+	assert_eq!(crate::WichHill::try_new([1, 1, 30323]), Err(Error::SeedOutOfRange));
+	assert_eq!(crate::WichHill2006::try_new([1, 1, 0, 1]), Err(Error::ZeroSeed));
+	assert_eq!(crate::WichHill2006::try_new([1, 1, 1, 2147483123]), Err(Error::SeedOutOfRange));
+	assert_eq!(crate::FibLFSR16::try_new(0), Err(Error::ZeroSeed));
+	assert_eq!(crate::FibLFSR16::<0x2f>::try_new_n(0), Err(Error::ZeroSeed));
+	assert_eq!(crate::FibLFG8::try_new(0x0101_0001), Err(Error::ZeroSeed));
+	assert_eq!(crate::CollatzWeyl64::try_new_one(2), Err(Error::InvalidParameter));
+	assert_eq!(crate::CollatzWeyl128_64::try_new_two(1, 0), Err(Error::InvalidParameter));
+	assert_eq!(crate::CollatzWeyl128::try_new_two(1, 4), Err(Error::InvalidParameter));
+	assert_eq!(crate::lcg::Lecuyer8::try_new(0), Err(Error::ZeroSeed));
+	assert_eq!(crate::lcg::Lecuyer8::try_new(251), Err(Error::SeedOutOfRange));
+	assert_eq!(crate::lcg::Lcg16::<5, 3, 100>::try_new(100), Err(Error::SeedOutOfRange));
+	assert_eq!(crate::lcg::MINSTD::try_new(0), Err(Error::ZeroSeed));
+	assert_eq!(crate::lcg::MINSTD::try_new(2147483647), Err(Error::SeedOutOfRange));
+	assert_eq!(crate::lcg::LcgSchrage32::<48271, 0, 2147483647>::try_new(0), Err(Error::ZeroSeed));
+	assert_eq!(crate::lcg::LcgSchrage32::<48271, 0, 2147483647>::try_new(u32::MAX), Err(Error::SeedOutOfRange));
+	assert_eq!(crate::lcg::Lcg64::<3, 0, 0>::try_new(0), Err(Error::ZeroSeed));
+	assert_eq!(crate::lcg::Lcg128::<3, 0, 7>::try_new(7), Err(Error::SeedOutOfRange));
+
+	// accepted seeds are used as is, like `new()` would
+	assert_eq!(crate::XorShift32::try_new(5), Ok(crate::XorShift32::new(5)));
+	assert_eq!(crate::XorShift64::try_new(5), Ok(crate::XorShift64::new(5)));
+	assert_eq!(crate::XorShift128p::try_new([1, 2]), Ok(crate::XorShift128p::new([1, 2])));
+	assert_eq!(crate::XorShift256ss::try_new([1, 2, 3, 4]), Ok(crate::XorShift256ss::new([1, 2, 3, 4])));
+	assert_eq!(crate::WichHill::try_new([30268, 1, 2]), Ok(crate::WichHill::new([30268, 1, 2])));
+	assert_eq!(crate::FibLFSR16::try_new(5), Ok(crate::FibLFSR16::new(5)));
+	assert_eq!(crate::FibLFG8::try_new(0x0102_0304), Ok(crate::FibLFG8::new(0x0102_0304)));
+	assert_eq!(crate::CollatzWeyl64::try_new_one(3), Ok(crate::CollatzWeyl64::new_one(3)));
+	assert_eq!(crate::CollatzWeyl128_64::try_new_two(1, 3), Ok(crate::CollatzWeyl128_64::new_two(1, 3)));
+	assert_eq!(crate::CollatzWeyl128::try_new_two(1, 3), Ok(crate::CollatzWeyl128::new_two(1, 3)));
+	assert_eq!(crate::lcg::Lecuyer8::try_new(250), Ok(crate::lcg::Lecuyer8::new(250)));
+	assert_eq!(crate::lcg::Lcg16::<5, 3, 100>::try_new(0), Ok(crate::lcg::Lcg16::<5, 3, 100>::new(0)));
+	assert_eq!(crate::lcg::MINSTD::try_new(1), Ok(crate::lcg::MINSTD::new(1)));
+	assert_eq!(crate::lcg::Mmix::try_new(0), Ok(crate::lcg::Mmix::new(0)));
+
+	// the infallible constructors still adjust their input
+	assert_eq!(crate::XorShift32::new(0), crate::XorShift32::new(1));
+	assert_eq!(crate::XorShift64::new(0), crate::XorShift64::new(1));
+	assert_eq!(crate::XorShift128p::new([0, 2]), crate::XorShift128p::new([1, 2]));
+	assert_eq!(crate::XorShift256ss::new([0, 2, 0, 4]), crate::XorShift256ss::new([1, 2, 1, 4]));
+	assert_eq!(crate::WichHill::new([0, 30307, 30324]), crate::WichHill::new([1, 1, 1]));
+	assert_eq!(crate::FibLFSR16::new(0), crate::FibLFSR16::new(1));
+	assert_eq!(crate::FibLFG8::new(0), crate::FibLFG8::new(0x0101_0101));
+	assert_eq!(crate::CollatzWeyl64::new_one(2), crate::CollatzWeyl64::new_one(3));
+	assert_eq!(crate::lcg::Lecuyer8::new(0), crate::lcg::Lecuyer8::new(1));
+	assert_eq!(crate::lcg::Lecuyer8::new(252), crate::lcg::Lecuyer8::new(1));
+	assert_eq!(crate::lcg::MINSTD::new(2147483647), crate::lcg::MINSTD::new(1));
+}
+
+#[test]
+fn test_bytes_uninit() {
+	use crate::RandomImpl;
+	use core::mem::MaybeUninit;
+
+	// the same bytes as `random_bytes()`, and in the same state afterwards.
+	// odd lengths, and lengths around the staging buffer, for miri to check
+	macro_rules! same {
+		($($rng:expr),* $(,)?) => {$({
+			for len in [0, 1, 3, 7, 13, 64, 255, 256, 257, 513, 1001] {
+				let mut a = $rng;
+				let mut b = $rng;
+
+				let mut buf = Box::<[u8]>::new_uninit_slice(len);
+				let x = a.random_bytes_uninit(&mut buf).to_vec();
+				let mut y = std::vec![0; len];
+				b.random_bytes(&mut y);
+				assert_eq!(x, y, "{} {len}", stringify!($rng));
+				assert_eq!(a.random_u64(), b.random_u64(), "{len}");
+			}
+		})*};
+	}
+
+	same!(
+		crate::XorShift32::new(1),
+		crate::XorShift64::new(1),
+		crate::Xoshiro512ss::new([1, 2, 3, 4, 5, 6, 7, 8]),
+		crate::Pcg16::new(1, 2),
+		crate::Pcg8::new(1, 2),
+		crate::Rc4::new(b"Key"),
+		crate::SpongeRng::new(b"Key"),
+		crate::MTwister::new(5489),
+		crate::ChaCha::new([1; 8], [2; 3], 0),
+		crate::ChaChaRng::new([1; 32]),
+		{
+			let mut rng = crate::ChaChaRng::new([1; 32]);
+			rng.seek_bytes(61);
+			rng
+		},
+		crate::CollatzWeyl128::new_one(1),
+		crate::FibLFG8::new(1),
+		crate::lcg::Lcg128::<3, 1, 0>::new(9),
+		crate::Buffer8::<5, _>::new(crate::XorShift64::new(1)),
+		crate::Counting::new(crate::XorShift64::new(1)),
+		crate::ByteStream::new(crate::XorShift64::new(1)),
+		crate::BitReader::new(crate::XorShift64::new(1)),
+		crate::Combine::new(crate::XorShift64::new(1), crate::Pcg8::new(1, 2)),
+		crate::Interleave::new(crate::XorShift64::new(1), crate::Pcg32::new(1, 2)),
+		crate::Reseeding::new(crate::XorShift64::new(1), crate::SplitMix64::new(2), 100),
+		crate::Iter::<u8, _>::new(crate::ChaChaRng::new([1; 32])),
+		crate::Crush::<4, _, _>::new(crate::XorShift64::new(1), std::hash::BuildHasherDefault::<std::hash::DefaultHasher>::default()),
+		crate::Crush32::<4, _, _>::new(crate::XorShift32::new(1), std::hash::BuildHasherDefault::<std::hash::DefaultHasher>::default()),
+	);
+
+	// through references, and with a single lock
+	let global = crate::GlobalRng::new(crate::ChaChaRng::new([1; 32]));
+	let mut rng = crate::ChaChaRng::new([1; 32]);
+	let mut dyn_rng: &mut dyn RandomImpl = &mut rng;
+	let mut buf = [MaybeUninit::uninit(); 301];
+	let x = (&global).random_bytes_uninit(&mut buf).to_vec();
+	let mut buf = [MaybeUninit::uninit(); 301];
+	let y = (&mut dyn_rng).random_bytes_uninit(&mut buf);
+	assert_eq!(x, y);
+	assert_eq!((&global).random_u32(), rng.random_u32());
+
+	let mut counting = crate::Counting::new(crate::XorShift64::new(1));
+	counting.random_bytes_uninit(&mut [MaybeUninit::uninit(); 300]);
+	assert_eq!(counting.bytes_filled(), 300);
+
+	// one expectation, however long
+	let bytes = [7; 1000];
+	let mut rng = crate::Mock::<1>::new().then_bytes(&bytes);
+	assert_eq!(rng.random_bytes_uninit(&mut [MaybeUninit::uninit(); 1000]), bytes);
+	assert_eq!(rng.remaining(), 0);
+}
+
+// checks `rng` against the width policy documented on `RandomImpl`, where
+// `next` returns a native draw as little-endian bytes
+fn consistency<R: crate::Random + Clone, const W: usize>(rng: R, next: impl Fn(&mut R) -> [u8; W]) {
+	// `max(1, width / W)` draws, the first in the high bits
+	let compose = |rng: &mut R, width: usize| -> u128 {
+		let mut x = 0u128;
+		for _ in 0..(width / W).max(1) {
+			let mut draw = [0; 16];
+			draw[..W].copy_from_slice(&next(rng));
+			x = x.checked_shl(W as u32 * 8).unwrap_or(0) | u128::from_le_bytes(draw);
+		}
+		x
+	};
+
+	let mut a = rng.clone();
+	let mut b = rng.clone();
+	for _ in 0..16 {
+		assert_eq!(a.random_u32() as u128, compose(&mut b, 4) as u32 as u128);
+		assert_eq!(a.random_u64() as u128, compose(&mut b, 8) as u64 as u128);
+	}
+
+	for len in [0, 1, 3, 8, 13, 64, 100, 257] {
+		let mut a = rng.clone();
+		let mut b = rng.clone();
+
+		let mut bytes = [0; 257];
+		a.random_bytes(&mut bytes[..len]);
+
+		let mut expect = [0; 257 + 16];
+		let mut i = 0;
+		while i < len {
+			expect[i..i + W].copy_from_slice(&next(&mut b));
+			i += W;
+		}
+		assert_eq!(bytes[..len], expect[..len], "{len}");
+
+		// the partial draw was used up
+		assert_eq!(a.random_u32() as u128, compose(&mut b, 4) as u32 as u128);
+	}
+
+	let mut a = rng.clone();
+	let mut b = rng.clone();
+	for _ in 0..16 {
+		let x = b.random_u64();
+		let y = b.random_u64();
+		assert_eq!(a.random_u128(), crate::common::u64_compose_u128(x, y));
+		assert_eq!(a.random_f64(), crate::common::u64_normalize_f64(b.random_u64()));
+		assert_eq!(a.random_f32(), crate::common::u32_normalize_f32(b.random_u32()));
+	}
+}
+
+#[test]
+fn test_consistency() {
+	consistency(crate::XorShift32::new(1), |rng| rng.get().to_le_bytes());
+	consistency(crate::XorShift64::new(1), |rng| rng.get().to_le_bytes());
+	consistency(crate::XorShift128p::new([1, 2]), |rng| rng.get().to_le_bytes());
+	consistency(crate::XorShift256ss::new([1, 2, 3, 4]), |rng| rng.get().to_le_bytes());
+	consistency(crate::Xoroshiro64ss::new([1, 2]), |rng| rng.get().to_le_bytes());
+	consistency(crate::Xoroshiro64s::new([1, 2]), |rng| rng.get().to_le_bytes());
+	consistency(crate::Xoshiro512ss::new([1, 2, 3, 4, 5, 6, 7, 8]), |rng| rng.get().to_le_bytes());
+	consistency(crate::SplitMix64::new(1), |rng| rng.get().to_le_bytes());
+	consistency(crate::Pcg32::new(1, 2), |rng| rng.get().to_le_bytes());
+	consistency(crate::Pcg32XshRs::new(1, 2), |rng| rng.get().to_le_bytes());
+	consistency(crate::PcgRxsMXs64::new(1, 2), |rng| rng.get().to_le_bytes());
+	consistency(crate::Pcg16::new(1, 2), |rng| rng.get().to_le_bytes());
+	consistency(crate::Pcg8::new(1, 2), |rng| rng.get().to_le_bytes());
+	consistency(crate::Rc4::new(b"Key"), |rng| rng.get().to_le_bytes());
+	consistency(crate::SpongeRng::new(b"Key"), |rng| rng.get_byte().to_le_bytes());
+	consistency(crate::MTwister::new(5489), |rng| rng.get().to_le_bytes());
+	consistency(crate::ChaCha::new([1; 8], [2; 3], 0), |rng| rng.get().to_le_bytes());
+	consistency(crate::ChaCha::<20>::new_n([1; 8], [2; 3], 0), |rng| rng.get().to_le_bytes());
+	consistency(crate::ChaChaRng::new([1; 32]), |rng| rng.get().to_le_bytes());
+	consistency(crate::ChaChaRng::<20>::new_n([1; 32]), |rng| rng.get().to_le_bytes());
+	// words split across blocks
+	let mut rng = crate::ChaChaRng::new([1; 32]);
+	rng.seek_bytes(62);
+	consistency(rng, |rng| rng.get().to_le_bytes());
+	consistency(crate::WichHill::new([1, 2, 3]), |rng| rng.get_u32().to_le_bytes());
+	consistency(crate::WichHill2006::new([1, 2, 3, 4]), |rng| rng.get_u64().to_le_bytes());
+	consistency(crate::CollatzWeyl64::new_one(1), |rng| rng.get().to_le_bytes());
+	consistency(crate::CollatzWeyl128_64::new_one(1), |rng| rng.get().to_le_bytes());
+	consistency(crate::CollatzWeyl128::new_one(1), |rng| rng.get().to_le_bytes());
+	consistency(crate::FibLFSR16::new(1), |rng| rng.get().to_le_bytes());
+	consistency(crate::FibLFG8::new(1), |rng| rng.get().to_le_bytes());
+	consistency(crate::lcg::Lecuyer8::new(1), |rng| rng.get().to_le_bytes());
+	consistency(crate::lcg::Lecuyer16::new(1), |rng| rng.get().to_le_bytes());
+	consistency(crate::lcg::MINSTD::new(1), |rng| rng.get().to_le_bytes());
+	consistency(crate::lcg::LcgSchrage32::<48271, 0, 2147483647>::new(1), |rng| rng.get().to_le_bytes());
+	consistency(crate::lcg::Mmix::new(1), |rng| rng.get().to_le_bytes());
+	consistency(crate::lcg::Lcg128::<3, 1, 0>::new(9), |rng| rng.get().to_le_bytes());
 }
@@ -0,0 +1,235 @@
+/// utility type for consuming a [`crate::Random`] one bit at a time.
+/// 
+/// `BitReader` buffers a single `u64` from the inner rng, and hands out its
+/// bits as they are asked for, only drawing a new `u64` once every bit of
+/// the last one was used. this is useful when the inner rng is slow or
+/// entropy-limited, like a hardware rng.
+/// 
+/// bits are handed out from the top of each buffered `u64` down, so reading
+/// whole words back together gives the same values as the inner rng.
+/// 
+/// ```
+/// # use prrng::BitReader;
+/// # use prrng::XorShift64;
+/// let mut rng = BitReader::new(XorShift64::new(1));
+/// 
+/// // a d6 only needs 3 bits per attempt
+/// let x = rng.take_bounded(6);
+/// assert!(x < 6);
+/// 
+/// let coin = rng.take_bit();
+/// ```
+/// 
+/// `BitReader` also implements [`crate::RandomImpl`]. those methods are
+/// passed straight through to the inner rng, and leave the buffered bits
+/// alone.
+#[derive(Clone)]
+pub struct BitReader<R: crate::Random> {
+	inner: R,
+	buf: u64,
+	len: u32,
+	consumed: u64,
+}
+
+impl<R: crate::Random> BitReader<R> {
+	/// construct a new `BitReader`, with nothing buffered.
+	#[inline]
+	pub const fn new(inner: R) -> Self {
+		Self {
+			inner,
+			buf: 0,
+			len: 0,
+			consumed: 0,
+		}
+	}
+
+	/// consume `self`, returning the inner rng.
+	/// 
+	/// any buffered bits are lost.
+	#[inline]
+	pub fn unwrap(self) -> R {
+		self.inner
+	}
+
+	/// how many bits were handed out, including by the
+	/// [`crate::RandomImpl`] methods.
+	#[inline]
+	pub fn bits_consumed(&self) -> u64 {
+		self.consumed
+	}
+
+	/// how many bits are buffered, and can be taken without drawing from
+	/// the inner rng.
+	#[inline]
+	pub fn bits_buffered(&self) -> u32 {
+		self.len
+	}
+
+	// take `n <= self.len` bits from the top of the buffer
+	#[inline]
+	fn take_buffered(&mut self, n: u32) -> u64 {
+		let x = self.buf.checked_shr(64 - n).unwrap_or(0);
+		self.buf = self.buf.checked_shl(n).unwrap_or(0);
+		self.len -= n;
+		x
+	}
+
+	/// returns a new `u64`, with only the low `n` bits random, and the rest
+	/// `0`.
+	/// 
+	/// ## panics
+	/// 
+	/// panics if `n` is more than `64`.
+	pub fn take_bits(&mut self, n: u32) -> u64 {
+		assert!(n <= 64, "`n` must not be more than 64");
+		self.consumed += n as u64;
+
+		if n <= self.len {
+			return self.take_buffered(n);
+		}
+
+		let rest = n - self.len;
+		let hi = self.take_buffered(self.len);
+		self.buf = self.inner.random_u64();
+		self.len = 64;
+		let lo = self.take_buffered(rest);
+		hi.checked_shl(rest).unwrap_or(0) | lo
+	}
+
+	/// returns a new `bool`, using a single bit.
+	#[inline]
+	pub fn take_bit(&mut self) -> bool {
+		self.take_bits(1) == 1
+	}
+
+	/// returns a new `u64`, uniformly distributed within `0 .. bound`.
+	/// 
+	/// each attempt only takes as many bits as needed to represent
+	/// `bound - 1`, and is rejected if it is not less than `bound`.
+	/// 
+	/// ## panics
+	/// 
+	/// panics if `bound` is `0`.
+	pub fn take_bounded(&mut self, bound: u64) -> u64 {
+		assert!(bound != 0, "`bound` must not be 0");
+		let bits = 64 - (bound - 1).leading_zeros();
+		loop {
+			let x = self.take_bits(bits);
+			if x < bound {
+				return x;
+			}
+		}
+	}
+}
+
+impl<R: crate::Random> crate::RandomImpl for BitReader<R> {
+	#[inline]
+	fn random_u64(&mut self) -> u64 {
+		self.consumed += 64;
+		self.inner.random_u64()
+	}
+
+	#[inline]
+	fn random_u32(&mut self) -> u32 {
+		self.consumed += 32;
+		self.inner.random_u32()
+	}
+
+	#[inline]
+	fn random_bytes(&mut self, dst: &mut [u8]) {
+		self.consumed += dst.len() as u64 * 8;
+		self.inner.random_bytes(dst);
+	}
+}
+
+impl<R: crate::Random + core::fmt::Debug> core::fmt::Debug for BitReader<R> {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		write!(f, "BitReader({:?}, buffered: {})", self.inner, self.len)
+	}
+}
+
+
+#[cfg(test)]
+mod test {
+	use crate::{BitReader, Counting, RandomImpl, XorShift64};
+
+	#[test]
+	fn test_consumed() {
+		let mut rng = BitReader::new(Counting::new(XorShift64::new(1)));
+
+		for n in 0..=64 {
+			let x = rng.take_bits(n);
+			assert!(n == 64 || x >> n == 0);
+		}
+		for _ in 0..100 {
+			rng.take_bit();
+		}
+		// 0 + 1 + ... + 64, then 100
+		assert_eq!(rng.bits_consumed(), 2080 + 100);
+
+		let calls = rng.clone().unwrap().u64_calls();
+		assert_eq!(calls, (2180u64).div_ceil(64));
+		assert_eq!(rng.bits_buffered() as u64, calls * 64 - 2180);
+
+		let before = rng.bits_consumed();
+		rng.random_u64();
+		rng.random_u32();
+		rng.random_bytes(&mut [0; 3]);
+		assert_eq!(rng.bits_consumed() - before, 64 + 32 + 24);
+		assert_eq!(rng.bits_buffered() as u64, calls * 64 - 2180);
+	}
+
+	#[test]
+	fn test_bounded() {
+		let mut rng = BitReader::new(XorShift64::new(1));
+
+		assert_eq!(rng.take_bounded(1), 0);
+		assert_eq!(rng.bits_consumed(), 0);
+
+		// powers of two never reject
+		for _ in 0..100 {
+			assert!(rng.take_bounded(8) < 8);
+		}
+		assert_eq!(rng.bits_consumed(), 300);
+
+		let mut counts = [0; 6];
+		for _ in 0..6000 {
+			counts[rng.take_bounded(6) as usize] += 1;
+		}
+		for count in counts {
+			assert!((850..1150).contains(&count), "{counts:?}");
+		}
+		assert_eq!(rng.bits_consumed() % 3, 0);
+	}
+
+	#[test]
+	fn test_aligned() {
+		let mut raw = XorShift64::new(3);
+		let mut rng = BitReader::new(raw);
+
+		assert_eq!(rng.take_bits(64), raw.random_u64());
+
+		let x = raw.random_u64();
+		assert_eq!(rng.take_bits(32), x >> 32);
+		assert_eq!(rng.take_bits(32), x & 0xffff_ffff);
+
+		let x = raw.random_u64();
+		let mut y = 0;
+		for _ in 0..64 {
+			y = y << 1 | rng.take_bit() as u64;
+		}
+		assert_eq!(x, y);
+
+		// a read straddling two words
+		let a = raw.random_u64();
+		let b = raw.random_u64();
+		assert_eq!(rng.take_bits(40), a >> 24);
+		assert_eq!(rng.take_bits(40), (a & 0xff_ffff) << 16 | b >> 48);
+	}
+
+	#[test]
+	#[should_panic = "`n` must not be more than 64"]
+	fn test_too_many() {
+		BitReader::new(XorShift64::new(1)).take_bits(65);
+	}
+}
@@ -0,0 +1,225 @@
+/// infinite iterator of `u64`s, uniformly distributed within `0 .. bound`.
+/// 
+/// this produces the same values as repeatedly calling
+/// [`crate::Random::random_u64_bound()`], but computes its rejection
+/// threshold once, instead of for every value.
+/// see [`crate::Random::random_iter_bound()`].
+/// 
+/// ```
+/// # use prrng::XorShift64;
+/// use prrng::Random;
+/// let mut rng = XorShift64::new(1);
+/// 
+/// let mut counts = [0; 6];
+/// for i in rng.random_iter_bound(6).take(600) {
+///     counts[i as usize] += 1;
+/// }
+/// ```
+pub struct BoundedIter<'a, R: crate::Random> {
+	inner: &'a mut R,
+	bound: u64,
+	threshold: u64,
+}
+
+impl<'a, R: crate::Random> BoundedIter<'a, R> {
+	/// construct a new `BoundedIter`.
+	/// 
+	/// ## panics
+	/// 
+	/// panics if `bound` is `0`.
+	#[inline]
+	pub fn new(inner: &'a mut R, bound: u64) -> Self {
+		assert!(bound != 0, "`bound` must not be 0");
+		Self {
+			inner,
+			bound,
+			threshold: bound.wrapping_neg() % bound,
+		}
+	}
+
+	/// the exclusive upper bound of every value.
+	#[inline]
+	pub fn bound(&self) -> u64 {
+		self.bound
+	}
+
+	#[inline]
+	fn accept(&mut self) -> u64 {
+		loop {
+			let x = self.inner.random_u64();
+			if x >= self.threshold {
+				return x;
+			}
+		}
+	}
+}
+
+impl<R: crate::Random> Iterator for BoundedIter<'_, R> {
+	type Item = u64;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		Some(self.accept() % self.bound)
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		(usize::MAX, None)
+	}
+
+	/// skipped values are drawn but not reduced, so the values after this
+	/// are the same as calling [`Self::next()`] `n + 1` times.
+	#[inline]
+	fn nth(&mut self, n: usize) -> Option<Self::Item> {
+		for _ in 0..n {
+			self.accept();
+		}
+		self.next()
+	}
+}
+
+impl<R: crate::Random> core::iter::FusedIterator for BoundedIter<'_, R> {}
+
+impl<R: crate::Random + core::fmt::Debug> core::fmt::Debug for BoundedIter<'_, R> {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		write!(f, "BoundedIter<{}>({:?})", self.bound, self.inner)
+	}
+}
+
+/// infinite iterator of `u32`s, uniformly distributed within `0 .. bound`.
+/// 
+/// this is the same as [`BoundedIter`], but with
+/// [`crate::Random::random_u32_bound()`].
+/// see [`crate::Random::random_iter_bound32()`].
+pub struct BoundedIter32<'a, R: crate::Random> {
+	inner: &'a mut R,
+	bound: u32,
+	threshold: u32,
+}
+
+impl<'a, R: crate::Random> BoundedIter32<'a, R> {
+	/// construct a new `BoundedIter32`.
+	/// 
+	/// ## panics
+	/// 
+	/// panics if `bound` is `0`.
+	#[inline]
+	pub fn new(inner: &'a mut R, bound: u32) -> Self {
+		assert!(bound != 0, "`bound` must not be 0");
+		Self {
+			inner,
+			bound,
+			threshold: bound.wrapping_neg() % bound,
+		}
+	}
+
+	/// the exclusive upper bound of every value.
+	#[inline]
+	pub fn bound(&self) -> u32 {
+		self.bound
+	}
+
+	#[inline]
+	fn accept(&mut self) -> u32 {
+		loop {
+			let x = self.inner.random_u32();
+			if x >= self.threshold {
+				return x;
+			}
+		}
+	}
+}
+
+impl<R: crate::Random> Iterator for BoundedIter32<'_, R> {
+	type Item = u32;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		Some(self.accept() % self.bound)
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		(usize::MAX, None)
+	}
+
+	/// skipped values are drawn but not reduced, so the values after this
+	/// are the same as calling [`Self::next()`] `n + 1` times.
+	#[inline]
+	fn nth(&mut self, n: usize) -> Option<Self::Item> {
+		for _ in 0..n {
+			self.accept();
+		}
+		self.next()
+	}
+}
+
+impl<R: crate::Random> core::iter::FusedIterator for BoundedIter32<'_, R> {}
+
+impl<R: crate::Random + core::fmt::Debug> core::fmt::Debug for BoundedIter32<'_, R> {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		write!(f, "BoundedIter32<{}>({:?})", self.bound, self.inner)
+	}
+}
+
+
+#[cfg(test)]
+mod test {
+	use crate::{Random, XorShift64};
+
+	#[test]
+	fn test_bound() {
+		let mut rng = XorShift64::new(1);
+
+		for bound in [1, 2, 3, 7, 100, (1 << 63) + 1, u64::MAX] {
+			assert!(rng.random_iter_bound(bound).take(256).all(|x| x < bound));
+		}
+		for bound in [1, 2, 3, 7, 100, (1 << 31) + 1, u32::MAX] {
+			assert!(rng.random_iter_bound32(bound).take(256).all(|x| x < bound));
+		}
+	}
+
+	#[test]
+	fn test_agreement() {
+		for bound in [3, 7, 100, (1 << 63) + 1] {
+			let mut a = XorShift64::new(1);
+			let mut b = XorShift64::new(1);
+
+			let mut iter = a.random_iter_bound(bound);
+			for _ in 0..256 {
+				assert_eq!(iter.next(), Some(b.random_u64_bound(bound)));
+			}
+		}
+
+		for bound in [3, 7, 100, (1 << 31) + 1] {
+			let mut a = XorShift64::new(1);
+			let mut b = XorShift64::new(1);
+
+			let mut iter = a.random_iter_bound32(bound);
+			for _ in 0..256 {
+				assert_eq!(iter.next(), Some(b.random_u32_bound(bound)));
+			}
+		}
+	}
+
+	#[test]
+	fn test_nth() {
+		let bound = (1 << 63) + 1;
+
+		let mut a = XorShift64::new(1);
+		let mut b = XorShift64::new(1);
+
+		let mut x = a.random_iter_bound(bound);
+		let mut y = b.random_iter_bound(bound);
+
+		assert_eq!(x.nth(10), y.by_ref().take(11).last());
+		assert_eq!(x.nth(1), y.nth(1));
+		assert!(x.take(64).eq(y.take(64)));
+	}
+
+	#[test]
+	#[should_panic = "`bound` must not be 0"]
+	fn test_zero() {
+		XorShift64::new(1).random_iter_bound(0);
+	}
+}
@@ -11,6 +11,18 @@
 /// cache values `T`. see the [module level documentation](self) for more information.
 /// 
 /// note that this type only implements [`crate::Random`] if `T` is either `u32` or `u64`.
+/// 
+/// the buffered values are owned, so a `Buffer` is [`Send`] or [`Sync`] when
+/// both `T` and `R` are.
+/// 
+/// ```
+/// # use prrng::XorShift64;
+/// # extern crate std;
+/// use prrng::Random;
+/// let mut rng = XorShift64::new(1).random_into_buffer::<u64, 64>();
+/// 
+/// let x = std::thread::spawn(move || rng.get()).join().unwrap();
+/// ```
 #[derive(Clone)]
 pub struct Buffer<T: crate::FromRandom, const N: usize, R: crate::Random> {
 	inner: R,
@@ -302,8 +314,31 @@ impl<const N: usize, R: crate::Random> crate::RandomImpl for Buffer8<N, R> {
 			*i = self.get();
 		}
 	}
+
+	fn random_bytes_uninit<'a>(&mut self, dst: &'a mut [core::mem::MaybeUninit<u8>]) -> &'a mut [u8] {
+		for i in dst.iter_mut() {
+			i.write(self.get());
+		}
+		unsafe {
+			// safety:
+			// every byte of `dst` was written above, and `MaybeUninit<u8>`
+			// has the same layout as `u8`.
+			&mut *(dst as *mut [core::mem::MaybeUninit<u8>] as *mut [u8])
+		}
+	}
+}
+
+// only the unconsumed part of the buffer affects future output
+impl<const N: usize, R: crate::Random + PartialEq> PartialEq for Buffer8<N, R> {
+	fn eq(&self, other: &Self) -> bool {
+		let a = self.buf.get(self.index..).unwrap_or(&[]);
+		let b = other.buf.get(other.index..).unwrap_or(&[]);
+		a == b && self.inner == other.inner
+	}
 }
 
+impl<const N: usize, R: crate::Random + Eq> Eq for Buffer8<N, R> {}
+
 impl<const N: usize, R: crate::Random + core::fmt::Debug> core::fmt::Debug for Buffer8<N, R> {
 	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
 		write!(f, "Buffer8<[u8; {}]>({:?})", N, self.inner)
@@ -0,0 +1,177 @@
+/// utility type for consuming a [`crate::Random`] as a stream of bytes.
+/// 
+/// [`crate::common::bytes_from_u64()`] throws away the unused bytes of the
+/// last value it draws, so many small [`crate::RandomImpl::random_bytes()`]
+/// calls can waste most of the generated bytes. `ByteStream` keeps those
+/// bytes around for the next call instead, so nothing is wasted.
+/// 
+/// the stream is every [`crate::RandomImpl::random_u64()`] of the inner rng,
+/// in little-endian byte order. this is different from calling the inner
+/// rng's `random_bytes()` repeatedly, and `random_u32()` and `random_u64()`
+/// also read from this stream, rather than from the inner rng directly.
+/// 
+/// ```
+/// # use prrng::ByteStream;
+/// # use prrng::XorShift64;
+/// use prrng::RandomImpl;
+/// let mut rng = ByteStream::new(XorShift64::new(1));
+/// 
+/// let mut rgb = [0u8; 3];
+/// rng.random_bytes(&mut rgb);
+/// assert_eq!(rng.buffered(), 5);
+/// 
+/// rng.random_bytes(&mut rgb);
+/// assert_eq!(rng.buffered(), 2);
+/// ```
+#[derive(Clone)]
+pub struct ByteStream<R: crate::Random> {
+	inner: R,
+	buf: [u8; 8],
+	// `buf[index..]` has not been used yet
+	index: u8,
+}
+
+impl<R: crate::Random> ByteStream<R> {
+	/// construct a new `ByteStream`, with nothing buffered.
+	#[inline]
+	pub const fn new(inner: R) -> Self {
+		Self {
+			inner,
+			buf: [0; 8],
+			index: 8,
+		}
+	}
+
+	/// consume `self`, returning the inner rng.
+	/// 
+	/// any buffered bytes are lost.
+	#[inline]
+	pub fn unwrap(self) -> R {
+		self.inner
+	}
+
+	/// how many bytes are buffered, and can be read without drawing from
+	/// the inner rng.
+	#[inline]
+	pub fn buffered(&self) -> usize {
+		8 - self.index as usize
+	}
+
+	/// returns the next byte of the stream.
+	/// 
+	/// this shadows [`crate::Random::random_u8()`], which would use up 4
+	/// bytes of the stream.
+	#[inline]
+	pub fn random_u8(&mut self) -> u8 {
+		let mut x = [0];
+		crate::RandomImpl::random_bytes(self, &mut x);
+		x[0]
+	}
+}
+
+impl<R: crate::Random> crate::RandomImpl for ByteStream<R> {
+	#[inline]
+	fn random_u64(&mut self) -> u64 {
+		if self.index == 8 {
+			return self.inner.random_u64();
+		}
+		let mut x = [0; 8];
+		self.random_bytes(&mut x);
+		u64::from_le_bytes(x)
+	}
+
+	#[inline]
+	fn random_u32(&mut self) -> u32 {
+		let mut x = [0; 4];
+		self.random_bytes(&mut x);
+		u32::from_le_bytes(x)
+	}
+
+	fn random_bytes(&mut self, dst: &mut [u8]) {
+		// use up what is buffered
+		let len = self.buffered().min(dst.len());
+		let (head, dst) = dst.split_at_mut(len);
+		let start = self.index as usize;
+		head.copy_from_slice(&self.buf[start..start + len]);
+		self.index += len as u8;
+
+		// whole values
+		let (chunks, extra) = dst.as_chunks_mut::<8>();
+		for chunk in chunks {
+			*chunk = self.inner.random_u64().to_le_bytes();
+		}
+
+		// buffer the rest of the last value
+		if !extra.is_empty() {
+			self.buf = self.inner.random_u64().to_le_bytes();
+			extra.copy_from_slice(&self.buf[..extra.len()]);
+			self.index = extra.len() as u8;
+		}
+	}
+}
+
+impl<R: crate::Random + core::fmt::Debug> core::fmt::Debug for ByteStream<R> {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		write!(f, "ByteStream({:?}, buffered: {})", self.inner, self.buffered())
+	}
+}
+
+
+#[cfg(test)]
+mod test {
+	use crate::{ByteStream, Counting, RandomImpl, XorShift64};
+
+	#[test]
+	fn test_contiguous() {
+		let mut expect = [0u8; 300];
+		crate::common::bytes_from_u64(&mut XorShift64::new(1), &mut expect);
+
+		let mut rng = ByteStream::new(XorShift64::new(1));
+		let mut bytes = [0u8; 300];
+		for chunk in bytes.chunks_mut(3) {
+			rng.random_bytes(chunk);
+		}
+		assert_eq!(bytes, expect);
+
+		// mixed sizes
+		let mut rng = ByteStream::new(XorShift64::new(1));
+		let mut bytes = [0u8; 300];
+		let mut rest = &mut bytes[..];
+		for len in [1, 0, 7, 8, 9, 16, 2, 30, 100].into_iter().cycle() {
+			let len = len.min(rest.len());
+			let (head, tail) = rest.split_at_mut(len);
+			rng.random_bytes(head);
+			rest = tail;
+			if rest.is_empty() {
+				break;
+			}
+		}
+		assert_eq!(bytes, expect);
+
+		let mut rng = ByteStream::new(XorShift64::new(1));
+		assert_eq!(rng.random_u8(), expect[0]);
+		assert_eq!(rng.random_u32(), u32::from_le_bytes(expect[1..5].try_into().unwrap()));
+		assert_eq!(rng.random_u64(), u64::from_le_bytes(expect[5..13].try_into().unwrap()));
+		assert_eq!(rng.buffered(), 3);
+	}
+
+	#[test]
+	fn test_draws() {
+		let mut rng = ByteStream::new(Counting::new(XorShift64::new(1)));
+
+		for _ in 0..100 {
+			rng.random_bytes(&mut [0; 3]);
+		}
+		let inner = rng.clone().unwrap();
+		assert_eq!(inner.u64_calls(), 38);
+		assert_eq!(inner.u32_calls(), 0);
+		assert_eq!(rng.buffered(), 4);
+
+		// aligned reads pass straight through
+		let mut rng = ByteStream::new(Counting::new(XorShift64::new(1)));
+		rng.random_u64();
+		rng.random_u32();
+		rng.random_u32();
+		assert_eq!(rng.unwrap().u64_calls(), 2);
+	}
+}
@@ -0,0 +1,145 @@
+/// `a-z`, `A-Z` and `0-9`.
+pub const ALPHANUMERIC: [char; 62] = [
+	'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm',
+	'n', 'o', 'p', 'q', 'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z',
+	'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'I', 'J', 'K', 'L', 'M',
+	'N', 'O', 'P', 'Q', 'R', 'S', 'T', 'U', 'V', 'W', 'X', 'Y', 'Z',
+	'0', '1', '2', '3', '4', '5', '6', '7', '8', '9',
+];
+
+/// `0-9` and `a-f`.
+pub const HEX: [char; 16] = [
+	'0', '1', '2', '3', '4', '5', '6', '7', '8', '9',
+	'a', 'b', 'c', 'd', 'e', 'f',
+];
+
+/// infinite iterator of `char`s, uniformly selected from a charset.
+/// 
+/// see [`crate::Random::random_chars_alphanumeric()`],
+/// [`crate::Random::random_chars_hex()`] and
+/// [`crate::Random::random_chars_from()`].
+/// 
+/// ```
+/// # use prrng::XorShift64;
+/// use prrng::Random;
+/// let mut rng = XorShift64::new(1);
+/// 
+/// let mut token = [0u8; 16];
+/// for (o, c) in token.iter_mut().zip(rng.random_chars_alphanumeric()) {
+///     *o = c as u8;
+/// }
+/// 
+/// let token = core::str::from_utf8(&token).unwrap();
+/// assert!(token.chars().all(|c| c.is_ascii_alphanumeric()));
+/// ```
+/// 
+/// a `&mut dyn Random` can be used by upcasting it:
+/// 
+/// ```
+/// # use prrng::{Chars, XorShift64};
+/// use prrng::{Random, RandomImpl};
+/// let object: &mut dyn Random = &mut XorShift64::new(1);
+/// 
+/// let mut chars = Chars::hex(object as &mut dyn RandomImpl);
+/// assert!(chars.next().unwrap().is_ascii_hexdigit());
+/// ```
+#[derive(Clone)]
+pub struct Chars<'a, R: crate::Random> {
+	inner: R,
+	charset: &'a [char],
+}
+
+impl<R: crate::Random> Chars<'static, R> {
+	/// construct a new `Chars` over [`ALPHANUMERIC`].
+	#[inline]
+	pub const fn alphanumeric(inner: R) -> Self {
+		Self::new(inner, &ALPHANUMERIC)
+	}
+
+	/// construct a new `Chars` over [`HEX`].
+	#[inline]
+	pub const fn hex(inner: R) -> Self {
+		Self::new(inner, &HEX)
+	}
+}
+
+impl<'a, R: crate::Random> Chars<'a, R> {
+	/// construct a new `Chars` over `charset`.
+	/// 
+	/// ## panics
+	/// 
+	/// panics if `charset` is empty.
+	#[inline]
+	pub const fn new(inner: R, charset: &'a [char]) -> Self {
+		assert!(!charset.is_empty(), "`charset` must not be empty");
+		Self {
+			inner,
+			charset,
+		}
+	}
+
+	/// the charset being selected from.
+	#[inline]
+	pub const fn charset(&self) -> &'a [char] {
+		self.charset
+	}
+
+	/// consume `self`, returning the inner rng.
+	#[inline]
+	pub fn unwrap(self) -> R {
+		self.inner
+	}
+}
+
+impl<R: crate::Random> Iterator for Chars<'_, R> {
+	type Item = char;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		let i = crate::common::bounded_u32(&mut self.inner, self.charset.len() as u32);
+		Some(self.charset[i as usize])
+	}
+}
+
+impl<R: crate::Random + core::fmt::Debug> core::fmt::Debug for Chars<'_, R> {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		write!(f, "Chars<{}>({:?})", self.charset.len(), self.inner)
+	}
+}
+
+
+#[cfg(test)]
+mod test {
+	use crate::{Chars, Random, XorShift64};
+
+	#[test]
+	fn test_members() {
+		let mut rng = XorShift64::new(1);
+
+		assert!(rng.random_chars_alphanumeric().take(1000).all(|c| c.is_ascii_alphanumeric()));
+		assert!(rng.random_chars_hex().take(1000).all(|c| matches!(c, '0'..='9' | 'a'..='f')));
+
+		let charset = ['a', 'ß', '🦀'];
+		let mut seen = [false; 3];
+		for c in rng.random_chars_from(&charset).take(1000) {
+			let i = charset.iter().position(|&x| x == c).unwrap();
+			seen[i] = true;
+		}
+		assert_eq!(seen, [true; 3]);
+
+		assert!(rng.random_chars_from(&['x']).take(100).all(|c| c == 'x'));
+	}
+
+	#[test]
+	fn test_determinism() {
+		let a = Chars::alphanumeric(XorShift64::new(7));
+		let b = Chars::alphanumeric(XorShift64::new(7));
+		assert!(a.take(256).eq(b.take(256)));
+	}
+
+	#[test]
+	#[should_panic = "`charset` must not be empty"]
+	fn test_empty() {
+		let _ = Chars::new(XorShift64::new(1), &[]);
+	}
+}
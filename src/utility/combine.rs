@@ -0,0 +1,130 @@
+
+/// utility type for combining two [`crate::Random`] types.
+/// 
+/// every value returned by `Combine` is the xor of a value from both inner
+/// rngs. this is a common trick to mask weaknesses in either generator, as
+/// long as the two are unrelated.
+/// 
+/// note that combining generators does not make a cryptographically secure
+/// rng. combining two predictable generators simply results in a different
+/// predictable generator.
+/// 
+/// ```
+/// # use prrng::XorShift64;
+/// # use prrng::SplitMix64;
+/// use prrng::{Random, RandomImpl};
+/// 
+/// let mut a = XorShift64::new(1);
+/// let mut b = SplitMix64::new(1);
+/// 
+/// let mut rng = a.clone().random_combine(b.clone());
+/// 
+/// assert_eq!(rng.random_u64(), a.get() ^ b.get());
+/// assert_eq!(rng.random_u64(), a.get() ^ b.get());
+/// ```
+#[derive(Clone)]
+pub struct Combine<A: crate::Random, B: crate::Random> {
+	a: A,
+	b: B,
+}
+
+impl<A: crate::Random, B: crate::Random> Combine<A, B> {
+	/// construct a new `Combine`.
+	#[inline]
+	pub const fn new(a: A, b: B) -> Self {
+		Self {
+			a,
+			b,
+		}
+	}
+
+	/// consume `self`, returning both inner rngs.
+	#[inline]
+	pub fn unwrap(self) -> (A, B) {
+		(self.a, self.b)
+	}
+}
+
+impl<A: crate::Random, B: crate::Random> crate::RandomImpl for Combine<A, B> {
+	#[inline]
+	fn random_u64(&mut self) -> u64 {
+		self.a.random_u64() ^ self.b.random_u64()
+	}
+
+	#[inline]
+	fn random_u32(&mut self) -> u32 {
+		self.a.random_u32() ^ self.b.random_u32()
+	}
+
+	/// fills `dst` from the first rng, then xors it with bytes from the second.
+	/// 
+	/// the second rng is asked for bytes in chunks of 64, so its byte stream is
+	/// identical to a single call as long as it serializes whole words.
+	fn random_bytes(&mut self, dst: &mut [u8]) {
+		self.a.random_bytes(dst);
+
+		let mut tmp = [0u8; 64];
+		for chunk in dst.chunks_mut(tmp.len()) {
+			let tmp = &mut tmp[..chunk.len()];
+			self.b.random_bytes(tmp);
+			for (o, i) in chunk.iter_mut().zip(tmp.iter()) {
+				*o ^= *i;
+			}
+		}
+	}
+}
+
+impl<A, B> core::fmt::Debug for Combine<A, B>
+where
+	A: crate::Random + core::fmt::Debug,
+	B: crate::Random + core::fmt::Debug,
+{
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		write!(f, "Combine({:?}, {:?})", self.a, self.b)
+	}
+}
+
+
+#[cfg(test)]
+mod test {
+	use crate::{Combine, Random, RandomImpl, SplitMix64, XorShift32, XorShift64};
+
+	#[test]
+	fn test_xor() {
+		let mut a = XorShift64::new(7);
+		let mut b = XorShift32::new(7);
+		let mut rng = Combine::new(a, b);
+
+		for _ in 0..16 {
+			assert_eq!(rng.random_u32(), a.random_u32() ^ b.random_u32());
+			assert_eq!(rng.random_u64(), a.random_u64() ^ b.random_u64());
+		}
+
+		let mut buf = [0u8; 203];
+		let mut buf_a = [0u8; 203];
+		let mut buf_b = [0u8; 203];
+		rng.random_bytes(&mut buf);
+		a.random_bytes(&mut buf_a);
+		b.random_bytes(&mut buf_b);
+
+		for i in 0..buf.len() {
+			assert_eq!(buf[i], buf_a[i] ^ buf_b[i]);
+		}
+	}
+
+	#[test]
+	fn test_dyn() {
+		let mut a = XorShift64::new(3);
+		let mut b = SplitMix64::new(3);
+
+		let mut check_a = a;
+		let mut check_b = b;
+
+		let dyn_a: &mut dyn RandomImpl = &mut a;
+		let dyn_b: &mut dyn RandomImpl = &mut b;
+		let mut rng = dyn_a.random_combine(dyn_b);
+
+		let object: &mut dyn RandomImpl = &mut rng;
+		assert_eq!(object.random_u64(), check_a.get() ^ check_b.get());
+	}
+}
@@ -0,0 +1,157 @@
+
+/// utility type for counting [`crate::Random`] calls.
+/// 
+/// `Counting` forwards every call to the inner rng, while keeping track of
+/// how many times each [`crate::RandomImpl`] method was called, and how many
+/// bytes were filled. this is useful for finding out exactly how much
+/// entropy some code consumes.
+/// 
+/// ```
+/// # use prrng::Counting;
+/// # use prrng::XorShift64;
+/// use prrng::Random;
+/// 
+/// let mut rng = Counting::new(XorShift64::new(1));
+/// 
+/// let _: (u8, u64) = rng.random();
+/// let _: [u8; 3] = rng.random();
+/// 
+/// assert_eq!(rng.u32_calls(), 4);
+/// assert_eq!(rng.u64_calls(), 1);
+/// assert_eq!(rng.bytes_filled(), 0);
+/// ```
+#[derive(Clone)]
+pub struct Counting<R: crate::Random> {
+	inner: R,
+	u32_calls: u64,
+	u64_calls: u64,
+	bytes_filled: u64,
+}
+
+impl<R: crate::Random> Counting<R> {
+	/// construct a new `Counting`, with all counts at `0`.
+	#[inline]
+	pub const fn new(inner: R) -> Self {
+		Self {
+			inner,
+			u32_calls: 0,
+			u64_calls: 0,
+			bytes_filled: 0,
+		}
+	}
+
+	/// consume `self`, returning the inner rng.
+	#[inline]
+	pub fn unwrap(self) -> R {
+		self.inner
+	}
+
+	/// how many times [`crate::RandomImpl::random_u32()`] was called.
+	#[inline]
+	pub fn u32_calls(&self) -> u64 {
+		self.u32_calls
+	}
+
+	/// how many times [`crate::RandomImpl::random_u64()`] was called.
+	#[inline]
+	pub fn u64_calls(&self) -> u64 {
+		self.u64_calls
+	}
+
+	/// how many bytes were filled by [`crate::RandomImpl::random_bytes()`].
+	#[inline]
+	pub fn bytes_filled(&self) -> u64 {
+		self.bytes_filled
+	}
+
+	/// set all counts back to `0`.
+	#[inline]
+	pub fn reset_counts(&mut self) {
+		self.u32_calls = 0;
+		self.u64_calls = 0;
+		self.bytes_filled = 0;
+	}
+}
+
+impl<R: crate::Random> crate::RandomImpl for Counting<R> {
+	#[inline]
+	fn random_u64(&mut self) -> u64 {
+		self.u64_calls += 1;
+		self.inner.random_u64()
+	}
+
+	#[inline]
+	fn random_u32(&mut self) -> u32 {
+		self.u32_calls += 1;
+		self.inner.random_u32()
+	}
+
+	#[inline]
+	fn random_bytes(&mut self, dst: &mut [u8]) {
+		self.bytes_filled += dst.len() as u64;
+		self.inner.random_bytes(dst);
+	}
+
+	#[inline]
+	fn random_bytes_uninit<'a>(&mut self, dst: &'a mut [core::mem::MaybeUninit<u8>]) -> &'a mut [u8] {
+		self.bytes_filled += dst.len() as u64;
+		self.inner.random_bytes_uninit(dst)
+	}
+}
+
+impl<R: crate::Random + core::fmt::Debug> core::fmt::Debug for Counting<R> {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		write!(
+			f,
+			"Counting({:?}, u32: {}, u64: {}, bytes: {})",
+			self.inner, self.u32_calls, self.u64_calls, self.bytes_filled,
+		)
+	}
+}
+
+
+#[cfg(test)]
+mod test {
+	use crate::{Counting, Random, RandomImpl, XorShift64};
+
+	#[test]
+	fn test_counts() {
+		let mut raw = XorShift64::new(4);
+		let mut rng = Counting::new(raw);
+
+		let x: (u8, u64) = rng.random();
+		assert_eq!(x, (raw.get() as u8, raw.get()));
+
+		rng.random_f64();
+		rng.random_f32();
+		rng.random_bool();
+
+		let mut buf = [0u8; 13];
+		rng.random_bytes(&mut buf);
+
+		assert_eq!(rng.u32_calls(), 3);
+		assert_eq!(rng.u64_calls(), 2);
+		assert_eq!(rng.bytes_filled(), 13);
+
+		rng.reset_counts();
+		assert_eq!(rng.u32_calls(), 0);
+		assert_eq!(rng.u64_calls(), 0);
+		assert_eq!(rng.bytes_filled(), 0);
+	}
+
+	#[test]
+	fn test_dyn() {
+		let mut rng = Counting::new(XorShift64::new(4));
+
+		{
+			let object: &mut dyn RandomImpl = &mut rng;
+			object.random_u64();
+			object.random_u32();
+			object.random_bytes(&mut [0u8; 3]);
+		}
+
+		assert_eq!(rng.u32_calls(), 1);
+		assert_eq!(rng.u64_calls(), 1);
+		assert_eq!(rng.bytes_filled(), 3);
+	}
+}
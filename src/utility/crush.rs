@@ -5,21 +5,44 @@
 /// that is, given outputs of the algorithm, future or past outputs can be mathematically predicted.
 /// 
 /// `Crush` doesn't help with this. this type wraps a [`crate::Random`] type along with a
-/// [`core::hash::Hasher`] type. all random getters are set to insert a random value
-/// into the hasher `N` times, afterwards returning the hasher's `finish()` value.
+/// [`core::hash::BuildHasher`] type. every value is produced by building a fresh hasher,
+/// writing a per-instance counter into it, then inserting a random value into the hasher
+/// `N` times, afterwards returning the hasher's `finish()` value.
 /// this may [improve the percieved randomness of an algorithm](https://en.wikipedia.org/wiki/Randomness_extractor).
 /// 
+/// since a new hasher is built for every value, outputs never depend on the
+/// history of previous outputs, only on the counter and the `N` inner values.
+/// the counter starts at `0` and increments for every value, so even a degenerate
+/// inner rng (say, a [`crate::Static`] that always returns the same value) will
+/// not cause `Crush` to return the same value forever.
+/// 
+/// `N` must be at least `1`, as otherwise no random values would be hashed at
+/// all. this is checked at compile time:
+/// 
+/// ```compile_fail
+/// # use prrng::XorShift64;
+/// # use prrng::Crush;
+/// # extern crate std;
+/// use std::hash::{BuildHasherDefault, DefaultHasher};
+/// 
+/// let crush = Crush::<0, _, _>::new(
+///     XorShift64::new(1),
+///     BuildHasherDefault::<DefaultHasher>::default(),
+/// );
+/// ```
+/// 
 /// that being said, this is not a replacement for proper security. do not use this in
 /// lieu of a properly, provably cryptographically secure rng like, say, [`crate::ChaCha`].
 #[derive(Clone)]
 pub struct Crush<const N: usize, R, H>
-where R: crate::Random, H: core::hash::Hasher {
+where R: crate::Random, H: core::hash::BuildHasher {
 	inner: R,
 	hash: H,
+	count: u64,
 }
 
 impl<const N: usize, R, H> Crush<N, R, H>
-where R: crate::Random, H: core::hash::Hasher {
+where R: crate::Random, H: core::hash::BuildHasher {
 	/// construct a new `Crush`.
 	/// 
 	/// ## examples
@@ -29,37 +52,61 @@ where R: crate::Random, H: core::hash::Hasher {
 	/// # use prrng::Crush;
 	/// # extern crate std;
 	/// use prrng::Random;
+	/// use std::hash::{BuildHasherDefault, DefaultHasher};
 	/// 
 	/// let rng = MTwister::new(0);
-	/// let hasher = std::hash::DefaultHasher::new();
+	/// let hasher = BuildHasherDefault::<DefaultHasher>::default();
 	/// 
 	/// let crush = rng.random_into_crush::<4>(hasher);
 	/// ```
 	#[inline]
 	pub const fn new(inner: R, hasher: H) -> Self {
+		const { assert!(N >= 1, "`N` must be at least 1") };
 		Self {
 			inner,
 			hash: hasher,
+			count: 0,
 		}
 	}
 
-	/// consume `self` and return the inner rng and hasher.
+	/// consume `self` and return the inner rng and hasher builder.
 	#[inline]
 	pub fn unwrap(self) -> (R, H) {
 		(self.inner, self.hash)
 	}
 
-	/// write into the hasher `N` times and return the value.
+	/// build a new hasher, write into it `N` times and return the value.
 	pub fn get(&mut self) -> u64 {
+		use core::hash::Hasher;
+
+		let mut hasher = self.hash.build_hasher();
+		hasher.write_u64(self.count);
+		self.count = self.count.wrapping_add(1);
+
 		for _ in 0..N {
-			self.hash.write_u64(self.inner.random_u64());
+			hasher.write_u64(self.inner.random_u64());
 		}
-		self.hash.finish()
+		hasher.finish()
+	}
+}
+
+impl<const N: usize, R, H> Crush<N, R, CloneHasher<H>>
+where R: crate::Random, H: core::hash::Hasher + Clone {
+	/// construct a new `Crush` from a [`core::hash::Hasher`], rather than a
+	/// [`core::hash::BuildHasher`].
+	/// 
+	/// this is the old signature of [`Crush::new()`]. `hasher` is wrapped in a
+	/// [`CloneHasher`], so every value starts from a clone of `hasher`, instead
+	/// of accumulating into it forever.
+	#[deprecated(note = "`Crush` takes a `BuildHasher` now, use `Crush::new()`, with `CloneHasher` if needed")]
+	#[inline]
+	pub const fn from_hasher(inner: R, hasher: H) -> Self {
+		Self::new(inner, CloneHasher(hasher))
 	}
 }
 
 impl<const N: usize, R, H> crate::RandomImpl for Crush<N, R, H>
-where R: crate::Random, H: core::hash::Hasher {
+where R: crate::Random, H: core::hash::BuildHasher {
 	fn random_u64(&mut self) -> u64 {
 		self.get()
 	}
@@ -69,18 +116,319 @@ where R: crate::Random, H: core::hash::Hasher {
 		self.get() as u32
 	}
 
+	/// fills `dst` using a single hasher for the whole call.
+	/// 
+	/// every 8 bytes, the counter and `N` inner values are written into the
+	/// hasher, and its `finish()` value is emitted. the hasher is not rebuilt
+	/// between blocks, so this does not produce the same bytes as
+	/// serializing successive [`Crush::get()`] calls.
 	fn random_bytes(&mut self, dst: &mut [u8]) {
-		crate::common::bytes_from_u64(self, dst);
+		let mut hasher = self.hash.build_hasher();
+
+		for chunk in dst.chunks_mut(8) {
+			let bytes = self.next_block(&mut hasher);
+			chunk.copy_from_slice(&bytes[..chunk.len()]);
+		}
+	}
+
+	// also a single hasher for the whole call, rather than one per staged chunk
+	fn random_bytes_uninit<'a>(&mut self, dst: &'a mut [core::mem::MaybeUninit<u8>]) -> &'a mut [u8] {
+		let mut hasher = self.hash.build_hasher();
+
+		for chunk in dst.chunks_mut(8) {
+			let bytes = self.next_block(&mut hasher);
+			for (o, i) in chunk.iter_mut().zip(bytes.iter()) {
+				o.write(*i);
+			}
+		}
+		unsafe {
+			// safety:
+			// every byte of `dst` was written above, and `MaybeUninit<u8>`
+			// has the same layout as `u8`.
+			&mut *(dst as *mut [core::mem::MaybeUninit<u8>] as *mut [u8])
+		}
+	}
+}
+
+impl<const N: usize, R, H> Crush<N, R, H>
+where R: crate::Random, H: core::hash::BuildHasher {
+	// the next 8 bytes of `random_bytes()`, from its shared `hasher`
+	fn next_block(&mut self, hasher: &mut H::Hasher) -> [u8; 8] {
+		use core::hash::Hasher;
+
+		hasher.write_u64(self.count);
+		self.count = self.count.wrapping_add(1);
+
+		for _ in 0..N {
+			hasher.write_u64(self.inner.random_u64());
+		}
+
+		hasher.finish().to_le_bytes()
 	}
 }
 
 impl<const N: usize, R, H> core::fmt::Debug for Crush<N, R, H>
 where
 	R: crate::Random + core::fmt::Debug,
-	H: core::hash::Hasher + core::fmt::Debug,
+	H: core::hash::BuildHasher + core::fmt::Debug,
 {
 	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
 		write!(f, "Crush{}({:?}, {:?})", N, self.inner, self.hash)
 	}
 }
 
+
+/// a [`core::hash::BuildHasher`] that builds clones of a [`core::hash::Hasher`].
+/// 
+/// [`Crush`] and [`Crush32`] take a `BuildHasher`. this adapts a plain `Hasher`
+/// to one, where every built hasher starts from the state `self.0` is in.
+/// 
+/// ## examples
+/// 
+/// ```
+/// # use prrng::XorShift64;
+/// # use prrng::{CloneHasher, Crush};
+/// # extern crate std;
+/// use std::hash::DefaultHasher;
+/// 
+/// let crush = Crush::<4, _, _>::new(XorShift64::new(1), CloneHasher(DefaultHasher::new()));
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct CloneHasher<H>(pub H)
+where H: core::hash::Hasher + Clone;
+
+impl<H> core::hash::BuildHasher for CloneHasher<H>
+where H: core::hash::Hasher + Clone {
+	type Hasher = H;
+
+	#[inline]
+	fn build_hasher(&self) -> H {
+		self.0.clone()
+	}
+}
+
+
+/// 32bit variant of [`Crush`].
+/// 
+/// `Crush` always pulls `u64` values from the inner rng. for generators
+/// that natively produce `u32` values (such as [`crate::XorShift32`] or
+/// [`crate::Pcg32`]), every one of those pulls costs two inner draws.
+/// `Crush32` instead writes `N` values from [`crate::RandomImpl::random_u32()`]
+/// into the hasher per output, and folds the hasher's 64bit `finish()` into
+/// a `u32`.
+/// 
+/// as a consequence, every `u32` from `Crush32` is derived from `N * 32` bits
+/// of inner output, whereas every `u64` from `Crush` is derived from `N * 64`
+/// bits. a `u64` from `Crush32` is composed of two separate hashes.
+/// 
+/// like `Crush`, `N` must be at least `1`, and a counter is mixed into every value.
+#[derive(Clone)]
+pub struct Crush32<const N: usize, R, H>
+where R: crate::Random, H: core::hash::BuildHasher {
+	inner: R,
+	hash: H,
+	count: u64,
+}
+
+impl<const N: usize, R, H> Crush32<N, R, H>
+where R: crate::Random, H: core::hash::BuildHasher {
+	/// construct a new `Crush32`.
+	/// 
+	/// ## examples
+	/// 
+	/// ```
+	/// # use prrng::XorShift32;
+	/// # extern crate std;
+	/// use prrng::Random;
+	/// use std::hash::{BuildHasherDefault, DefaultHasher};
+	/// 
+	/// let rng = XorShift32::new(1);
+	/// let hasher = BuildHasherDefault::<DefaultHasher>::default();
+	/// 
+	/// let crush = rng.random_into_crush32::<4>(hasher);
+	/// ```
+	#[inline]
+	pub const fn new(inner: R, hasher: H) -> Self {
+		const { assert!(N >= 1, "`N` must be at least 1") };
+		Self {
+			inner,
+			hash: hasher,
+			count: 0,
+		}
+	}
+
+	/// consume `self` and return the inner rng and hasher builder.
+	#[inline]
+	pub fn unwrap(self) -> (R, H) {
+		(self.inner, self.hash)
+	}
+
+	/// build a new hasher, write into it `N` times and return the folded value.
+	pub fn get(&mut self) -> u32 {
+		use core::hash::Hasher;
+
+		let mut hasher = self.hash.build_hasher();
+		hasher.write_u64(self.count);
+		self.count = self.count.wrapping_add(1);
+
+		for _ in 0..N {
+			hasher.write_u32(self.inner.random_u32());
+		}
+
+		let x = hasher.finish();
+		(x >> 32) as u32 ^ x as u32
+	}
+}
+
+impl<const N: usize, R, H> crate::RandomImpl for Crush32<N, R, H>
+where R: crate::Random, H: core::hash::BuildHasher {
+	#[inline]
+	fn random_u64(&mut self) -> u64 {
+		crate::common::u32_compose_u64(self.get(), self.get())
+	}
+
+	fn random_u32(&mut self) -> u32 {
+		self.get()
+	}
+
+	fn random_bytes(&mut self, dst: &mut [u8]) {
+		crate::common::bytes_from_u32(self, dst);
+	}
+}
+
+impl<const N: usize, R, H> core::fmt::Debug for Crush32<N, R, H>
+where
+	R: crate::Random + core::fmt::Debug,
+	H: core::hash::BuildHasher + core::fmt::Debug,
+{
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		write!(f, "Crush32_{}({:?}, {:?})", N, self.inner, self.hash)
+	}
+}
+
+
+#[cfg(test)]
+mod test {
+	extern crate std;
+
+	use std::hash::{BuildHasherDefault, DefaultHasher};
+
+	use crate::{Crush, Crush32, RandomImpl, XorShift32, XorShift64};
+
+	type Hash = BuildHasherDefault<DefaultHasher>;
+
+	#[test]
+	fn test_history() {
+		let mut a = Crush::<4, _, _>::new(XorShift64::new(1), Hash::default());
+
+		for _ in 0..7 {
+			a.get();
+		}
+
+		// a fresh hasher with the same counter and inner state must agree,
+		// regardless of what `a` has hashed before.
+		let mut b = Crush::<4, _, _> {
+			inner: a.inner,
+			hash: Hash::default(),
+			count: a.count,
+		};
+
+		for _ in 0..8 {
+			assert_eq!(a.get(), b.get());
+		}
+	}
+
+	#[test]
+	fn test_bytes() {
+		let mut rng = Crush::<2, _, _>::new(crate::Static::new(|| 0.5), Hash::default());
+
+		for len in [0, 1, 7, 8, 9, 63] {
+			let mut buf = [0u8; 64];
+			rng.random_bytes(&mut buf[..len]);
+			assert!(buf[len..].iter().all(|x| *x == 0));
+		}
+
+		// constant inner values should never produce repeated blocks
+		let mut buf = [0u8; 8 * 64];
+		rng.random_bytes(&mut buf);
+		let (blocks, _) = buf.as_chunks::<8>();
+		for (i, a) in blocks.iter().enumerate() {
+			for b in &blocks[i + 1..] {
+				assert_ne!(a, b);
+			}
+		}
+
+		let mut a = Crush::<2, _, _>::new(XorShift64::new(3), Hash::default());
+		let mut b = a.clone();
+		let mut buf_a = [0u8; 100];
+		let mut buf_b = [0u8; 100];
+		a.random_bytes(&mut buf_a);
+		b.random_bytes(&mut buf_b);
+		assert_eq!(buf_a, buf_b);
+	}
+
+	#[test]
+	fn test_determinism() {
+		let mut a = Crush::<2, _, _>::new(XorShift64::new(5), Hash::default());
+		let mut b = Crush::<2, _, _>::new(XorShift64::new(5), Hash::default());
+		let mut c = Crush::<2, _, _>::new(XorShift64::new(6), Hash::default());
+
+		for _ in 0..16 {
+			let x = a.get();
+			assert_eq!(x, b.get());
+			assert_ne!(x, c.get());
+		}
+	}
+
+	#[test]
+	fn test_width() {
+		use crate::Counting;
+
+		let mut a = Crush::<4, _, _>::new(Counting::new(XorShift32::new(1)), Hash::default());
+		let mut b = Crush32::<4, _, _>::new(Counting::new(XorShift32::new(1)), Hash::default());
+
+		for _ in 0..10 {
+			a.random_u32();
+			b.random_u32();
+		}
+
+		// every `u64` from `XorShift32` is two inner draws
+		assert_eq!(a.inner.u64_calls() * 2, 80);
+		assert_eq!(b.inner.u32_calls(), 40);
+
+		let mut c = Crush32::<4, _, _>::new(XorShift32::new(1), Hash::default());
+		let mut d = c.clone();
+		for _ in 0..16 {
+			assert_eq!(c.random_u64(), d.random_u64());
+		}
+	}
+
+	#[test]
+	#[allow(deprecated)]
+	fn test_from_hasher() {
+		let mut a = Crush::<2, _, _>::from_hasher(XorShift64::new(5), DefaultHasher::new());
+		let mut b = Crush::<2, _, _>::new(XorShift64::new(5), Hash::default());
+
+		for _ in 0..16 {
+			assert_eq!(a.get(), b.get());
+		}
+	}
+
+	#[test]
+	fn test_constant() {
+		let mut a = Crush::<1, _, _>::new(crate::Static::new(|| 0.25), Hash::default());
+		let mut b = Crush32::<1, _, _>::new(crate::Static::new(|| 0.25), Hash::default());
+
+		let mut last_a = a.get();
+		let mut last_b = b.get();
+		for _ in 0..64 {
+			let x = a.get();
+			assert_ne!(x, last_a);
+			last_a = x;
+
+			let x = b.get();
+			assert_ne!(x, last_b);
+			last_b = x;
+		}
+	}
+}
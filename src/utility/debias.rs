@@ -0,0 +1,181 @@
+
+/// [von neumann extractor](https://en.wikipedia.org/wiki/Randomness_extractor#Von_Neumann_extractor)
+/// for biased sources.
+/// 
+/// some sources of entropy (say, a ring oscillator or the lowest bits of a
+/// jittery adc) emit independent bits that are biased towards either `0` or `1`.
+/// `Debias` reads pairs of bits from the inner rng, emitting `0` for `(0, 1)`,
+/// `1` for `(1, 0)`, and discarding `(0, 0)` and `(1, 1)`. as long as the
+/// input bits are independent, the output bits are unbiased.
+/// 
+/// every output is assembled bit-by-bit, using an internal cache of
+/// [`crate::RandomImpl::random_u64()`] values. this means the throughput of
+/// `Debias` depends entirely on the input: an unbiased source will require
+/// about 4 input bits per output bit, while a heavily biased source may
+/// require far more. in the worst case (say, a source that only ever emits `1`),
+/// the [`crate::RandomImpl`] methods will never return.
+/// see [`Self::try_random_u32()`] and [`Self::try_random_u64()`] for versions
+/// that give up after a certain amount of input.
+/// 
+/// ```
+/// # use prrng::Debias;
+/// # use prrng::XorShift64;
+/// let mut rng = Debias::new(XorShift64::new(1));
+/// 
+/// assert!(matches!(rng.try_random_u32(16), Some(_)));
+/// ```
+#[derive(Clone)]
+pub struct Debias<R: crate::Random> {
+	inner: R,
+	cache: u64,
+	// pairs of bits remaining in `cache`
+	pairs: u32,
+}
+
+impl<R: crate::Random> Debias<R> {
+	/// construct a new `Debias`.
+	#[inline]
+	pub const fn new(inner: R) -> Self {
+		Self {
+			inner,
+			cache: 0,
+			pairs: 0,
+		}
+	}
+
+	/// consume `self`, returning the inner rng.
+	#[inline]
+	pub fn unwrap(self) -> R {
+		self.inner
+	}
+
+	/// assemble `bits` debiased bits. if `budget` is `Some`, only that many
+	/// `u64` values may be pulled from the inner rng.
+	fn take(&mut self, bits: u32, mut budget: Option<usize>) -> Option<u64> {
+		let mut out = 0;
+		let mut n = 0;
+
+		while n < bits {
+			if self.pairs == 0 {
+				if let Some(budget) = &mut budget {
+					if *budget == 0 {
+						return None;
+					}
+					*budget -= 1;
+				}
+				self.cache = self.inner.random_u64();
+				self.pairs = 32;
+			}
+
+			let pair = self.cache & 0b11;
+			self.cache >>= 2;
+			self.pairs -= 1;
+
+			// `0b01` is `(1, 0)` and `0b10` is `(0, 1)`
+			if pair == 0b01 || pair == 0b10 {
+				out |= (pair & 1) << n;
+				n += 1;
+			}
+		}
+
+		Some(out)
+	}
+
+	/// returns a new `u32`, pulling at most `max_input_words` values from
+	/// the inner rng. if this isn't enough to assemble a value, this returns
+	/// `None`, and any bits assembled so far are discarded.
+	/// 
+	/// ```
+	/// # use prrng::Debias;
+	/// # use prrng::Static;
+	/// // a source that only ever emits `1` bits
+	/// let mut rng = Debias::new(Static::new(|| 1.0));
+	/// 
+	/// assert_eq!(rng.try_random_u32(64), None);
+	/// ```
+	#[inline]
+	pub fn try_random_u32(&mut self, max_input_words: usize) -> Option<u32> {
+		self.take(32, Some(max_input_words)).map(|x| x as u32)
+	}
+
+	/// returns a new `u64`, pulling at most `max_input_words` values from
+	/// the inner rng. if this isn't enough to assemble a value, this returns
+	/// `None`, and any bits assembled so far are discarded.
+	#[inline]
+	pub fn try_random_u64(&mut self, max_input_words: usize) -> Option<u64> {
+		self.take(64, Some(max_input_words))
+	}
+}
+
+impl<R: crate::Random> crate::RandomImpl for Debias<R> {
+	#[inline]
+	fn random_u64(&mut self) -> u64 {
+		// unwrap: `take()` cannot fail without a budget
+		self.take(64, None).unwrap()
+	}
+
+	#[inline]
+	fn random_u32(&mut self) -> u32 {
+		// unwrap: `take()` cannot fail without a budget
+		self.take(32, None).unwrap() as u32
+	}
+
+	fn random_bytes(&mut self, dst: &mut [u8]) {
+		crate::common::bytes_from_u64(self, dst);
+	}
+}
+
+impl<R: crate::Random + core::fmt::Debug> core::fmt::Debug for Debias<R> {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		write!(f, "Debias({:?})", self.inner)
+	}
+}
+
+
+#[cfg(test)]
+mod test {
+	use crate::{Debias, RandomImpl, XorShift64};
+
+	#[test]
+	fn test_bias() {
+		// every bit is `1` with a probability of 90%
+		struct Biased(XorShift64);
+
+		impl RandomImpl for Biased {
+			fn random_u64(&mut self) -> u64 {
+				let mut x = 0;
+				for i in 0..64 {
+					if self.0.get() < u64::MAX / 10 * 9 {
+						x |= 1 << i;
+					}
+				}
+				x
+			}
+			fn random_u32(&mut self) -> u32 {
+				self.random_u64() as u32
+			}
+			fn random_bytes(&mut self, dst: &mut [u8]) {
+				crate::common::bytes_from_u64(self, dst);
+			}
+		}
+
+		let mut raw = Biased(XorShift64::new(1));
+		let ones = (0..256).map(|_| raw.random_u64().count_ones()).sum::<u32>();
+		assert!(ones > 256 * 64 * 85 / 100);
+
+		let mut rng = Debias::new(Biased(XorShift64::new(1)));
+		let ones = (0..256).map(|_| rng.random_u64().count_ones()).sum::<u32>();
+		assert!(ones > 256 * 64 * 48 / 100);
+		assert!(ones < 256 * 64 * 52 / 100);
+	}
+
+	#[test]
+	fn test_budget() {
+		let mut rng = Debias::new(crate::Static::new(|| 1.0));
+		assert_eq!(rng.try_random_u32(1000), None);
+		assert_eq!(rng.try_random_u64(0), None);
+
+		let mut rng = Debias::new(XorShift64::new(1));
+		assert!(rng.try_random_u64(1000).is_some());
+	}
+}
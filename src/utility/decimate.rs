@@ -0,0 +1,112 @@
+
+/// utility type for discarding values of a [`crate::Random`].
+/// 
+/// every value returned by `Decimate` is produced by drawing and discarding
+/// `K` values from the inner rng, then returning the next one. this is sometimes
+/// specified by older algorithms (for example, "use every 5th output"), and
+/// can be used to decorrelate the outputs of weak generators.
+/// 
+/// [`crate::RandomImpl::random_u64()`] and [`crate::RandomImpl::random_u32()`]
+/// each discard values of their own width. [`crate::RandomImpl::random_bytes()`]
+/// is the little-endian serialization of the decimated `u64` stream.
+/// 
+/// ```
+/// # use prrng::XorShift64;
+/// use prrng::{Random, RandomImpl};
+/// 
+/// let mut raw = XorShift64::new(1);
+/// let mut rng = raw.clone().random_into_decimate::<2>();
+/// 
+/// raw.get();
+/// raw.get();
+/// assert_eq!(rng.random_u64(), raw.get());
+/// 
+/// raw.get();
+/// raw.get();
+/// assert_eq!(rng.random_u64(), raw.get());
+/// ```
+#[derive(Clone)]
+pub struct Decimate<const K: usize, R: crate::Random> {
+	inner: R,
+}
+
+impl<const K: usize, R: crate::Random> Decimate<K, R> {
+	/// construct a new `Decimate`.
+	#[inline]
+	pub const fn new(inner: R) -> Self {
+		Self {
+			inner,
+		}
+	}
+
+	/// consume `self`, returning the inner rng.
+	#[inline]
+	pub fn unwrap(self) -> R {
+		self.inner
+	}
+}
+
+impl<const K: usize, R: crate::Random> crate::RandomImpl for Decimate<K, R> {
+	#[inline]
+	fn random_u64(&mut self) -> u64 {
+		for _ in 0..K {
+			self.inner.random_u64();
+		}
+		self.inner.random_u64()
+	}
+
+	#[inline]
+	fn random_u32(&mut self) -> u32 {
+		for _ in 0..K {
+			self.inner.random_u32();
+		}
+		self.inner.random_u32()
+	}
+
+	fn random_bytes(&mut self, dst: &mut [u8]) {
+		crate::common::bytes_from_u64(self, dst);
+	}
+}
+
+impl<const K: usize, R: crate::Random + core::fmt::Debug> core::fmt::Debug for Decimate<K, R> {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		write!(f, "Decimate{}({:?})", K, self.inner)
+	}
+}
+
+
+#[cfg(test)]
+mod test {
+	use crate::{Decimate, RandomImpl, XorShift64};
+
+	#[test]
+	fn test_decimate() {
+		let mut raw = XorShift64::new(1);
+		let mut rng = Decimate::<0, _>::new(raw);
+
+		for _ in 0..16 {
+			assert_eq!(rng.random_u64(), raw.get());
+			assert_eq!(rng.random_u32(), raw.get() as u32);
+		}
+
+		let mut raw = XorShift64::new(1);
+		let mut rng = Decimate::<2, _>::new(raw);
+
+		for _ in 0..16 {
+			raw.get();
+			raw.get();
+			assert_eq!(rng.random_u64(), raw.get());
+		}
+
+		let mut buf = [0u8; 12];
+		rng.random_bytes(&mut buf);
+
+		let mut check = [0u8; 12];
+		for chunk in check.chunks_mut(8) {
+			raw.get();
+			raw.get();
+			chunk.copy_from_slice(&raw.get().to_le_bytes()[..chunk.len()]);
+		}
+		assert_eq!(buf, check);
+	}
+}
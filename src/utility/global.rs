@@ -0,0 +1,198 @@
+use core::cell::UnsafeCell;
+use core::sync::atomic::{AtomicBool, Ordering};
+
+/// utility type for sharing a [`crate::Random`] through a `static`.
+/// 
+/// `GlobalRng` puts a lock around an rng, so it can be used from anywhere
+/// without `std`. [`Self::new()`] is a `const fn`, so it can be used to
+/// initialize a `static` directly. `&GlobalRng` implements
+/// [`crate::Random`], locking for each value drawn, so it can be passed
+/// anywhere a `Random` is expected.
+/// 
+/// ```
+/// use prrng::{GlobalRng, Random, XorShift64};
+/// 
+/// static RNG: GlobalRng<XorShift64> = GlobalRng::new(XorShift64::new(1));
+/// 
+/// fn roll() -> u32 {
+///     (&RNG).random_u32_bound(6) + 1
+/// }
+/// 
+/// // reseed once at boot, once a real seed is available
+/// RNG.with(|rng| *rng = XorShift64::new(0x5eed));
+/// 
+/// let x = roll();
+/// let [a, b] = RNG.with(|rng| [rng.get(), rng.get()]);
+/// ```
+/// 
+/// ## interrupts
+/// 
+/// by default, the lock is a spin lock. this is fine between threads, but
+/// an interrupt handler that uses a `GlobalRng` which was locked by the
+/// code it interrupted will spin forever. with the `critical-section`
+/// feature, the lock is taken inside a
+/// [critical section](https://docs.rs/critical-section) instead, which
+/// disables interrupts (or whatever the platform's implementation does)
+/// for as long as the rng is in use, so it is safe to use from interrupts.
+/// keep closures passed to [`Self::with()`] short either way.
+/// 
+/// calling [`Self::with()`] again from inside its own closure, on the same
+/// `GlobalRng`, spins forever with the spin lock, and panics with the
+/// `critical-section` feature.
+pub struct GlobalRng<R: crate::Random> {
+	locked: AtomicBool,
+	inner: UnsafeCell<R>,
+}
+
+// safety:
+// `inner` is only accessed while `locked` is held
+unsafe impl<R: crate::Random + Send> Sync for GlobalRng<R> {}
+
+impl<R: crate::Random> GlobalRng<R> {
+	/// construct a new, unlocked `GlobalRng`.
+	#[inline]
+	pub const fn new(inner: R) -> Self {
+		Self {
+			locked: AtomicBool::new(false),
+			inner: UnsafeCell::new(inner),
+		}
+	}
+
+	/// consume `self`, returning the inner rng.
+	#[inline]
+	pub fn unwrap(self) -> R {
+		self.inner.into_inner()
+	}
+
+	/// lock `self`, and call `f` with the inner rng.
+	/// 
+	/// see the [type level documentation](Self) for what happens inside
+	/// interrupts.
+	#[inline]
+	pub fn with<T>(&self, f: impl FnOnce(&mut R) -> T) -> T {
+		#[cfg(feature = "critical-section")]
+		{
+			critical_section::with(|_| {
+				assert!(
+					!self.locked.load(Ordering::Relaxed),
+					"`GlobalRng::with()` called while already in use",
+				);
+				self.locked.store(true, Ordering::Relaxed);
+				let _unlock = Unlock(&self.locked);
+				f(unsafe {
+					// safety:
+					// `locked` is held
+					&mut *self.inner.get()
+				})
+			})
+		}
+
+		#[cfg(not(feature = "critical-section"))]
+		{
+			while self.locked
+				.compare_exchange_weak(false, true, Ordering::Acquire, Ordering::Relaxed)
+				.is_err()
+			{
+				while self.locked.load(Ordering::Relaxed) {
+					core::hint::spin_loop();
+				}
+			}
+			let _unlock = Unlock(&self.locked);
+			f(unsafe {
+				// safety:
+				// `locked` is held
+				&mut *self.inner.get()
+			})
+		}
+	}
+}
+
+// releases the lock, even if `f` panics
+struct Unlock<'a>(&'a AtomicBool);
+
+impl Drop for Unlock<'_> {
+	#[inline]
+	fn drop(&mut self) {
+		self.0.store(false, Ordering::Release);
+	}
+}
+
+impl<R: crate::Random> crate::RandomImpl for &GlobalRng<R> {
+	#[inline]
+	fn random_u64(&mut self) -> u64 {
+		self.with(|rng| rng.random_u64())
+	}
+
+	#[inline]
+	fn random_u32(&mut self) -> u32 {
+		self.with(|rng| rng.random_u32())
+	}
+
+	#[inline]
+	fn random_bytes(&mut self, dst: &mut [u8]) {
+		self.with(|rng| rng.random_bytes(dst));
+	}
+
+	fn random_bytes_uninit<'a>(&mut self, dst: &'a mut [core::mem::MaybeUninit<u8>]) -> &'a mut [u8] {
+		// locked once, rather than for every staged chunk
+		self.with(|rng| rng.random_bytes_uninit(dst))
+	}
+}
+
+impl<R: crate::Random> core::fmt::Debug for GlobalRng<R> {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		// not locking, which could deadlock inside of `with()`
+		write!(f, "GlobalRng")
+	}
+}
+
+
+#[cfg(test)]
+mod test {
+	extern crate std;
+	use std::vec::Vec;
+
+	use crate::{GlobalRng, RandomImpl, SplitMix64, XorShift256ss};
+
+	fn hammer<R: crate::Random + Clone + Send>(rng: R) {
+		const THREADS: usize = 8;
+		const DRAWS: usize = 5000;
+
+		let mut expect = rng.clone();
+		let global = GlobalRng::new(rng);
+
+		let mut seen: Vec<u64> = std::thread::scope(|s| {
+			let handles: Vec<_> = (0..THREADS)
+				.map(|_| s.spawn(|| {
+					let mut rng = &global;
+					(0..DRAWS).map(|_| rng.random_u64()).collect::<Vec<_>>()
+				}))
+				.collect();
+			handles.into_iter().flat_map(|x| x.join().unwrap()).collect()
+		});
+
+		// every value was drawn exactly once, nothing was lost or repeated
+		let mut expect: Vec<u64> = (0..THREADS * DRAWS).map(|_| expect.random_u64()).collect();
+		seen.sort_unstable();
+		expect.sort_unstable();
+		assert_eq!(seen, expect);
+		seen.dedup();
+		assert_eq!(seen.len(), THREADS * DRAWS);
+	}
+
+	#[test]
+	fn test_threads() {
+		hammer(SplitMix64::new(1));
+		hammer(XorShift256ss::new([1, 2, 3, 4]));
+	}
+
+	#[test]
+	fn test_unlock_on_panic() {
+		let global = GlobalRng::new(SplitMix64::new(1));
+		let result = std::panic::catch_unwind(core::panic::AssertUnwindSafe(|| {
+			global.with(|_| panic!());
+		}));
+		assert!(result.is_err());
+		assert_eq!((&global).random_u64(), SplitMix64::new(1).get());
+	}
+}
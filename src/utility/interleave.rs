@@ -0,0 +1,154 @@
+
+/// utility type for alternating between two [`crate::Random`] types.
+/// 
+/// every call to [`crate::RandomImpl::random_u64()`] or
+/// [`crate::RandomImpl::random_u32()`] alternates between the two inner rngs.
+/// by default, even calls are served by `A`, and odd calls by `B`. see
+/// [`Self::swap_phase()`] to change which one goes first.
+/// 
+/// [`crate::RandomImpl::random_bytes()`] alternates every 8 byte block, with
+/// each block filled by one call to the respective rng's `random_bytes()`.
+/// 
+/// ```
+/// # use prrng::Interleave;
+/// # use prrng::XorShift64;
+/// # use prrng::SplitMix64;
+/// use prrng::RandomImpl;
+/// 
+/// let mut a = XorShift64::new(1);
+/// let mut b = SplitMix64::new(1);
+/// 
+/// let mut rng = Interleave::new(a.clone(), b.clone());
+/// 
+/// assert_eq!(rng.random_u64(), a.get());
+/// assert_eq!(rng.random_u64(), b.get());
+/// assert_eq!(rng.random_u64(), a.get());
+/// assert_eq!(rng.random_u64(), b.get());
+/// ```
+#[derive(Clone)]
+pub struct Interleave<A: crate::Random, B: crate::Random> {
+	a: A,
+	b: B,
+	// whether `b` is next
+	odd: bool,
+}
+
+impl<A: crate::Random, B: crate::Random> Interleave<A, B> {
+	/// construct a new `Interleave`, where `a` goes first.
+	#[inline]
+	pub const fn new(a: A, b: B) -> Self {
+		Self {
+			a,
+			b,
+			odd: false,
+		}
+	}
+
+	/// consume `self`, returning both inner rngs.
+	#[inline]
+	pub fn unwrap(self) -> (A, B) {
+		(self.a, self.b)
+	}
+
+	/// swap which rng will be used for the next value.
+	/// 
+	/// ```
+	/// # use prrng::Interleave;
+	/// # use prrng::XorShift64;
+	/// # use prrng::SplitMix64;
+	/// use prrng::RandomImpl;
+	/// 
+	/// let mut a = XorShift64::new(1);
+	/// let mut b = SplitMix64::new(1);
+	/// 
+	/// let mut rng = Interleave::new(a.clone(), b.clone());
+	/// rng.swap_phase();
+	/// 
+	/// assert_eq!(rng.random_u64(), b.get());
+	/// assert_eq!(rng.random_u64(), a.get());
+	/// ```
+	#[inline]
+	pub fn swap_phase(&mut self) {
+		self.odd = !self.odd;
+	}
+
+	#[inline]
+	fn next(&mut self) -> &mut dyn crate::RandomImpl {
+		let odd = self.odd;
+		self.odd = !odd;
+		if odd {
+			&mut self.b
+		} else {
+			&mut self.a
+		}
+	}
+}
+
+impl<A: crate::Random, B: crate::Random> crate::RandomImpl for Interleave<A, B> {
+	#[inline]
+	fn random_u64(&mut self) -> u64 {
+		self.next().random_u64()
+	}
+
+	#[inline]
+	fn random_u32(&mut self) -> u32 {
+		self.next().random_u32()
+	}
+
+	fn random_bytes(&mut self, dst: &mut [u8]) {
+		for chunk in dst.chunks_mut(8) {
+			self.next().random_bytes(chunk);
+		}
+	}
+}
+
+impl<A, B> core::fmt::Debug for Interleave<A, B>
+where
+	A: crate::Random + core::fmt::Debug,
+	B: crate::Random + core::fmt::Debug,
+{
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		write!(f, "Interleave({:?}, {:?})", self.a, self.b)
+	}
+}
+
+
+#[cfg(test)]
+mod test {
+	use crate::{Interleave, RandomImpl, SplitMix64, XorShift32};
+
+	#[test]
+	fn test_interleave() {
+		let mut a = XorShift32::new(9);
+		let mut b = SplitMix64::new(9);
+		let mut rng = Interleave::new(a, b);
+
+		for _ in 0..8 {
+			assert_eq!(rng.random_u32(), a.random_u32());
+			assert_eq!(rng.random_u64(), b.random_u64());
+		}
+
+		let mut buf = [0u8; 20];
+		rng.random_bytes(&mut buf);
+
+		let mut check = [0u8; 20];
+		a.random_bytes(&mut check[0..8]);
+		b.random_bytes(&mut check[8..16]);
+		a.random_bytes(&mut check[16..20]);
+		assert_eq!(buf, check);
+
+		// `a` was used last, so `b` is next
+		assert_eq!(rng.random_u64(), b.random_u64());
+	}
+
+	#[test]
+	fn test_clone() {
+		let mut rng = Interleave::new(XorShift32::new(1), SplitMix64::new(1));
+		rng.random_u32();
+
+		let mut other = rng.clone();
+		for _ in 0..8 {
+			assert_eq!(rng.random_u32(), other.random_u32());
+		}
+	}
+}
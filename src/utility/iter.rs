@@ -18,10 +18,14 @@
 /// ```
 /// 
 /// notably, this type *also* implements `Random`. this likely isn't useful.
+/// 
+/// values of `T` are only ever returned, never stored, so an `Iter` is
+/// [`Send`] or [`Sync`] whenever `R` is, regardless of `T`.
 #[derive(Clone)]
 pub struct Iter<T: crate::FromRandom, R: crate::Random> {
 	inner: R,
-	_marker: core::marker::PhantomData<T>,
+	// `fn() -> T`, as `Iter` produces `T`s rather than owning any
+	_marker: core::marker::PhantomData<fn() -> T>,
 }
 
 impl<T: crate::FromRandom, R: crate::Random> Iter<T, R> {
@@ -65,6 +69,11 @@ impl<T: crate::FromRandom, R: crate::Random> crate::RandomImpl for Iter<T, R> {
 	fn random_bytes(&mut self, dst: &mut [u8]) {
 		self.inner.random_bytes(dst);
 	}
+
+	#[inline]
+	fn random_bytes_uninit<'a>(&mut self, dst: &'a mut [core::mem::MaybeUninit<u8>]) -> &'a mut [u8] {
+		self.inner.random_bytes_uninit(dst)
+	}
 }
 
 impl<T: crate::FromRandom, R: crate::Random + core::fmt::Debug> core::fmt::Debug for Iter<T, R> {
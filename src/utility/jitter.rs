@@ -0,0 +1,199 @@
+/// utility type for collecting entropy from noisy, low quality samples.
+/// 
+/// on targets without a hardware rng, entropy can be harvested from timing
+/// jitter, like the difference between timer values across interrupts, or
+/// the least significant bits of an adc. each sample contains very little
+/// entropy, and is heavily biased, so it can't be used directly.
+/// 
+/// `JitterAccumulator` absorbs every sample into a [`crate::SpongeRng`]
+/// state, and credits it with a fixed amount of entropy, supplied by the
+/// caller. once enough entropy has been credited (see [`Self::ready()`]),
+/// output is squeezed out of that state. feeding more samples afterwards
+/// keeps mixing them in.
+/// 
+/// the credit is the only thing that makes this safe, and is measured in
+/// millibits (thousandths of a bit) per sample. it must be a conservative
+/// estimate of the *worst case* entropy of a sample, not the average: if
+/// every sample might be predictable to within 2 values, credit at most
+/// 1000. when unsure, credit much less.
+/// 
+/// drawing from a `JitterAccumulator` that isn't ready panics. check
+/// [`Self::ready()`] before drawing.
+/// 
+/// ```
+/// # use prrng::JitterAccumulator;
+/// # fn read_timer() -> u32 { 0 }
+/// use prrng::RandomImpl;
+/// 
+/// // an eighth of a bit per sample
+/// let mut pool = JitterAccumulator::new(125);
+/// 
+/// while !pool.ready() {
+///     pool.feed(read_timer());
+/// }
+/// 
+/// let key = pool.random_u64();
+/// ```
+#[derive(Clone, PartialEq, Eq)]
+pub struct JitterAccumulator {
+	sponge: crate::SpongeRng,
+	credit: u32,
+	// total credit, in millibits
+	collected: u64,
+}
+
+impl JitterAccumulator {
+	/// how many bits of entropy must be credited before
+	/// [`Self::ready()`] returns `true`.
+	pub const READY_BITS: u64 = 128;
+
+	/// construct a new, empty `JitterAccumulator`, crediting every sample
+	/// with `credit` millibits of entropy.
+	#[inline]
+	pub const fn new(credit: u32) -> Self {
+		Self {
+			sponge: crate::SpongeRng::new(b"JitterAccumulator"),
+			credit,
+			collected: 0,
+		}
+	}
+
+	/// mix `sample` into the state, and credit it.
+	#[inline]
+	pub const fn feed(&mut self, sample: u32) {
+		self.sponge.absorb(sample.to_le_bytes());
+		self.collected = self.collected.saturating_add(self.credit as u64);
+	}
+
+	/// how many whole bits of entropy have been credited so far.
+	/// 
+	/// drawing output doesn't lower this.
+	#[inline]
+	pub const fn entropy_estimate(&self) -> u64 {
+		self.collected / 1000
+	}
+
+	/// whether at least [`Self::READY_BITS`] bits have been credited, and
+	/// output can be drawn.
+	#[inline]
+	pub const fn ready(&self) -> bool {
+		self.entropy_estimate() >= Self::READY_BITS
+	}
+
+	#[inline]
+	fn check(&self) {
+		assert!(self.ready(), "`JitterAccumulator` is not ready");
+	}
+}
+
+impl crate::RandomImpl for JitterAccumulator {
+	/// ## panics
+	/// 
+	/// panics if `self` isn't [`Self::ready()`].
+	#[inline]
+	fn random_u64(&mut self) -> u64 {
+		self.check();
+		self.sponge.random_u64()
+	}
+
+	/// ## panics
+	/// 
+	/// panics if `self` isn't [`Self::ready()`].
+	#[inline]
+	fn random_u32(&mut self) -> u32 {
+		self.check();
+		self.sponge.random_u32()
+	}
+
+	/// ## panics
+	/// 
+	/// panics if `self` isn't [`Self::ready()`].
+	#[inline]
+	fn random_bytes(&mut self, dst: &mut [u8]) {
+		self.check();
+		self.sponge.random_bytes(dst);
+	}
+}
+
+impl core::fmt::Debug for JitterAccumulator {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		write!(f, "JitterAccumulator(entropy: {})", self.entropy_estimate())
+	}
+}
+
+
+#[cfg(test)]
+mod test {
+	use crate::{JitterAccumulator, RandomImpl};
+
+	// a badly biased "timer": mostly regular, with a little wobble
+	fn scripted(n: u32) -> u32 {
+		1000 * n + (n * n % 7)
+	}
+
+	#[test]
+	fn test_ready() {
+		let mut pool = JitterAccumulator::new(125);
+		for i in 0..1023 {
+			pool.feed(scripted(i));
+		}
+		assert_eq!(pool.entropy_estimate(), 127);
+		assert!(!pool.ready());
+
+		pool.feed(scripted(1023));
+		assert_eq!(pool.entropy_estimate(), 128);
+		assert!(pool.ready());
+
+		// nothing is ever credited
+		let mut pool = JitterAccumulator::new(0);
+		for i in 0..10000 {
+			pool.feed(scripted(i));
+		}
+		assert!(!pool.ready());
+	}
+
+	#[test]
+	fn test_deterministic() {
+		let mut a = JitterAccumulator::new(1000);
+		let mut b = JitterAccumulator::new(1000);
+		for i in 0..128 {
+			a.feed(scripted(i));
+			b.feed(scripted(i));
+		}
+		assert!(a == b);
+		assert_eq!(a.random_u64(), b.random_u64());
+
+		// feeding after drawing still changes the output
+		let mut c = a.clone();
+		a.feed(1);
+		c.feed(2);
+		assert_ne!(a.random_u64(), c.random_u64());
+
+		// so does a single different sample
+		let mut a = JitterAccumulator::new(1000);
+		let mut b = JitterAccumulator::new(1000);
+		for i in 0..128 {
+			a.feed(scripted(i));
+			b.feed(scripted(i) ^ (i == 64) as u32);
+		}
+		assert_ne!(a.random_u64(), b.random_u64());
+	}
+
+	#[test]
+	fn test_quick_check() {
+		let mut pool = JitterAccumulator::new(125);
+		for i in 0..1024 {
+			pool.feed(scripted(i));
+		}
+		let report = crate::stats::quick_check(&mut pool, 1 << 16);
+		assert!(report.passed(0.0001), "{:?}", report);
+	}
+
+	#[test]
+	#[should_panic = "`JitterAccumulator` is not ready"]
+	fn test_not_ready() {
+		let mut pool = JitterAccumulator::new(1000);
+		pool.feed(1);
+		pool.random_u32();
+	}
+}
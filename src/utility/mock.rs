@@ -0,0 +1,269 @@
+
+#[derive(Clone, Copy)]
+enum Expect<'a> {
+	U32(u32),
+	U64(u64),
+	F64(f64),
+	F32(f32),
+	Bool(bool),
+	Bytes(&'a [u8]),
+}
+
+impl Expect<'_> {
+	const fn name(&self) -> &'static str {
+		match self {
+			Self::U32(_) => "u32",
+			Self::U64(_) => "u64",
+			Self::F64(_) => "f64",
+			Self::F32(_) => "f32",
+			Self::Bool(_) => "bool",
+			Self::Bytes(_) => "bytes",
+		}
+	}
+}
+
+/// a scripted [`crate::Random`] for unit tests.
+/// 
+/// `Mock` holds a queue of up to `N` expected draws, built with the `then_*`
+/// methods. every draw consumes the next expectation in order. if the queue
+/// is empty, or the draw is of the wrong kind, `Mock` panics with a message
+/// naming the index of the call and the expected kind.
+/// 
+/// ```
+/// # use prrng::Mock;
+/// use prrng::{Random, RandomImpl};
+/// 
+/// let mut rng = Mock::<4>::new()
+///     .then_u32(7)
+///     .then_f64(0.25)
+///     .then_bool(true)
+///     .then_bytes(&[1, 2, 3]);
+/// 
+/// assert_eq!(rng.random::<u32>(), 7);
+/// assert_eq!(rng.random_f64(), 0.25);
+/// assert_eq!(rng.random_bool(), true);
+/// 
+/// let mut buf = [0u8; 3];
+/// rng.random_bytes(&mut buf);
+/// assert_eq!(buf, [1, 2, 3]);
+/// ```
+/// 
+/// since every [`crate::Random`] method is built on top of the three
+/// [`crate::RandomImpl`] methods, kinds are checked by which of those methods
+/// is called: `u32`, `f32` and `bool` expect [`crate::RandomImpl::random_u32()`],
+/// `u64` and `f64` expect [`crate::RandomImpl::random_u64()`], and `bytes`
+/// expects [`crate::RandomImpl::random_bytes()`] with a buffer of the same length.
+/// for example, [`crate::Random::random_u8()`] is satisfied by [`Self::then_u32()`].
+#[derive(Clone)]
+pub struct Mock<'a, const N: usize> {
+	queue: [Option<Expect<'a>>; N],
+	head: usize,
+	len: usize,
+	calls: usize,
+}
+
+impl<'a, const N: usize> Mock<'a, N> {
+	/// construct a new `Mock`, with no expectations.
+	#[inline]
+	pub const fn new() -> Self {
+		Self {
+			queue: [None; N],
+			head: 0,
+			len: 0,
+			calls: 0,
+		}
+	}
+
+	#[track_caller]
+	const fn push(mut self, expect: Expect<'a>) -> Self {
+		assert!(self.len < N, "`Mock` is full");
+		self.queue[(self.head + self.len) % N] = Some(expect);
+		self.len += 1;
+		self
+	}
+
+	/// expect a `u32`, returning `value`.
+	#[inline]
+	#[track_caller]
+	pub const fn then_u32(self, value: u32) -> Self {
+		self.push(Expect::U32(value))
+	}
+
+	/// expect a `u64`, returning `value`.
+	#[inline]
+	#[track_caller]
+	pub const fn then_u64(self, value: u64) -> Self {
+		self.push(Expect::U64(value))
+	}
+
+	/// expect an `f64` from [`crate::Random::random_f64()`], returning `value`.
+	/// 
+	/// `value` must be within `0.0..1.0`. values with more precision than
+	/// `random_f64()` can produce (that is, very small values) will be rounded.
+	#[inline]
+	#[track_caller]
+	pub const fn then_f64(self, value: f64) -> Self {
+		assert!(value >= 0.0 && value < 1.0, "`value` must be within `0.0..1.0`");
+		self.push(Expect::F64(value))
+	}
+
+	/// expect an `f32` from [`crate::Random::random_f32()`], returning `value`.
+	/// 
+	/// `value` must be within `0.0..1.0`. values with more precision than
+	/// `random_f32()` can produce (that is, very small values) will be rounded.
+	#[inline]
+	#[track_caller]
+	pub const fn then_f32(self, value: f32) -> Self {
+		assert!(value >= 0.0 && value < 1.0, "`value` must be within `0.0..1.0`");
+		self.push(Expect::F32(value))
+	}
+
+	/// expect a `bool` from [`crate::Random::random_bool()`], returning `value`.
+	#[inline]
+	#[track_caller]
+	pub const fn then_bool(self, value: bool) -> Self {
+		self.push(Expect::Bool(value))
+	}
+
+	/// expect a call to [`crate::RandomImpl::random_bytes()`] with a buffer of
+	/// the same length as `value`, filling it with `value`.
+	#[inline]
+	#[track_caller]
+	pub const fn then_bytes(self, value: &'a [u8]) -> Self {
+		self.push(Expect::Bytes(value))
+	}
+
+	/// how many expectations are left.
+	#[inline]
+	pub const fn remaining(&self) -> usize {
+		self.len
+	}
+
+	#[track_caller]
+	fn pop(&mut self, call: &str) -> Expect<'a> {
+		let index = self.calls;
+		self.calls += 1;
+
+		if self.len == 0 {
+			panic!("`Mock` call #{}: expected nothing, got `{}`", index, call);
+		}
+
+		// unwrap: every slot within `head..head + len` is `Some`
+		let expect = self.queue[self.head].take().unwrap();
+		self.head = (self.head + 1) % N;
+		self.len -= 1;
+		expect
+	}
+
+	#[track_caller]
+	fn mismatch(&self, expect: Expect<'_>, call: &str) -> ! {
+		panic!("`Mock` call #{}: expected `{}`, got `{}`", self.calls - 1, expect.name(), call);
+	}
+}
+
+impl<const N: usize> Default for Mock<'_, N> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<const N: usize> crate::RandomImpl for Mock<'_, N> {
+	#[track_caller]
+	fn random_u64(&mut self) -> u64 {
+		match self.pop("random_u64") {
+			Expect::U64(x) => x,
+			Expect::F64(x) => (x + 1.0).to_bits() & 0x00_0f_ff_ff_ff_ff_ff_ff,
+			expect => self.mismatch(expect, "random_u64"),
+		}
+	}
+
+	#[track_caller]
+	fn random_u32(&mut self) -> u32 {
+		match self.pop("random_u32") {
+			Expect::U32(x) => x,
+			Expect::F32(x) => (x + 1.0).to_bits() & 0x00_7f_ff_ff,
+			Expect::Bool(x) => x as u32,
+			expect => self.mismatch(expect, "random_u32"),
+		}
+	}
+
+	#[track_caller]
+	fn random_bytes(&mut self, dst: &mut [u8]) {
+		match self.pop("random_bytes") {
+			Expect::Bytes(x) if x.len() == dst.len() => dst.copy_from_slice(x),
+			Expect::Bytes(x) => panic!(
+				"`Mock` call #{}: expected `bytes` with length {}, got length {}",
+				self.calls - 1, x.len(), dst.len(),
+			),
+			expect => self.mismatch(expect, "random_bytes"),
+		}
+	}
+
+	// a single expectation, however long `dst` is
+	#[track_caller]
+	fn random_bytes_uninit<'a>(&mut self, dst: &'a mut [core::mem::MaybeUninit<u8>]) -> &'a mut [u8] {
+		match self.pop("random_bytes") {
+			Expect::Bytes(x) if x.len() == dst.len() => {
+				for (o, i) in dst.iter_mut().zip(x.iter()) {
+					o.write(*i);
+				}
+			}
+			Expect::Bytes(x) => panic!(
+				"`Mock` call #{}: expected `bytes` with length {}, got length {}",
+				self.calls - 1, x.len(), dst.len(),
+			),
+			expect => self.mismatch(expect, "random_bytes"),
+		}
+		unsafe {
+			// safety:
+			// every byte of `dst` was written above, and `MaybeUninit<u8>`
+			// has the same layout as `u8`.
+			&mut *(dst as *mut [core::mem::MaybeUninit<u8>] as *mut [u8])
+		}
+	}
+}
+
+impl<const N: usize> core::fmt::Debug for Mock<'_, N> {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		write!(f, "Mock({}/{})", self.len, N)
+	}
+}
+
+
+#[cfg(test)]
+mod test {
+	use crate::{Mock, Random, RandomImpl};
+
+	#[test]
+	fn test_happy() {
+		let mut rng = Mock::<8>::new()
+			.then_u64(0xdeadbeef)
+			.then_f32(0.5)
+			.then_f64(0.75)
+			.then_bool(false)
+			.then_u32(3);
+
+		assert_eq!(rng.random_u64(), 0xdeadbeef);
+		assert_eq!(rng.random_f32(), 0.5);
+		assert_eq!(rng.random_f64(), 0.75);
+		assert!(!rng.random_bool());
+		assert_eq!(rng.random_u8(), 3);
+		assert_eq!(rng.remaining(), 0);
+	}
+
+	#[test]
+	#[should_panic(expected = "`Mock` call #1: expected `f64`, got `random_u32`")]
+	fn test_mismatch() {
+		let mut rng = Mock::<2>::new().then_u32(1).then_f64(0.5);
+		rng.random_u32();
+		rng.random_u32();
+	}
+
+	#[test]
+	#[should_panic(expected = "`Mock` call #1: expected nothing, got `random_u64`")]
+	fn test_exhausted() {
+		let mut rng = Mock::<1>::new().then_u64(1);
+		rng.random_u64();
+		rng.random_u64();
+	}
+}
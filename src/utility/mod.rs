@@ -1,6 +1,29 @@
 
+pub mod bitreader;
+pub mod bounded;
 pub mod buffer;
+pub mod bytestream;
+pub mod chars;
+pub mod combine;
+pub mod counting;
 pub mod crush;
+pub mod debias;
+pub mod decimate;
+pub mod global;
+pub mod interleave;
 pub mod iter;
+pub mod jitter;
+pub mod mock;
+pub mod normal;
+pub mod poisson_disk;
+pub mod recording;
+pub mod replay;
+pub mod reseeding;
+pub mod sample;
+pub mod shuffle_bag;
 pub mod r#static;
+#[cfg(any(test, feature = "std"))]
+pub mod time;
+pub mod walk;
+pub mod whiten;
 
@@ -0,0 +1,179 @@
+/// infinite iterator of normally distributed `f64`s.
+/// 
+/// values are generated with the
+/// [box-muller transform](https://en.wikipedia.org/wiki/Box%E2%80%93Muller_transform),
+/// which turns two uniform values into two normal values. the second value
+/// of each pair is cached and returned by the next call to
+/// [`Iterator::next()`], so the transform only runs for every other value.
+/// 
+/// cloning a `NormalIter` also clones the cached value, so a clone
+/// continues with the exact same sequence as the original.
+/// 
+/// see [`crate::Random::random_normal_iter()`].
+/// 
+/// ```
+/// # use prrng::XorShift64;
+/// use prrng::Random;
+/// let mut rng = XorShift64::new(1);
+/// 
+/// let n = 10000;
+/// let mean = rng.random_normal_iter(10.0, 2.0).take(n).sum::<f64>() / n as f64;
+/// assert!((mean - 10.0).abs() < 0.1);
+/// ```
+#[derive(Clone)]
+pub struct NormalIter<R: crate::Random> {
+	inner: R,
+	mean: f64,
+	std_dev: f64,
+	spare: Option<f64>,
+}
+
+impl<R: crate::Random> NormalIter<R> {
+	/// construct a new `NormalIter`.
+	/// 
+	/// ## panics
+	/// 
+	/// panics if `std_dev` is negative or NaN.
+	#[inline]
+	pub const fn new(inner: R, mean: f64, std_dev: f64) -> Self {
+		assert!(std_dev >= 0.0, "`std_dev` must not be negative");
+		Self {
+			inner,
+			mean,
+			std_dev,
+			spare: None,
+		}
+	}
+
+	/// the mean of the distribution.
+	#[inline]
+	pub const fn mean(&self) -> f64 {
+		self.mean
+	}
+
+	/// the standard deviation of the distribution.
+	#[inline]
+	pub const fn std_dev(&self) -> f64 {
+		self.std_dev
+	}
+
+	/// consume `self`, returning the inner rng.
+	/// 
+	/// a cached value, if any, is lost.
+	#[inline]
+	pub fn unwrap(self) -> R {
+		self.inner
+	}
+}
+
+// returns a pair of independent standard normal values, with the box-muller
+// transform.
+#[inline]
+pub(crate) fn standard_normal_pair(random: &mut (impl crate::Random + ?Sized)) -> (f64, f64) {
+	// `1 - x` is in `(0, 1]`, avoiding `ln(0)`
+	let u1 = 1.0 - random.random_f64();
+	let u2 = random.random_f64();
+
+	let r = crate::math::sqrt(-2.0 * crate::math::ln(u1));
+	let (sin, cos) = crate::math::sin_cos(core::f64::consts::TAU * u2);
+	(r * cos, r * sin)
+}
+
+impl<R: crate::Random> Iterator for NormalIter<R> {
+	type Item = f64;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		let z = match self.spare.take() {
+			Some(z) => z,
+			None => {
+				let (z0, z1) = standard_normal_pair(&mut self.inner);
+				self.spare = Some(z1);
+				z0
+			}
+		};
+		Some(self.mean + self.std_dev * z)
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		(usize::MAX, None)
+	}
+}
+
+impl<R: crate::Random> core::iter::FusedIterator for NormalIter<R> {}
+
+impl<R: crate::Random + core::fmt::Debug> core::fmt::Debug for NormalIter<R> {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		write!(f, "NormalIter<{}, {}>({:?})", self.mean, self.std_dev, self.inner)
+	}
+}
+
+
+#[cfg(test)]
+mod test {
+	use crate::{NormalIter, Random, XorShift64};
+
+	#[test]
+	fn test_pairs() {
+		let mut iter = NormalIter::new(XorShift64::new(1), 3.0, 0.5);
+
+		// reference computation, one pair at a time
+		let mut rng = XorShift64::new(1);
+		for _ in 0..64 {
+			let u1 = 1.0 - rng.random_f64();
+			let u2 = rng.random_f64();
+			let r = crate::math::sqrt(-2.0 * crate::math::ln(u1));
+			let (sin, cos) = crate::math::sin_cos(core::f64::consts::TAU * u2);
+
+			assert_eq!(iter.next(), Some(3.0 + 0.5 * r * cos));
+			assert_eq!(iter.next(), Some(3.0 + 0.5 * r * sin));
+		}
+	}
+
+	#[test]
+	fn test_statistics() {
+		let mut rng = XorShift64::new(1);
+
+		for (mean, std_dev) in [(0.0, 1.0), (10.0, 2.0), (-5.0, 0.1)] {
+			let n = 100000;
+			let mut sum = 0.0;
+			let mut sum_sq = 0.0;
+			for x in rng.random_normal_iter(mean, std_dev).take(n) {
+				sum += x;
+				sum_sq += x * x;
+			}
+			let m = sum / n as f64;
+			let s = crate::math::sqrt(sum_sq / n as f64 - m * m);
+
+			assert!((m - mean).abs() < 0.02 * std_dev, "mean {m}");
+			assert!((s - std_dev).abs() < 0.02 * std_dev, "std_dev {s}");
+		}
+
+		assert!(rng.random_normal_iter(1.0, 0.0).take(16).all(|x| x == 1.0));
+	}
+
+	#[test]
+	fn test_cache() {
+		let mut a = NormalIter::new(XorShift64::new(1), 0.0, 1.0);
+		let b = NormalIter::new(XorShift64::new(1), 0.0, 1.0);
+
+		let mut taken = [0.0; 64];
+		for i in &mut taken {
+			*i = a.by_ref().take(1).next().unwrap();
+		}
+		assert!(taken.into_iter().eq(b.clone().take(64)));
+
+		// the cached value is cloned
+		let mut a = b;
+		a.next();
+		let c = a.clone();
+		assert!(a.take(64).eq(c.take(64)));
+	}
+
+	#[test]
+	#[should_panic = "`std_dev` must not be negative"]
+	fn test_negative() {
+		let _ = NormalIter::new(XorShift64::new(1), 0.0, -1.0);
+	}
+}
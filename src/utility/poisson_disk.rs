@@ -0,0 +1,313 @@
+use core::f64::consts::{SQRT_2, TAU};
+
+// background grid for `poisson_disk_2d_into()`. each cell is small enough
+// that it holds at most one point, so it can store that point's index.
+struct Grid {
+	cell: f64,
+	cols: usize,
+	rows: usize,
+}
+
+impl Grid {
+	#[track_caller]
+	fn new(width: f64, height: f64, min_dist: f64) -> Self {
+		assert!(width > 0.0 && width.is_finite(), "`width` must be positive and finite");
+		assert!(height > 0.0 && height.is_finite(), "`height` must be positive and finite");
+		assert!(min_dist > 0.0, "`min_dist` must be positive");
+
+		// the diagonal of a cell is `min_dist`
+		let cell = min_dist / SQRT_2;
+		let cols = ((width / cell) as usize).checked_add(1);
+		let rows = ((height / cell) as usize).checked_add(1);
+		assert!(
+			cols.zip(rows).and_then(|(c, r)| c.checked_mul(r)).is_some(),
+			"`min_dist` is too small for the domain",
+		);
+
+		Self {
+			cell,
+			cols: cols.unwrap(),
+			rows: rows.unwrap(),
+		}
+	}
+
+	#[inline]
+	fn len(&self) -> usize {
+		self.cols * self.rows
+	}
+
+	#[inline]
+	fn coords(&self, (x, y): (f64, f64)) -> (usize, usize) {
+		(
+			((x / self.cell) as usize).min(self.cols - 1),
+			((y / self.cell) as usize).min(self.rows - 1),
+		)
+	}
+
+	#[inline]
+	fn index(&self, point: (f64, f64)) -> usize {
+		let (x, y) = self.coords(point);
+		y * self.cols + x
+	}
+
+	// whether `point` is at least `min_dist` away from every point in `grid`
+	fn is_clear(&self, grid: &[usize], points: &[(f64, f64)], point: (f64, f64), min_dist_sq: f64) -> bool {
+		if grid[self.index(point)] != 0 {
+			return false;
+		}
+
+		// anything closer than `min_dist` is at most 2 cells away
+		let (x, y) = self.coords(point);
+		for ny in y.saturating_sub(2)..=(y + 2).min(self.rows - 1) {
+			for nx in x.saturating_sub(2)..=(x + 2).min(self.cols - 1) {
+				let i = grid[ny * self.cols + nx];
+				if i == 0 {
+					continue;
+				}
+				let (dx, dy) = (points[i - 1].0 - point.0, points[i - 1].1 - point.1);
+				if dx * dx + dy * dy < min_dist_sq {
+					return false;
+				}
+			}
+		}
+		true
+	}
+}
+
+/// returns how long the `grid` passed to [`poisson_disk_2d_into()`] must be,
+/// for the same `width`, `height` and `min_dist`.
+/// 
+/// this is about `2 * width * height / (min_dist * min_dist)`.
+/// 
+/// ## panics
+/// 
+/// panics under the same conditions as [`poisson_disk_2d_into()`].
+#[track_caller]
+pub fn poisson_disk_2d_grid_len(width: f64, height: f64, min_dist: f64) -> usize {
+	Grid::new(width, height, min_dist).len()
+}
+
+/// fill `points` with a
+/// [poisson disk sample](https://en.wikipedia.org/wiki/Supersampling#Poisson_disk)
+/// of the rectangle `[0, width) x [0, height)`, returning how many points
+/// were written.
+/// 
+/// every pair of points is at least `min_dist` apart, yet no point could be
+/// added without breaking that, up to `k`, the number of candidates tried
+/// around each point before giving up on it. `30` is a good default. this
+/// is "blue noise", spread out evenly without looking like a grid, which is
+/// useful for placing things like trees or stars.
+/// 
+/// this uses [bridson's algorithm](https://dl.acm.org/doi/10.1145/1278780.1278807),
+/// with `grid` as scratch space for its background grid. `grid` must be at
+/// least [`poisson_disk_2d_grid_len()`] long. if `points` fills up first,
+/// sampling stops early, leaving gaps. the points are not in any particular
+/// order, but the same rng state always gives the same points.
+/// 
+/// ```
+/// use prrng::{XorShift64, poisson_disk_2d_grid_len, poisson_disk_2d_into};
+/// 
+/// let mut rng = XorShift64::new(1);
+/// 
+/// let mut points = [(0.0, 0.0); 64];
+/// let mut grid = [0; 128];
+/// assert!(poisson_disk_2d_grid_len(10.0, 10.0, 2.0) <= grid.len());
+/// 
+/// let count = poisson_disk_2d_into(&mut rng, 10.0, 10.0, 2.0, 30, &mut points, &mut grid);
+/// let trees = &points[..count];
+/// ```
+/// 
+/// ## panics
+/// 
+/// panics if `width` or `height` is not positive and finite, if `min_dist`
+/// is not positive, if `min_dist` is so small that the grid length
+/// overflows, or if `grid` is too short.
+#[track_caller]
+pub fn poisson_disk_2d_into(
+	rng: &mut impl crate::Random,
+	width: f64,
+	height: f64,
+	min_dist: f64,
+	k: u32,
+	points: &mut [(f64, f64)],
+	grid: &mut [usize],
+) -> usize {
+	let g = Grid::new(width, height, min_dist);
+	assert!(grid.len() >= g.len(), "`grid` is too short, see `poisson_disk_2d_grid_len()`");
+
+	// each cell holds the index of its point, plus 1, or 0 when empty
+	let grid = &mut grid[..g.len()];
+	grid.fill(0);
+
+	if points.is_empty() {
+		return 0;
+	}
+
+	let min_dist_sq = min_dist * min_dist;
+
+	// rounding can land a point exactly on `width` or `height`
+	let in_bounds = |(x, y): (f64, f64)| x < width && y < height;
+
+	let first = loop {
+		let first = (rng.random_f64() * width, rng.random_f64() * height);
+		if in_bounds(first) {
+			break first;
+		}
+	};
+	points[0] = first;
+	grid[g.index(first)] = 1;
+
+	let mut count = 1;
+	// `points[done..count]` are still active
+	let mut done = 0;
+
+	while done < count && count < points.len() {
+		let i = done + rng.random_u64_bound((count - done) as u64) as usize;
+		let (x, y) = points[i];
+
+		let mut found = false;
+		for _ in 0..k {
+			// uniform by area, between `min_dist` and `2 * min_dist` away
+			let d = min_dist * crate::math::sqrt(1.0 + 3.0 * rng.random_f64());
+			let (sin, cos) = crate::math::sin_cos(TAU * rng.random_f64());
+			let candidate = (x + d * cos, y + d * sin);
+
+			if candidate.0 >= 0.0
+				&& candidate.1 >= 0.0
+				&& in_bounds(candidate)
+				&& g.is_clear(grid, points, candidate, min_dist_sq)
+			{
+				points[count] = candidate;
+				grid[g.index(candidate)] = count + 1;
+				count += 1;
+				found = true;
+				break;
+			}
+		}
+
+		if !found {
+			// retire `i`, by swapping it to the start of the active range
+			points.swap(i, done);
+			grid[g.index(points[i])] = i + 1;
+			grid[g.index(points[done])] = done + 1;
+			done += 1;
+		}
+	}
+
+	count
+}
+
+/// returns a
+/// [poisson disk sample](https://en.wikipedia.org/wiki/Supersampling#Poisson_disk)
+/// of the rectangle `[0, width) x [0, height)`, with every pair of points at
+/// least `min_dist` apart.
+/// 
+/// see [`poisson_disk_2d_into()`], which this allocates for. this requires
+/// the `alloc` feature.
+/// 
+/// ```
+/// use prrng::{XorShift64, poisson_disk_2d};
+/// 
+/// let mut rng = XorShift64::new(1);
+/// 
+/// let stars = poisson_disk_2d(&mut rng, 640.0, 480.0, 12.0, 30);
+/// assert!(stars.iter().all(|&(x, y)| x < 640.0 && y < 480.0));
+/// ```
+/// 
+/// ## panics
+/// 
+/// panics under the same conditions as [`poisson_disk_2d_into()`], other
+/// than `grid` being too short.
+#[cfg(any(test, feature = "alloc"))]
+#[track_caller]
+pub fn poisson_disk_2d(
+	rng: &mut impl crate::Random,
+	width: f64,
+	height: f64,
+	min_dist: f64,
+	k: u32,
+) -> alloc::vec::Vec<(f64, f64)> {
+	// every cell holds at most one point, so this is never filled
+	let len = poisson_disk_2d_grid_len(width, height, min_dist);
+	let mut grid = alloc::vec![0; len];
+	let mut points = alloc::vec![(0.0, 0.0); len];
+
+	let count = poisson_disk_2d_into(rng, width, height, min_dist, k, &mut points, &mut grid);
+	points.truncate(count);
+	points.shrink_to_fit();
+	points
+}
+
+
+#[cfg(test)]
+mod test {
+	use crate::{XorShift64, poisson_disk_2d, poisson_disk_2d_grid_len, poisson_disk_2d_into};
+
+	fn check_spacing(points: &[(f64, f64)], width: f64, height: f64, min_dist: f64) {
+		for (i, a) in points.iter().enumerate() {
+			assert!((0.0..width).contains(&a.0) && (0.0..height).contains(&a.1), "{a:?}");
+			for b in &points[i + 1..] {
+				let (dx, dy) = (a.0 - b.0, a.1 - b.1);
+				assert!(dx * dx + dy * dy >= min_dist * min_dist, "{a:?} {b:?}");
+			}
+		}
+	}
+
+	#[test]
+	fn test_spacing() {
+		for (width, height, min_dist) in [(100.0, 100.0, 5.0), (37.5, 3.0, 1.0), (1.0, 200.0, 0.3)] {
+			let points = poisson_disk_2d(&mut XorShift64::new(1), width, height, min_dist, 30);
+			check_spacing(&points, width, height, min_dist);
+
+			// roughly as dense as a maximal packing should be, with no big gaps
+			let density = points.len() as f64 * min_dist * min_dist / (width * height);
+			assert!(density > 0.5, "{width} {height} {min_dist}: {density}");
+		}
+	}
+
+	#[test]
+	fn test_deterministic() {
+		let a = poisson_disk_2d(&mut XorShift64::new(1), 50.0, 50.0, 3.0, 30);
+		let b = poisson_disk_2d(&mut XorShift64::new(1), 50.0, 50.0, 3.0, 30);
+		let c = poisson_disk_2d(&mut XorShift64::new(2), 50.0, 50.0, 3.0, 30);
+		assert_eq!(a, b);
+		assert_ne!(a, c);
+
+		// the same as the allocating version
+		let mut points = [(0.0, 0.0); 1000];
+		let mut grid = [usize::MAX; 1000];
+		let count = poisson_disk_2d_into(&mut XorShift64::new(1), 50.0, 50.0, 3.0, 30, &mut points, &mut grid);
+		assert_eq!(&points[..count], &a[..]);
+	}
+
+	#[test]
+	fn test_small() {
+		// `min_dist` larger than the whole domain
+		for seed in 1..20 {
+			let points = poisson_disk_2d(&mut XorShift64::new(seed), 2.0, 3.0, 10.0, 30);
+			assert_eq!(points.len(), 1);
+			assert_eq!(poisson_disk_2d_grid_len(2.0, 3.0, 10.0), 1);
+		}
+
+		// a full buffer stops early, still spaced out
+		let mut points = [(0.0, 0.0); 10];
+		let mut grid = [0; 1000];
+		let count = poisson_disk_2d_into(&mut XorShift64::new(1), 50.0, 50.0, 3.0, 30, &mut points, &mut grid);
+		assert_eq!(count, 10);
+		check_spacing(&points, 50.0, 50.0, 3.0);
+
+		let count = poisson_disk_2d_into(&mut XorShift64::new(1), 50.0, 50.0, 3.0, 30, &mut [], &mut grid);
+		assert_eq!(count, 0);
+
+		// no candidates at all
+		assert_eq!(poisson_disk_2d(&mut XorShift64::new(1), 50.0, 50.0, 3.0, 0).len(), 1);
+	}
+
+	#[test]
+	#[should_panic(expected = "`grid` is too short")]
+	fn test_grid_len() {
+		let len = poisson_disk_2d_grid_len(50.0, 50.0, 3.0);
+		let mut grid = [0; 1000];
+		poisson_disk_2d_into(&mut XorShift64::new(1), 50.0, 50.0, 3.0, 30, &mut [(0.0, 0.0); 10], &mut grid[..len - 1]);
+	}
+}
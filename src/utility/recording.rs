@@ -0,0 +1,201 @@
+
+/// a single value drawn through a [`Recording`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum RecordedDraw {
+	/// a value returned by [`crate::RandomImpl::random_u32()`].
+	U32(u32),
+	/// a value returned by [`crate::RandomImpl::random_u64()`].
+	U64(u64),
+	/// up to 8 bytes written by [`crate::RandomImpl::random_bytes()`].
+	/// 
+	/// every call to `random_bytes()` is recorded as one `Bytes` per 8 byte chunk,
+	/// where `value` is the chunk with little-endian ordering, padded with `0`s.
+	Bytes {
+		value: u64,
+		len: u8,
+	},
+}
+
+impl RecordedDraw {
+	/// returns this draw as a `u64`, as expected by [`crate::Replay`].
+	/// 
+	/// `U32` is zero-extended, and `Bytes` returns its `value`.
+	#[inline]
+	pub const fn word(&self) -> u64 {
+		match *self {
+			Self::U32(x) => x as u64,
+			Self::U64(x) => x,
+			Self::Bytes { value, .. } => value,
+		}
+	}
+}
+
+/// utility type for logging [`crate::Random`] calls.
+/// 
+/// `Recording` forwards every call to the inner rng, while writing every
+/// value drawn into a caller-provided slice of [`RecordedDraw`], in call order.
+/// once the slice is full, further values are not recorded, and
+/// [`Self::overflowed()`] returns `true`.
+/// 
+/// ```
+/// # use prrng::Recording;
+/// # use prrng::RecordedDraw;
+/// # use prrng::XorShift64;
+/// use prrng::{Random, RandomImpl};
+/// 
+/// let mut log = [RecordedDraw::U32(0); 4];
+/// let mut rng = Recording::new(XorShift64::new(1), &mut log);
+/// 
+/// let a = rng.random_u64();
+/// let b = rng.random_u32();
+/// 
+/// assert_eq!(rng.len(), 2);
+/// assert_eq!(rng.log(), &[RecordedDraw::U64(a), RecordedDraw::U32(b)]);
+/// ```
+pub struct Recording<'a, R: crate::Random> {
+	inner: R,
+	log: &'a mut [RecordedDraw],
+	len: usize,
+	overflowed: bool,
+}
+
+impl<'a, R: crate::Random> Recording<'a, R> {
+	/// construct a new `Recording`, which will write into `log`.
+	#[inline]
+	pub const fn new(inner: R, log: &'a mut [RecordedDraw]) -> Self {
+		Self {
+			inner,
+			log,
+			len: 0,
+			overflowed: false,
+		}
+	}
+
+	/// consume `self`, returning the inner rng.
+	#[inline]
+	pub fn unwrap(self) -> R {
+		self.inner
+	}
+
+	/// how many values have been recorded.
+	#[inline]
+	pub fn len(&self) -> usize {
+		self.len
+	}
+
+	/// whether no values have been recorded yet.
+	#[inline]
+	pub fn is_empty(&self) -> bool {
+		self.len == 0
+	}
+
+	/// whether any values were drawn after the log was full.
+	#[inline]
+	pub fn overflowed(&self) -> bool {
+		self.overflowed
+	}
+
+	/// the values recorded so far.
+	#[inline]
+	pub fn log(&self) -> &[RecordedDraw] {
+		&self.log[..self.len]
+	}
+
+	#[inline]
+	fn record(&mut self, draw: RecordedDraw) {
+		if let Some(slot) = self.log.get_mut(self.len) {
+			*slot = draw;
+			self.len += 1;
+		} else {
+			self.overflowed = true;
+		}
+	}
+}
+
+impl<R: crate::Random> crate::RandomImpl for Recording<'_, R> {
+	#[inline]
+	fn random_u64(&mut self) -> u64 {
+		let x = self.inner.random_u64();
+		self.record(RecordedDraw::U64(x));
+		x
+	}
+
+	#[inline]
+	fn random_u32(&mut self) -> u32 {
+		let x = self.inner.random_u32();
+		self.record(RecordedDraw::U32(x));
+		x
+	}
+
+	fn random_bytes(&mut self, dst: &mut [u8]) {
+		self.inner.random_bytes(dst);
+
+		for chunk in dst.chunks(8) {
+			let mut value = [0u8; 8];
+			value[..chunk.len()].copy_from_slice(chunk);
+			self.record(RecordedDraw::Bytes {
+				value: u64::from_le_bytes(value),
+				len: chunk.len() as u8,
+			});
+		}
+	}
+}
+
+impl<R: crate::Random + core::fmt::Debug> core::fmt::Debug for Recording<'_, R> {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		write!(f, "Recording({:?}, {}/{})", self.inner, self.len, self.log.len())
+	}
+}
+
+
+#[cfg(test)]
+mod test {
+	use crate::{Random, RandomImpl, RecordedDraw, Recording, XorShift64};
+
+	#[test]
+	fn test_log() {
+		let mut raw = XorShift64::new(2);
+		let mut log = [RecordedDraw::U32(0); 8];
+		let mut rng = Recording::new(raw, &mut log);
+
+		let a: u8 = rng.random();
+		let b = rng.random_u64();
+		let mut buf = [0u8; 11];
+		rng.random_bytes(&mut buf);
+		let c = rng.random_u32();
+
+		let w = raw.get() as u32;
+		assert_eq!(a, w as u8);
+		assert_eq!(b, raw.get());
+
+		let x = raw.get();
+		let y = raw.get();
+		assert_eq!(buf[..8], x.to_le_bytes());
+		assert_eq!(buf[8..], y.to_le_bytes()[..3]);
+
+		assert_eq!(c, raw.get() as u32);
+
+		assert_eq!(rng.log(), &[
+			RecordedDraw::U32(w),
+			RecordedDraw::U64(b),
+			RecordedDraw::Bytes { value: x, len: 8 },
+			RecordedDraw::Bytes { value: y & 0xff_ff_ff, len: 3 },
+			RecordedDraw::U32(c),
+		]);
+		assert!(!rng.overflowed());
+	}
+
+	#[test]
+	fn test_overflow() {
+		let mut log = [RecordedDraw::U32(0); 2];
+		let mut rng = Recording::new(XorShift64::new(2), &mut log);
+
+		rng.random_u64();
+		rng.random_u64();
+		assert!(!rng.overflowed());
+
+		rng.random_u64();
+		assert!(rng.overflowed());
+		assert_eq!(rng.len(), 2);
+	}
+}
@@ -0,0 +1,148 @@
+
+/// utility type for playing back a fixed sequence of values.
+/// 
+/// `Replay` serves values from a caller-provided slice of `u64`, in order.
+/// this is the counterpart to [`crate::Recording`]: a log captured from a
+/// real rng can be replayed later, without needing the original rng at all.
+/// see [`crate::RecordedDraw::word()`].
+/// 
+/// - [`crate::RandomImpl::random_u64()`] returns the next value.
+/// - [`crate::RandomImpl::random_u32()`] returns the low half of the next value.
+/// - [`crate::RandomImpl::random_bytes()`] serializes values with little-endian
+///   ordering, exactly like [`crate::common::bytes_from_u64()`].
+/// 
+/// all three panic once the slice has been exhausted. see
+/// [`Self::get_checked()`] and [`Self::exhausted()`] to handle this instead.
+/// 
+/// ```
+/// # use prrng::Replay;
+/// use prrng::{Random, RandomImpl};
+/// 
+/// let mut rng = Replay::new(&[4, 0xdeadbeef, u64::MAX]);
+/// 
+/// assert_eq!(rng.random_u64(), 4);
+/// assert_eq!(rng.random_u32(), 0xdeadbeef);
+/// assert_eq!(rng.random_bool(), true);
+/// assert!(rng.exhausted());
+/// ```
+#[derive(Clone)]
+pub struct Replay<'a> {
+	values: &'a [u64],
+	index: usize,
+}
+
+impl<'a> Replay<'a> {
+	/// construct a new `Replay`, starting at the first value.
+	#[inline]
+	pub const fn new(values: &'a [u64]) -> Self {
+		Self {
+			values,
+			index: 0,
+		}
+	}
+
+	/// whether every value has been consumed.
+	#[inline]
+	pub const fn exhausted(&self) -> bool {
+		self.index >= self.values.len()
+	}
+
+	/// how many values are left.
+	#[inline]
+	pub const fn remaining(&self) -> usize {
+		self.values.len().saturating_sub(self.index)
+	}
+
+	/// returns the next value, or `None` if every value has been consumed.
+	#[inline]
+	pub const fn get_checked(&mut self) -> Option<u64> {
+		if self.index >= self.values.len() {
+			None
+		} else {
+			let ret = self.values[self.index];
+			self.index += 1;
+			Some(ret)
+		}
+	}
+
+	/// returns the next value.
+	/// 
+	/// ## panics
+	/// 
+	/// panics if every value has been consumed.
+	/// see [`Self::get_checked()`] for a version that returns `None` instead.
+	#[inline]
+	#[track_caller]
+	pub const fn get(&mut self) -> u64 {
+		match self.get_checked() {
+			Some(x) => x,
+			None => panic!("`Replay` has been exhausted"),
+		}
+	}
+}
+
+impl crate::RandomImpl for Replay<'_> {
+	#[inline]
+	fn random_u64(&mut self) -> u64 {
+		self.get()
+	}
+
+	#[inline]
+	fn random_u32(&mut self) -> u32 {
+		self.get() as u32
+	}
+
+	fn random_bytes(&mut self, dst: &mut [u8]) {
+		crate::common::bytes_from_u64(self, dst);
+	}
+}
+
+impl core::fmt::Debug for Replay<'_> {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		write!(f, "Replay({}/{})", self.index, self.values.len())
+	}
+}
+
+
+#[cfg(test)]
+mod test {
+	use crate::{Random, RandomImpl, RecordedDraw, Recording, Replay, XorShift64};
+
+	fn system(rng: &mut impl Random) -> (u64, [u8; 13], (u8, bool, f64), u32) {
+		let a = rng.random_u64();
+		let mut b = [0u8; 13];
+		rng.random_bytes(&mut b);
+		let c = rng.random();
+		let d = rng.random_u32_bound(1000);
+		(a, b, c, d)
+	}
+
+	#[test]
+	fn test_replay() {
+		let mut log = [RecordedDraw::U32(0); 16];
+		let mut rng = Recording::new(XorShift64::new(8), &mut log);
+
+		let expected = system(&mut rng);
+		let len = rng.len();
+		assert!(!rng.overflowed());
+
+		let mut words = [0u64; 16];
+		for (o, i) in words.iter_mut().zip(log[..len].iter()) {
+			*o = i.word();
+		}
+
+		let mut rng = Replay::new(&words[..len]);
+		assert_eq!(system(&mut rng), expected);
+		assert!(rng.exhausted());
+	}
+
+	#[test]
+	#[should_panic(expected = "`Replay` has been exhausted")]
+	fn test_exhausted() {
+		let mut rng = Replay::new(&[1]);
+		assert_eq!(rng.get_checked(), Some(1));
+		assert_eq!(rng.get_checked(), None);
+		assert_eq!(rng.remaining(), 0);
+		rng.random_u32();
+	}
+}
@@ -0,0 +1,202 @@
+
+/// utility type for periodically reseeding a [`crate::Random`].
+/// 
+/// `Reseeding` wraps an rng `R` and a seeder `S`. after a certain number of
+/// bytes have been emitted, `R` is thrown away and a new one is constructed
+/// with [`crate::FromRandom`], using fresh values from `S`. this limits how
+/// much output is derived from any single state, which is useful for
+/// long-lived generators.
+/// 
+/// every output path counts towards the threshold:
+/// [`crate::RandomImpl::random_u64()`] counts as 8 bytes,
+/// [`crate::RandomImpl::random_u32()`] counts as 4 bytes, and
+/// [`crate::RandomImpl::random_bytes()`] counts every byte written. a call to
+/// `random_bytes()` that crosses the threshold is split, with the remainder
+/// written by the reseeded rng. a word that would cross the threshold is
+/// instead entirely drawn from the reseeded rng.
+/// 
+/// ```
+/// # use prrng::Reseeding;
+/// # use prrng::SplitMix64;
+/// # use prrng::ChaCha;
+/// use prrng::RandomImpl;
+/// 
+/// let mut rng = Reseeding::<ChaCha, _>::new_from(SplitMix64::new(1), 1024);
+/// 
+/// let mut buf = [0u8; 4096];
+/// rng.random_bytes(&mut buf); // reseeded 3 times
+/// 
+/// assert_eq!(rng.bytes_until_reseed(), 0);
+/// ```
+#[derive(Clone)]
+pub struct Reseeding<R: crate::Random + crate::FromRandom, S: crate::Random> {
+	inner: R,
+	seeder: S,
+	threshold: u64,
+	left: u64,
+}
+
+impl<R: crate::Random + crate::FromRandom, S: crate::Random> Reseeding<R, S> {
+	/// construct a new `Reseeding`, where `inner` will be reseeded with
+	/// values from `seeder` every `threshold` bytes.
+	/// 
+	/// `threshold` should not be `0`. if it is, it will be set to `1`.
+	#[inline]
+	pub const fn new(inner: R, seeder: S, threshold: u64) -> Self {
+		let threshold = crate::common::u64_or_1(threshold);
+		Self {
+			inner,
+			seeder,
+			threshold,
+			left: threshold,
+		}
+	}
+
+	/// construct a new `Reseeding`, where the initial inner rng is seeded
+	/// by `seeder`.
+	#[inline]
+	pub fn new_from(mut seeder: S, threshold: u64) -> Self {
+		let inner = seeder.random();
+		Self::new(inner, seeder, threshold)
+	}
+
+	/// consume `self`, returning the inner rng and seeder.
+	#[inline]
+	pub fn unwrap(self) -> (R, S) {
+		(self.inner, self.seeder)
+	}
+
+	/// immediately reseed the inner rng, resetting the byte count.
+	#[inline]
+	pub fn force_reseed(&mut self) {
+		self.inner = self.seeder.random();
+		self.left = self.threshold;
+	}
+
+	/// how many bytes can be emitted before the inner rng is reseeded.
+	#[inline]
+	pub fn bytes_until_reseed(&self) -> u64 {
+		self.left
+	}
+
+	#[inline]
+	fn advance(&mut self, bytes: u64) {
+		if self.left < bytes {
+			self.force_reseed();
+		}
+		self.left = self.left.saturating_sub(bytes);
+	}
+}
+
+impl<R: crate::Random + crate::FromRandom, S: crate::Random> crate::RandomImpl for Reseeding<R, S> {
+	#[inline]
+	fn random_u64(&mut self) -> u64 {
+		self.advance(8);
+		self.inner.random_u64()
+	}
+
+	#[inline]
+	fn random_u32(&mut self) -> u32 {
+		self.advance(4);
+		self.inner.random_u32()
+	}
+
+	fn random_bytes(&mut self, mut dst: &mut [u8]) {
+		while !dst.is_empty() {
+			if self.left == 0 {
+				self.force_reseed();
+			}
+
+			let len = dst.len().min(self.left.try_into().unwrap_or(usize::MAX));
+			let (now, rest) = dst.split_at_mut(len);
+			self.inner.random_bytes(now);
+			self.left -= len as u64;
+			dst = rest;
+		}
+	}
+
+	// split up the same way as `random_bytes()`, rather than by staged chunks
+	fn random_bytes_uninit<'a>(&mut self, dst: &'a mut [core::mem::MaybeUninit<u8>]) -> &'a mut [u8] {
+		let mut done = 0;
+		while done < dst.len() {
+			if self.left == 0 {
+				self.force_reseed();
+			}
+
+			let len = (dst.len() - done).min(self.left.try_into().unwrap_or(usize::MAX));
+			self.inner.random_bytes_uninit(&mut dst[done..done + len]);
+			self.left -= len as u64;
+			done += len;
+		}
+		unsafe {
+			// safety:
+			// every byte of `dst` was written above, and `MaybeUninit<u8>`
+			// has the same layout as `u8`.
+			&mut *(dst as *mut [core::mem::MaybeUninit<u8>] as *mut [u8])
+		}
+	}
+}
+
+impl<R, S> core::fmt::Debug for Reseeding<R, S>
+where
+	R: crate::Random + crate::FromRandom + core::fmt::Debug,
+	S: crate::Random + core::fmt::Debug,
+{
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		write!(f, "Reseeding({:?}, {:?})", self.inner, self.seeder)
+	}
+}
+
+
+#[cfg(test)]
+mod test {
+	use crate::{Random, RandomImpl, Reseeding, SplitMix64, XorShift64};
+
+	#[test]
+	fn test_threshold() {
+		let mut seeder = SplitMix64::new(1);
+		let mut raw = XorShift64::new(5);
+		let mut rng = Reseeding::new(raw, seeder, 32);
+
+		for _ in 0..4 {
+			assert_eq!(rng.random_u64(), raw.get());
+		}
+		assert_eq!(rng.bytes_until_reseed(), 0);
+
+		let mut fresh: XorShift64 = seeder.random();
+		let x = rng.random_u64();
+		assert_ne!(x, raw.get());
+		assert_eq!(x, fresh.get());
+		assert_eq!(rng.bytes_until_reseed(), 24);
+	}
+
+	#[test]
+	fn test_straddle() {
+		let mut seeder = SplitMix64::new(2);
+		let raw: XorShift64 = seeder.clone().random();
+		let mut rng = Reseeding::<XorShift64, _>::new_from(seeder, 12);
+		seeder.random::<XorShift64>();
+
+		let mut buf = [0u8; 20];
+		rng.random_bytes(&mut buf);
+
+		let mut check = [0u8; 20];
+		raw.clone().random_bytes(&mut check[..12]);
+		let mut next: XorShift64 = seeder.random();
+		next.random_bytes(&mut check[12..]);
+
+		assert_eq!(buf, check);
+		assert_eq!(rng.bytes_until_reseed(), 4);
+
+		// a u64 does not fit in the 4 remaining bytes
+		let mut next: XorShift64 = seeder.random();
+		assert_eq!(rng.random_u64(), next.get());
+		assert_eq!(rng.bytes_until_reseed(), 4);
+
+		rng.random_u32();
+		assert_eq!(rng.bytes_until_reseed(), 0);
+
+		rng.force_reseed();
+		assert_eq!(rng.bytes_until_reseed(), 12);
+	}
+}
@@ -0,0 +1,570 @@
+/// a distribution of values `T`, with parameters.
+/// 
+/// where [`crate::FromRandom`] describes how to make a `T` from nothing but
+/// a [`crate::Random`], a `Sample` carries its own parameters, like the
+/// range of a [`Uniform`] or the weights of a [`WeightedAlias`]. they are
+/// checked, and anything derived from them is computed, once, when the
+/// sampler is constructed, and the same sampler can then be used with any
+/// number of generators.
+/// 
+/// see [`crate::Random::sample_from()`] and [`crate::Random::sample_iter()`].
+/// 
+/// ```
+/// use prrng::{Bernoulli, Random, Sample, Uniform, XorShift64, Pcg32};
+/// 
+/// let coin = Bernoulli::new(0.5);
+/// let angle = Uniform::new(0.0..core::f64::consts::TAU);
+/// 
+/// let mut a = XorShift64::new(1);
+/// let mut b = Pcg32::new(1, 1);
+/// 
+/// let heads = a.sample_from(&coin);
+/// let theta = angle.sample(&mut b);
+/// let thetas: [f64; 8] = core::array::from_fn(|_| b.sample_from(&angle));
+/// ```
+pub trait Sample<T> {
+	/// returns a new value `T`, drawn from `rng`.
+	fn sample(&self, rng: &mut impl crate::Random) -> T;
+}
+
+impl<T, S: Sample<T> + ?Sized> Sample<T> for &S {
+	#[inline]
+	fn sample(&self, rng: &mut impl crate::Random) -> T {
+		(**self).sample(rng)
+	}
+}
+
+/// the uniform distribution over a range of `f64`s.
+/// 
+/// this samples the same values as [`crate::Random::random_range()`].
+/// 
+/// ```
+/// use prrng::{Random, Uniform, XorShift64};
+/// 
+/// let mut rng = XorShift64::new(1);
+/// let x = rng.sample_from(&Uniform::new(-1.0..1.0));
+/// assert!((-1.0..1.0).contains(&x));
+/// ```
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Uniform {
+	start: f64,
+	end: f64,
+}
+
+impl Uniform {
+	/// construct a new `Uniform` over `range`.
+	/// 
+	/// ## panics
+	/// 
+	/// panics if `range.start > range.end`, or if either is `NaN` or infinite.
+	#[inline]
+	#[track_caller]
+	pub const fn new(range: core::ops::Range<f64>) -> Self {
+		assert!(!range.start.is_nan() && !range.end.is_nan(), "`range` must not contain NaN");
+		assert!(range.start <= range.end, "`range` must not be inverted");
+		assert!(range.start.is_finite() && range.end.is_finite(), "`range` must be finite");
+		Self {
+			start: range.start,
+			end: range.end,
+		}
+	}
+
+	/// the range of the distribution.
+	#[inline]
+	pub const fn range(&self) -> core::ops::Range<f64> {
+		self.start..self.end
+	}
+}
+
+impl Sample<f64> for Uniform {
+	#[inline]
+	fn sample(&self, rng: &mut impl crate::Random) -> f64 {
+		rng.random_range(self.range())
+	}
+}
+
+/// the [bernoulli distribution](https://en.wikipedia.org/wiki/Bernoulli_distribution),
+/// which is `true` with probability `p`.
+/// 
+/// ```
+/// use prrng::{Bernoulli, Random, XorShift64};
+/// 
+/// let mut rng = XorShift64::new(1);
+/// let always = Bernoulli::new(1.0);
+/// assert!(rng.sample_from(&always));
+/// ```
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Bernoulli {
+	p: f64,
+}
+
+impl Bernoulli {
+	/// construct a new `Bernoulli`.
+	/// 
+	/// ## panics
+	/// 
+	/// panics if `p` is not within `0 ..= 1`.
+	#[inline]
+	#[track_caller]
+	pub const fn new(p: f64) -> Self {
+		assert!(p >= 0.0 && p <= 1.0, "`p` must be within `0 ..= 1`");
+		Self { p }
+	}
+
+	/// the probability of `true`.
+	#[inline]
+	pub const fn p(&self) -> f64 {
+		self.p
+	}
+}
+
+impl Sample<bool> for Bernoulli {
+	#[inline]
+	fn sample(&self, rng: &mut impl crate::Random) -> bool {
+		// `random_f64()` is never `1`, so `p == 1` is always `true`
+		rng.random_f64() < self.p
+	}
+}
+
+/// the [normal distribution](https://en.wikipedia.org/wiki/Normal_distribution).
+/// 
+/// every sample runs the box-muller transform, and throws away the second
+/// value it makes, since a `Sample` can't keep it for the next call. for a
+/// long run of values from a single generator, [`crate::NormalIter`] is
+/// about twice as fast.
+/// 
+/// ```
+/// use prrng::{Normal, Random, XorShift64};
+/// 
+/// let mut rng = XorShift64::new(1);
+/// let height = Normal::new(170.0, 8.0);
+/// 
+/// let n = 10000;
+/// let mean = rng.sample_iter(&height).take(n).sum::<f64>() / n as f64;
+/// assert!((mean - 170.0).abs() < 0.5);
+/// ```
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Normal {
+	mean: f64,
+	std_dev: f64,
+}
+
+impl Normal {
+	/// construct a new `Normal`.
+	/// 
+	/// ## panics
+	/// 
+	/// panics if `std_dev` is negative or NaN.
+	#[inline]
+	#[track_caller]
+	pub const fn new(mean: f64, std_dev: f64) -> Self {
+		assert!(std_dev >= 0.0, "`std_dev` must not be negative");
+		Self { mean, std_dev }
+	}
+
+	/// the mean of the distribution.
+	#[inline]
+	pub const fn mean(&self) -> f64 {
+		self.mean
+	}
+
+	/// the standard deviation of the distribution.
+	#[inline]
+	pub const fn std_dev(&self) -> f64 {
+		self.std_dev
+	}
+}
+
+impl Sample<f64> for Normal {
+	#[inline]
+	fn sample(&self, rng: &mut impl crate::Random) -> f64 {
+		let (z, _) = crate::utility::normal::standard_normal_pair(rng);
+		self.mean + self.std_dev * z
+	}
+}
+
+/// a discrete distribution over the indices `0 .. N`, each with its own
+/// weight.
+/// 
+/// this uses [vose's alias method](https://en.wikipedia.org/wiki/Alias_method),
+/// which builds a table in `O(N)` when constructed, after which every sample
+/// takes `O(1)`: one index, and one `f64` to choose between that index and
+/// its alias.
+/// 
+/// ```
+/// use prrng::{Random, WeightedAlias, XorShift64};
+/// 
+/// let mut rng = XorShift64::new(1);
+/// let loot = ["common", "rare", "legendary"];
+/// let table = WeightedAlias::new([90.0, 9.0, 1.0]);
+/// 
+/// let drop = loot[rng.sample_from(&table)];
+/// ```
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct WeightedAlias<const N: usize> {
+	// the chance of keeping column `i`, instead of taking `alias[i]`
+	prob: [f64; N],
+	alias: [usize; N],
+}
+
+impl<const N: usize> WeightedAlias<N> {
+	/// construct a new `WeightedAlias`, where index `i` is sampled with a
+	/// probability of `weights[i]` over the sum of `weights`.
+	/// 
+	/// ## panics
+	/// 
+	/// panics if any weight is negative or not finite, or if they sum to `0`.
+	#[track_caller]
+	pub fn new(weights: [f64; N]) -> Self {
+		assert!(weights.iter().all(|w| *w >= 0.0 && w.is_finite()), "`weights` must be finite and not negative");
+		let sum: f64 = weights.iter().sum();
+		assert!(sum > 0.0 && sum.is_finite(), "`weights` must sum to more than 0");
+
+		// scale so that the average weight is `1`
+		let mut scaled = weights.map(|w| w * N as f64 / sum);
+		let mut prob = [1.0; N];
+		let mut alias: [usize; N] = core::array::from_fn(|i| i);
+
+		let mut small = [0; N];
+		let mut large = [0; N];
+		let (mut n_small, mut n_large) = (0, 0);
+		for (i, p) in scaled.iter().enumerate() {
+			if *p < 1.0 {
+				small[n_small] = i;
+				n_small += 1;
+			} else {
+				large[n_large] = i;
+				n_large += 1;
+			}
+		}
+
+		// fill each short column with some of a tall one
+		while n_small > 0 && n_large > 0 {
+			n_small -= 1;
+			n_large -= 1;
+			let l = small[n_small];
+			let g = large[n_large];
+
+			prob[l] = scaled[l];
+			alias[l] = g;
+			scaled[g] = (scaled[g] + scaled[l]) - 1.0;
+			if scaled[g] < 1.0 {
+				small[n_small] = g;
+				n_small += 1;
+			} else {
+				large[n_large] = g;
+				n_large += 1;
+			}
+		}
+
+		// whatever is left over is `1`, give or take rounding, and keeps its
+		// initial `prob` of `1`
+		Self { prob, alias }
+	}
+
+	/// how many indices there are.
+	#[inline]
+	pub const fn len(&self) -> usize {
+		N
+	}
+
+	/// whether there are no indices, which can't be constructed.
+	#[inline]
+	pub const fn is_empty(&self) -> bool {
+		N == 0
+	}
+}
+
+impl<const N: usize> Sample<usize> for WeightedAlias<N> {
+	#[inline]
+	fn sample(&self, rng: &mut impl crate::Random) -> usize {
+		let i = rng.random_u64_bound(N as u64) as usize;
+		if rng.random_f64() < self.prob[i] {
+			i
+		} else {
+			self.alias[i]
+		}
+	}
+}
+
+macro_rules! impl_uniform_int {
+	($name:ident, $t:ty, $method:ident, $bound:ident) => {
+		#[doc = concat!("the uniform distribution over a range of `", stringify!($t), "`s.")]
+		/// 
+		#[doc = concat!("[`crate::Random::", stringify!($bound), "()`] works out its rejection")]
+		/// threshold on every call, which costs an integer division. this works
+		/// it out once, in [`Self::new()`]. it uses the same algorithm, so it
+		/// samples the exact same values, from the exact same draws:
+		#[doc = concat!("`", stringify!($name), "::new(low, high).sample(rng)` is")]
+		#[doc = concat!("`low + rng.", stringify!($bound), "(high - low)`.")]
+		/// 
+		/// ```
+		#[doc = concat!("use prrng::{Random, ", stringify!($name), ", XorShift64};")]
+		/// 
+		/// let mut a = XorShift64::new(1);
+		/// let mut b = XorShift64::new(1);
+		/// 
+		#[doc = concat!("let die = ", stringify!($name), "::new(1, 7);")]
+		/// for _ in 0..100 {
+		#[doc = concat!("    assert_eq!(die.sample(&mut a), 1 + b.", stringify!($bound), "(6));")]
+		/// }
+		/// ```
+		#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+		pub struct $name {
+			low: $t,
+			range: $t,
+			threshold: $t,
+		}
+
+		impl $name {
+			#[doc = concat!("construct a new `", stringify!($name), "` over `low .. high`.")]
+			/// 
+			/// ## panics
+			/// 
+			/// panics if `low` is not less than `high`.
+			#[inline]
+			#[track_caller]
+			pub const fn new(low: $t, high: $t) -> Self {
+				assert!(low < high, "`low` must be less than `high`");
+				let range = high - low;
+				Self {
+					low,
+					range,
+					threshold: range.wrapping_neg() % range,
+				}
+			}
+
+			/// the smallest value that can be sampled.
+			#[inline]
+			pub const fn low(&self) -> $t {
+				self.low
+			}
+
+			/// one past the largest value that can be sampled.
+			#[inline]
+			pub const fn high(&self) -> $t {
+				self.low + self.range
+			}
+
+			#[doc = concat!("returns a new `", stringify!($t), "`, uniformly distributed within `low .. high`.")]
+			#[inline]
+			pub fn sample(&self, rng: &mut impl crate::Random) -> $t {
+				loop {
+					let x = rng.$method();
+					if x >= self.threshold {
+						return self.low + x % self.range;
+					}
+				}
+			}
+		}
+
+		impl Sample<$t> for $name {
+			#[inline]
+			fn sample(&self, rng: &mut impl crate::Random) -> $t {
+				$name::sample(self, rng)
+			}
+		}
+	};
+}
+
+impl_uniform_int!(UniformInt32, u32, random_u32, random_u32_bound);
+impl_uniform_int!(UniformInt64, u64, random_u64, random_u64_bound);
+
+/// infinite iterator of values from a [`Sample`]. see
+/// [`crate::Random::sample_iter()`].
+/// 
+/// ```
+/// # use prrng::XorShift64;
+/// use prrng::{Random, Uniform};
+/// let mut rng = XorShift64::new(1);
+/// 
+/// let points: [f64; 16] = core::array::from_fn({
+///     let mut iter = rng.sample_iter(Uniform::new(0.0..100.0));
+///     move |_| iter.next().unwrap()
+/// });
+/// ```
+pub struct SampleIter<'a, T, S: Sample<T>, R: crate::Random> {
+	inner: &'a mut R,
+	sampler: S,
+	_phantom: core::marker::PhantomData<fn() -> T>,
+}
+
+impl<'a, T, S: Sample<T>, R: crate::Random> SampleIter<'a, T, S, R> {
+	/// construct a new `SampleIter`.
+	#[inline]
+	pub fn new(inner: &'a mut R, sampler: S) -> Self {
+		Self {
+			inner,
+			sampler,
+			_phantom: core::marker::PhantomData,
+		}
+	}
+
+	/// the sampler values are drawn from.
+	#[inline]
+	pub fn sampler(&self) -> &S {
+		&self.sampler
+	}
+}
+
+impl<T, S: Sample<T>, R: crate::Random> Iterator for SampleIter<'_, T, S, R> {
+	type Item = T;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		Some(self.sampler.sample(self.inner))
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		(usize::MAX, None)
+	}
+}
+
+impl<T, S: Sample<T>, R: crate::Random> core::iter::FusedIterator for SampleIter<'_, T, S, R> {}
+
+impl<T, S: Sample<T> + core::fmt::Debug, R: crate::Random + core::fmt::Debug> core::fmt::Debug for SampleIter<'_, T, S, R> {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		write!(f, "SampleIter<{:?}>({:?})", self.sampler, self.inner)
+	}
+}
+
+
+#[cfg(test)]
+mod test {
+	use crate::{Bernoulli, ChaChaRng, Normal, Pcg32, Random, Sample, Uniform, UniformInt32, UniformInt64, WeightedAlias, XorShift64};
+
+	#[test]
+	fn test_shared() {
+		// one of each sampler, used with several generators
+		let uniform = Uniform::new(-2.0..3.0);
+		let coin = Bernoulli::new(0.25);
+		let normal = Normal::new(5.0, 2.0);
+		let table = WeightedAlias::new([1.0, 0.0, 3.0, 4.0]);
+
+		fn check(rng: &mut impl Random, uniform: &Uniform, coin: &Bernoulli, normal: &Normal, table: &WeightedAlias<4>) {
+			let n = 20000;
+
+			let mut sum = 0.0;
+			for _ in 0..n {
+				let x = rng.sample_from(uniform);
+				assert!((-2.0..3.0).contains(&x), "{x}");
+				sum += x;
+			}
+			let mean = sum / n as f64;
+			assert!((mean - 0.5).abs() < 0.05, "{mean}");
+
+			let heads = rng.sample_iter(coin).take(n).filter(|x| *x).count();
+			assert!((4700..5300).contains(&heads), "{heads}");
+
+			let mean = rng.sample_iter(normal).take(n).sum::<f64>() / n as f64;
+			assert!((mean - 5.0).abs() < 0.05, "{mean}");
+
+			let mut counts = [0; 4];
+			for _ in 0..n {
+				counts[table.sample(rng)] += 1;
+			}
+			assert_eq!(counts[1], 0);
+			assert!((2300..2700).contains(&counts[0]), "{counts:?}");
+			assert!((7200..7800).contains(&counts[2]), "{counts:?}");
+			assert!((9700..10300).contains(&counts[3]), "{counts:?}");
+		}
+
+		check(&mut XorShift64::new(1), &uniform, &coin, &normal, &table);
+		check(&mut Pcg32::new(1, 1), &uniform, &coin, &normal, &table);
+		check(&mut ChaChaRng::new([1; 32]), &uniform, &coin, &normal, &table);
+	}
+
+	#[test]
+	fn test_deterministic() {
+		let table = WeightedAlias::new([0.5, 2.0, 0.25, 1.0, 0.0, 3.0]);
+		let mut a = XorShift64::new(1);
+		let mut b = XorShift64::new(1);
+		assert!(a.sample_iter(&table).take(256).eq(b.sample_iter(&table).take(256)));
+
+		// `Uniform` samples the same values as `random_range()`
+		let uniform = Uniform::new(1.0..4.0);
+		for _ in 0..256 {
+			assert_eq!(a.sample_from(&uniform), b.random_range(1.0..4.0));
+		}
+	}
+
+	#[test]
+	fn test_edges() {
+		let mut rng = XorShift64::new(1);
+
+		let never = Bernoulli::new(0.0);
+		let always = Bernoulli::new(1.0);
+		let point = Uniform::new(2.0..2.0);
+		let exact = Normal::new(-1.5, 0.0);
+		let single = WeightedAlias::new([0.0, 7.0, 0.0]);
+		for _ in 0..1000 {
+			assert!(!rng.sample_from(&never));
+			assert!(rng.sample_from(&always));
+			assert_eq!(rng.sample_from(&point), 2.0);
+			assert_eq!(rng.sample_from(&exact), -1.5);
+			assert_eq!(rng.sample_from(&single), 1);
+		}
+	}
+
+	#[test]
+	#[should_panic = "`p` must be within `0 ..= 1`"]
+	fn test_bernoulli_p() {
+		Bernoulli::new(1.5);
+	}
+
+	#[test]
+	#[should_panic = "`weights` must sum to more than 0"]
+	fn test_alias_zero() {
+		WeightedAlias::new([0.0, 0.0]);
+	}
+
+	#[test]
+	#[should_panic = "`weights` must be finite and not negative"]
+	fn test_alias_negative() {
+		WeightedAlias::new([1.0, -1.0]);
+	}
+
+	#[test]
+	fn test_uniform_int() {
+		// the same draws as `random_uN_bound()`, so the same values
+		let mut a = XorShift64::new(1);
+		let mut b = XorShift64::new(1);
+		for (low, high) in [(0, 1), (0, 3), (5, 6), (10, 17), (0, (1 << 31) + 1), (1, u32::MAX), (0, u32::MAX)] {
+			let u = UniformInt32::new(low, high);
+			assert_eq!((u.low(), u.high()), (low, high));
+			for _ in 0..1000 {
+				let x = u.sample(&mut a);
+				assert_eq!(x, low + b.random_u32_bound(high - low));
+				assert!((low..high).contains(&x));
+			}
+		}
+		for (low, high) in [(0, 1), (0, 3), (5, 6), (10, 17), (0, (1 << 63) + 1), (1, u64::MAX), (0, u64::MAX)] {
+			let u = UniformInt64::new(low, high);
+			assert_eq!((u.low(), u.high()), (low, high));
+			for _ in 0..1000 {
+				let x = u.sample(&mut a);
+				assert_eq!(x, low + b.random_u64_bound(high - low));
+				assert!((low..high).contains(&x));
+			}
+		}
+
+		// and through `Sample`, with other generators
+		let die = UniformInt32::new(1, 7);
+		let mut c = Pcg32::new(1, 1);
+		let mut d = Pcg32::new(1, 1);
+		assert!(c.sample_iter(&die).take(256).eq((0..256).map(|_| 1 + d.random_u32_bound(6))));
+
+		let mut counts = [0; 6];
+		for x in ChaChaRng::new([1; 32]).sample_iter(UniformInt64::new(100, 106)).take(60000) {
+			counts[(x - 100) as usize] += 1;
+		}
+		assert!(counts.iter().all(|n| (9700..10300).contains(n)), "{counts:?}");
+	}
+
+	#[test]
+	#[should_panic = "`low` must be less than `high`"]
+	fn test_uniform_int_empty() {
+		UniformInt32::new(4, 4);
+	}
+}
@@ -0,0 +1,286 @@
+/// utility type for drawing items "randomly, but fairly".
+/// 
+/// every item is drawn exactly once, in a random order, before any item is
+/// drawn again. once the bag is empty, it is refilled with every item and
+/// reshuffled. this avoids the long streaks and droughts of drawing
+/// uniformly, which is often what players expect from "random", like
+/// tetris pieces or loot drops.
+/// 
+/// the shuffle is a [fisher-yates shuffle](https://en.wikipedia.org/wiki/Fisher%E2%80%93Yates_shuffle)
+/// done one step per [`Self::draw()`], so every order is equally likely,
+/// and no draw costs more than another.
+/// 
+/// for a bag that can grow, see `ShuffleBagVec`, with the `alloc` feature.
+/// 
+/// ```
+/// use prrng::{ShuffleBag, XorShift64};
+/// 
+/// let mut rng = XorShift64::new(1);
+/// let mut pieces = ShuffleBag::new(['I', 'O', 'T', 'S', 'Z', 'J', 'L']);
+/// 
+/// let mut seen = [*pieces.draw(&mut rng); 7];
+/// for i in 1..7 {
+///     seen[i] = *pieces.draw(&mut rng);
+/// }
+/// seen.sort();
+/// assert_eq!(seen, ['I', 'J', 'L', 'O', 'S', 'T', 'Z']);
+/// ```
+#[derive(Clone, Debug)]
+pub struct ShuffleBag<T, const N: usize> {
+	// `items[..remaining]` have not been drawn yet
+	items: [T; N],
+	remaining: usize,
+}
+
+impl<T, const N: usize> ShuffleBag<T, N> {
+	/// construct a new, full `ShuffleBag`.
+	#[inline]
+	pub const fn new(items: [T; N]) -> Self {
+		Self {
+			items,
+			remaining: N,
+		}
+	}
+
+	/// draw an item, refilling the bag first if it is empty.
+	/// 
+	/// ## panics
+	/// 
+	/// panics if `N` is `0`.
+	#[inline]
+	#[track_caller]
+	pub fn draw(&mut self, rng: &mut impl crate::Random) -> &T {
+		assert!(N != 0, "`ShuffleBag` has no items");
+		if self.remaining == 0 {
+			self.remaining = N;
+		}
+		let i = rng.random_u64_bound(self.remaining as u64) as usize;
+		self.remaining -= 1;
+		self.items.swap(i, self.remaining);
+		&self.items[self.remaining]
+	}
+
+	/// how many items can be drawn before the bag is refilled.
+	#[inline]
+	pub const fn remaining(&self) -> usize {
+		self.remaining
+	}
+
+	/// put every drawn item back in the bag.
+	#[inline]
+	pub const fn reset(&mut self) {
+		self.remaining = N;
+	}
+
+	/// consume `self`, returning every item, in no particular order.
+	#[inline]
+	pub fn unwrap(self) -> [T; N] {
+		self.items
+	}
+}
+
+/// utility type for drawing items "randomly, but fairly", which can grow.
+/// 
+/// this is the same as [`ShuffleBag`], but backed by a
+/// [`Vec`](alloc::vec::Vec), so items can be added with [`Self::push()`].
+/// 
+/// this requires the `alloc` feature.
+/// 
+/// ```
+/// use prrng::{ShuffleBagVec, XorShift64};
+/// 
+/// let mut rng = XorShift64::new(1);
+/// let mut quests = ShuffleBagVec::new();
+/// quests.push("fetch");
+/// quests.push("escort");
+/// 
+/// let quest = *quests.draw(&mut rng);
+/// quests.push("defend");
+/// assert_eq!(quests.remaining(), 2);
+/// ```
+#[cfg(any(test, feature = "alloc"))]
+#[derive(Clone, Debug, Default)]
+pub struct ShuffleBagVec<T> {
+	// `items[..remaining]` have not been drawn yet
+	items: alloc::vec::Vec<T>,
+	remaining: usize,
+}
+
+#[cfg(any(test, feature = "alloc"))]
+impl<T> ShuffleBagVec<T> {
+	/// construct a new, empty `ShuffleBagVec`.
+	#[inline]
+	pub const fn new() -> Self {
+		Self {
+			items: alloc::vec::Vec::new(),
+			remaining: 0,
+		}
+	}
+
+	/// add `item` to the bag. it can be drawn before the bag is next
+	/// refilled.
+	#[inline]
+	pub fn push(&mut self, item: T) {
+		self.items.push(item);
+		let last = self.items.len() - 1;
+		self.items.swap(self.remaining, last);
+		self.remaining += 1;
+	}
+
+	/// draw an item, refilling the bag first if it is empty.
+	/// 
+	/// ## panics
+	/// 
+	/// panics if there are no items.
+	#[inline]
+	#[track_caller]
+	pub fn draw(&mut self, rng: &mut impl crate::Random) -> &T {
+		assert!(!self.items.is_empty(), "`ShuffleBagVec` has no items");
+		if self.remaining == 0 {
+			self.remaining = self.items.len();
+		}
+		let i = rng.random_u64_bound(self.remaining as u64) as usize;
+		self.remaining -= 1;
+		self.items.swap(i, self.remaining);
+		&self.items[self.remaining]
+	}
+
+	/// how many items can be drawn before the bag is refilled.
+	#[inline]
+	pub fn remaining(&self) -> usize {
+		self.remaining
+	}
+
+	/// put every drawn item back in the bag.
+	#[inline]
+	pub fn reset(&mut self) {
+		self.remaining = self.items.len();
+	}
+
+	/// how many items there are, drawn or not.
+	#[inline]
+	pub fn len(&self) -> usize {
+		self.items.len()
+	}
+
+	/// whether there are no items.
+	#[inline]
+	pub fn is_empty(&self) -> bool {
+		self.items.is_empty()
+	}
+
+	/// consume `self`, returning every item, in no particular order.
+	#[inline]
+	pub fn unwrap(self) -> alloc::vec::Vec<T> {
+		self.items
+	}
+}
+
+#[cfg(any(test, feature = "alloc"))]
+impl<T> From<alloc::vec::Vec<T>> for ShuffleBagVec<T> {
+	/// construct a new, full `ShuffleBagVec`.
+	#[inline]
+	fn from(items: alloc::vec::Vec<T>) -> Self {
+		Self {
+			remaining: items.len(),
+			items,
+		}
+	}
+}
+
+
+#[cfg(test)]
+mod test {
+	use crate::{ShuffleBag, ShuffleBagVec, XorShift64};
+
+	#[test]
+	fn test_fair() {
+		let mut rng = XorShift64::new(1);
+		let mut bag = ShuffleBag::new([0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+
+		let mut counts = [0; 10];
+		for round in 0..50 {
+			let mut seen = [false; 10];
+			for _ in 0..10 {
+				let x = *bag.draw(&mut rng);
+				assert!(!seen[x], "{x} repeated in round {round}");
+				seen[x] = true;
+				counts[x] += 1;
+			}
+			assert_eq!(bag.remaining(), 0);
+		}
+		assert_eq!(counts, [50; 10]);
+
+		// the same for the growable bag, with an item added mid round
+		let mut bag = ShuffleBagVec::from(std::vec![0, 1, 2]);
+		let first = *bag.draw(&mut rng);
+		bag.push(3);
+		assert_eq!(bag.remaining(), 3);
+		let mut seen = [first, *bag.draw(&mut rng), *bag.draw(&mut rng), *bag.draw(&mut rng)];
+		seen.sort();
+		assert_eq!(seen, [0, 1, 2, 3]);
+		assert_eq!(bag.remaining(), 0);
+
+		let mut counts = [0; 4];
+		for _ in 0..400 {
+			counts[*bag.draw(&mut rng)] += 1;
+		}
+		assert_eq!(counts, [100; 4]);
+	}
+
+	#[test]
+	fn test_deterministic() {
+		let mut a = ShuffleBag::new(['a', 'b', 'c', 'd', 'e']);
+		let mut b = a.clone();
+		let mut rng_a = XorShift64::new(1);
+		let mut rng_b = XorShift64::new(1);
+		for _ in 0..23 {
+			assert_eq!(a.draw(&mut rng_a), b.draw(&mut rng_b));
+			assert_eq!(a.remaining(), b.remaining());
+		}
+
+		// the order of each round differs
+		a.reset();
+		let mut rng = XorShift64::new(1);
+		let mut rounds = [['\0'; 5]; 8];
+		for round in &mut rounds {
+			for x in round.iter_mut() {
+				*x = *a.draw(&mut rng);
+			}
+		}
+		assert!(rounds.iter().any(|x| *x != rounds[0]));
+	}
+
+	#[test]
+	fn test_reset() {
+		let mut rng = XorShift64::new(1);
+		let mut bag = ShuffleBag::new([1, 2, 3]);
+		bag.draw(&mut rng);
+		bag.draw(&mut rng);
+		assert_eq!(bag.remaining(), 1);
+		bag.reset();
+		assert_eq!(bag.remaining(), 3);
+
+		let mut items = bag.unwrap();
+		items.sort();
+		assert_eq!(items, [1, 2, 3]);
+	}
+
+	#[test]
+	fn test_single() {
+		let mut rng = XorShift64::new(1);
+		let mut bag = ShuffleBag::new(["only"]);
+		let mut vec = ShuffleBagVec::new();
+		vec.push("only");
+		for _ in 0..100 {
+			assert_eq!(*bag.draw(&mut rng), "only");
+			assert_eq!(*vec.draw(&mut rng), "only");
+		}
+	}
+
+	#[test]
+	#[should_panic = "`ShuffleBag` has no items"]
+	fn test_empty() {
+		ShuffleBag::<u8, 0>::new([]).draw(&mut XorShift64::new(1));
+	}
+}
@@ -122,3 +122,408 @@ impl<T: FnMut() -> f64> core::fmt::Debug for Static<T> {
 	}
 }
 
+
+/// like [`Static`], but generating `u64` values directly.
+/// 
+/// [`crate::RandomImpl::random_u64()`] returns exactly what the inner `FnMut`
+/// returns, [`crate::RandomImpl::random_u32()`] returns its low half, and
+/// [`crate::RandomImpl::random_bytes()`] serializes it with little-endian ordering.
+/// 
+/// ```
+/// # use prrng::StaticU64;
+/// use prrng::RandomImpl;
+/// 
+/// let mut rng = StaticU64::new(|| 0xdeadbeef);
+/// assert_eq!(rng.random_u64(), 0xdeadbeef);
+/// assert_eq!(rng.random_u32(), 0xdeadbeef);
+/// ```
+#[derive(Clone)]
+pub struct StaticU64<T: FnMut() -> u64> {
+	cb: T,
+}
+
+impl<T: FnMut() -> u64> StaticU64<T> {
+	/// construct a new [`StaticU64`].
+	#[inline]
+	pub fn new(cb: T) -> Self {
+		Self {
+			cb,
+		}
+	}
+
+	/// returns the next value by calling the inner `FnMut`.
+	#[inline]
+	pub fn get(&mut self) -> u64 {
+		(self.cb)()
+	}
+}
+
+impl<T: FnMut() -> u64> crate::RandomImpl for StaticU64<T> {
+	#[inline]
+	fn random_u64(&mut self) -> u64 {
+		self.get()
+	}
+
+	#[inline]
+	fn random_u32(&mut self) -> u32 {
+		self.get() as u32
+	}
+
+	fn random_bytes(&mut self, dst: &mut [u8]) {
+		crate::common::bytes_from_u64(self, dst);
+	}
+}
+
+impl<T: FnMut() -> u64> core::fmt::Debug for StaticU64<T> {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		write!(f, "StaticU64")
+	}
+}
+
+/// like [`Static`], but generating bytes directly.
+/// 
+/// [`crate::RandomImpl::random_bytes()`] passes the destination straight to the
+/// inner `FnMut`, and [`crate::RandomImpl::random_u64()`] and
+/// [`crate::RandomImpl::random_u32()`] are built from bytes with little-endian ordering.
+/// 
+/// ```
+/// # use prrng::StaticBytes;
+/// use prrng::RandomImpl;
+/// 
+/// let mut rng = StaticBytes::new(|dst: &mut [u8]| {
+///     for (i, o) in dst.iter_mut().enumerate() {
+///         *o = i as u8;
+///     }
+/// });
+/// assert_eq!(rng.random_u32(), 0x03020100);
+/// ```
+#[derive(Clone)]
+pub struct StaticBytes<T: FnMut(&mut [u8])> {
+	cb: T,
+}
+
+impl<T: FnMut(&mut [u8])> StaticBytes<T> {
+	/// construct a new [`StaticBytes`].
+	#[inline]
+	pub fn new(cb: T) -> Self {
+		Self {
+			cb,
+		}
+	}
+
+	/// fills `dst` by calling the inner `FnMut`.
+	#[inline]
+	pub fn get(&mut self, dst: &mut [u8]) {
+		(self.cb)(dst)
+	}
+}
+
+impl<T: FnMut(&mut [u8])> crate::RandomImpl for StaticBytes<T> {
+	#[inline]
+	fn random_u64(&mut self) -> u64 {
+		crate::common::u64_from_bytes(self)
+	}
+
+	#[inline]
+	fn random_u32(&mut self) -> u32 {
+		crate::common::u32_from_bytes(self)
+	}
+
+	#[inline]
+	fn random_bytes(&mut self, dst: &mut [u8]) {
+		self.get(dst);
+	}
+}
+
+impl<T: FnMut(&mut [u8])> core::fmt::Debug for StaticBytes<T> {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		write!(f, "StaticBytes")
+	}
+}
+
+
+/// like [`Static`], but cycling through a slice of values.
+/// 
+/// scripted sequences ("first 0.1, then 0.9, then 0.5") are common in tests,
+/// and writing them as an `FnMut` with captured state is tedious.
+/// `StaticSeq` returns the values of a slice in order, then either starts over
+/// (see [`Self::new()`]), or panics (see [`Self::once()`]).
+/// 
+/// cloning a `StaticSeq` preserves its position.
+/// 
+/// ```
+/// # use prrng::StaticSeq;
+/// let mut rng = StaticSeq::new(&[0.1, 0.9, 0.5]);
+/// assert_eq!(rng.get(), 0.1);
+/// assert_eq!(rng.get(), 0.9);
+/// assert_eq!(rng.get(), 0.5);
+/// assert_eq!(rng.get(), 0.1);
+/// ```
+#[derive(Clone)]
+pub struct StaticSeq<'a> {
+	values: &'a [f64],
+	index: usize,
+	once: bool,
+}
+
+impl<'a> StaticSeq<'a> {
+	/// construct a new [`StaticSeq`], which starts over once every value
+	/// has been returned.
+	#[inline]
+	pub const fn new(values: &'a [f64]) -> Self {
+		Self {
+			values,
+			index: 0,
+			once: false,
+		}
+	}
+
+	/// construct a new [`StaticSeq`], which panics once every value has
+	/// been returned.
+	/// 
+	/// ```should_panic
+	/// # use prrng::StaticSeq;
+	/// let mut rng = StaticSeq::once(&[0.5]);
+	/// assert_eq!(rng.get(), 0.5);
+	/// rng.get(); // panics
+	/// ```
+	#[inline]
+	pub const fn once(values: &'a [f64]) -> Self {
+		Self {
+			values,
+			index: 0,
+			once: true,
+		}
+	}
+
+	/// the index of the next value.
+	#[inline]
+	pub const fn position(&self) -> usize {
+		self.index
+	}
+
+	/// returns the next value.
+	/// 
+	/// ## panics
+	/// 
+	/// panics if the slice is empty, or if constructed with [`Self::once()`]
+	/// and every value has been returned.
+	#[inline]
+	#[track_caller]
+	pub fn get(&mut self) -> f64 {
+		let i = seq_next(self.values.len(), &mut self.index, self.once);
+		self.values[i]
+	}
+}
+
+impl crate::RandomImpl for StaticSeq<'_> {
+	#[inline]
+	fn random_u64(&mut self) -> u64 {
+		crate::common::u32_compose_u64(self.random_u32(), self.random_u32())
+	}
+
+	#[inline]
+	fn random_u32(&mut self) -> u32 {
+		crate::common::f64_to_u32(self.get())
+	}
+
+	fn random_bytes(&mut self, dst: &mut [u8]) {
+		crate::common::bytes_from_u32(self, dst);
+	}
+}
+
+impl core::fmt::Debug for StaticSeq<'_> {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		write!(f, "StaticSeq({}/{})", self.index, self.values.len())
+	}
+}
+
+/// like [`StaticSeq`], but with `u64` values, as with [`StaticU64`].
+/// 
+/// ```
+/// # use prrng::StaticSeqU64;
+/// use prrng::RandomImpl;
+/// 
+/// let mut rng = StaticSeqU64::new(&[1, 2]);
+/// assert_eq!(rng.random_u64(), 1);
+/// assert_eq!(rng.random_u32(), 2);
+/// assert_eq!(rng.random_u64(), 1);
+/// ```
+#[derive(Clone)]
+pub struct StaticSeqU64<'a> {
+	values: &'a [u64],
+	index: usize,
+	once: bool,
+}
+
+impl<'a> StaticSeqU64<'a> {
+	/// construct a new [`StaticSeqU64`], which starts over once every value
+	/// has been returned.
+	#[inline]
+	pub const fn new(values: &'a [u64]) -> Self {
+		Self {
+			values,
+			index: 0,
+			once: false,
+		}
+	}
+
+	/// construct a new [`StaticSeqU64`], which panics once every value has
+	/// been returned.
+	#[inline]
+	pub const fn once(values: &'a [u64]) -> Self {
+		Self {
+			values,
+			index: 0,
+			once: true,
+		}
+	}
+
+	/// the index of the next value.
+	#[inline]
+	pub const fn position(&self) -> usize {
+		self.index
+	}
+
+	/// returns the next value.
+	/// 
+	/// ## panics
+	/// 
+	/// panics if the slice is empty, or if constructed with [`Self::once()`]
+	/// and every value has been returned.
+	#[inline]
+	#[track_caller]
+	pub fn get(&mut self) -> u64 {
+		let i = seq_next(self.values.len(), &mut self.index, self.once);
+		self.values[i]
+	}
+}
+
+impl crate::RandomImpl for StaticSeqU64<'_> {
+	#[inline]
+	fn random_u64(&mut self) -> u64 {
+		self.get()
+	}
+
+	#[inline]
+	fn random_u32(&mut self) -> u32 {
+		self.get() as u32
+	}
+
+	fn random_bytes(&mut self, dst: &mut [u8]) {
+		crate::common::bytes_from_u64(self, dst);
+	}
+}
+
+impl core::fmt::Debug for StaticSeqU64<'_> {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		write!(f, "StaticSeqU64({}/{})", self.index, self.values.len())
+	}
+}
+
+// returns the index of the next value, and moves `index` past it, wrapping
+// or panicking as needed. `index` only reaches `len` with `once`.
+#[inline]
+#[track_caller]
+fn seq_next(len: usize, index: &mut usize, once: bool) -> usize {
+	assert!(len > 0, "sequence is empty");
+	assert!(*index < len, "sequence has been exhausted");
+
+	let i = *index;
+	*index = if i + 1 == len && !once { 0 } else { i + 1 };
+	i
+}
+
+
+#[cfg(test)]
+mod test {
+	use crate::{Random, RandomImpl, StaticBytes, StaticSeq, StaticSeqU64, StaticU64};
+
+	#[test]
+	fn test_exact() {
+		let mut rng = StaticU64::new(|| 0x0123456789abcdef);
+		assert_eq!(rng.random_u64(), 0x0123456789abcdef);
+		assert_eq!(rng.random_u32(), 0x89abcdef);
+
+		let mut buf = [0u8; 10];
+		rng.random_bytes(&mut buf);
+		assert_eq!(buf, [0xef, 0xcd, 0xab, 0x89, 0x67, 0x45, 0x23, 0x01, 0xef, 0xcd]);
+
+		let mut rng = StaticBytes::new(|dst: &mut [u8]| dst.fill(0xab));
+		assert_eq!(rng.random_u64(), 0xabababababababab);
+		assert_eq!(rng.random_u32(), 0xabababab);
+
+		let mut buf = [0u8; 3];
+		rng.random_bytes(&mut buf);
+		assert_eq!(buf, [0xab; 3]);
+
+		let object: &mut dyn RandomImpl = &mut rng;
+		assert_eq!(object.random_u32(), 0xabababab);
+	}
+
+	#[test]
+	fn test_compose() {
+		extern crate std;
+		use std::hash::{BuildHasherDefault, DefaultHasher};
+
+		let mut i = 0;
+		let mut rng = StaticU64::new(|| {
+			i += 1;
+			i
+		}).random_into_buffer::<u64, 4>();
+
+		assert_eq!(rng.get(), 1);
+		assert_eq!(rng.get(), 2);
+
+		let mut crush = StaticU64::new(|| 7)
+			.random_into_crush::<2>(BuildHasherDefault::<DefaultHasher>::default());
+		assert_ne!(crush.random_u64(), crush.random_u64());
+	}
+
+	#[test]
+	fn test_seq() {
+		let mut rng = StaticSeqU64::new(&[1, 2, 3]);
+		assert_eq!(rng.get(), 1);
+
+		let mut other = rng.clone();
+		for _ in 0..7 {
+			assert_eq!(rng.get(), other.get());
+		}
+		assert_eq!(rng.position(), 2);
+		assert_eq!(rng.get(), 3);
+		assert_eq!(rng.get(), 1);
+
+		// after exactly a full cycle, the next value is the first again
+		let mut rng = StaticSeq::new(&[0.1, 0.2, 0.3]);
+		for _ in 0..2 {
+			for i in 0..3 {
+				assert_eq!(rng.position(), i);
+				rng.get();
+			}
+			assert_eq!(rng.position(), 0);
+			assert_eq!(std::format!("{rng:?}"), "StaticSeq(0/3)");
+		}
+		let mut rng = StaticSeqU64::new(&[7]);
+		rng.get();
+		assert_eq!(rng.position(), 0);
+
+		// unless it doesn't start over
+		let mut rng = StaticSeqU64::once(&[1, 2]);
+		rng.get();
+		rng.get();
+		assert_eq!(rng.position(), 2);
+
+		let mut rng = StaticSeq::once(&[0.0, 1.0]);
+		assert_eq!(rng.random_u32(), 0);
+		assert_eq!(rng.random_u32(), u32::MAX);
+	}
+
+	#[test]
+	#[should_panic(expected = "sequence has been exhausted")]
+	fn test_seq_once() {
+		let mut rng = StaticSeqU64::once(&[1, 2]);
+		rng.random_u64();
+		rng.random_u64();
+		rng.random_u64();
+	}
+}
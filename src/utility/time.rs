@@ -0,0 +1,68 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+static COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+/// make a seed from the current time.
+/// 
+/// **this is not secure.** the clock is easy to guess, often to within a
+/// few milliseconds, so anyone who wants to can recover the seed, and every
+/// value derived from it. only use this where nobody is trying to predict
+/// the output, and where runs don't need to be reproducible (in which case,
+/// use a fixed seed instead). for anything else, use a real entropy source,
+/// like the crate `getrandom`.
+/// 
+/// the time is mixed with a process wide counter through
+/// [`crate::SplitMix64`], so calls that read the same clock value, on
+/// platforms with a coarse clock, still get different seeds.
+/// 
+/// this requires the `std` feature.
+/// 
+/// ```
+/// use prrng::{ChaCha, XorShift64, seed_from_time, seed_from_time_bytes};
+/// 
+/// let mut rng = XorShift64::new(seed_from_time());
+/// 
+/// let mut rng = ChaCha::from_key_bytes(&seed_from_time_bytes(), &[0; 12], 0);
+/// ```
+pub fn seed_from_time() -> u64 {
+	let nanos = SystemTime::now()
+		.duration_since(UNIX_EPOCH)
+		.map(|x| x.as_nanos())
+		.unwrap_or(0);
+	let time = crate::SplitMix64::new(nanos as u64 ^ (nanos >> 64) as u64).get();
+
+	// the same `time` gives a different value for every `count`, since this
+	// is `count` steps of one splitmix stream
+	let count = COUNTER.fetch_add(1, Ordering::Relaxed) as u64;
+	crate::SplitMix64::new(time.wrapping_add(count.wrapping_mul(0x9e3779b97f4a7c15))).get()
+}
+
+/// make a 32 byte seed from the current time, for generators that take a
+/// key, like [`crate::ChaCha`].
+/// 
+/// **this is not secure**, and has no more entropy than
+/// [`seed_from_time()`], which it is derived from. see there.
+/// 
+/// this requires the `std` feature.
+pub fn seed_from_time_bytes() -> [u8; 32] {
+	crate::Random::random(&mut crate::SplitMix64::new(seed_from_time()))
+}
+
+
+#[cfg(test)]
+mod test {
+	use crate::{seed_from_time, seed_from_time_bytes};
+
+	#[test]
+	fn test_distinct() {
+		let mut seeds = [0; 64];
+		for i in &mut seeds {
+			*i = seed_from_time();
+		}
+		seeds.sort_unstable();
+		assert!(seeds.windows(2).all(|x| x[0] != x[1]));
+
+		assert_ne!(seed_from_time_bytes(), seed_from_time_bytes());
+	}
+}
@@ -0,0 +1,248 @@
+/// infinite iterator of a random walk over `f64`s.
+/// 
+/// every item is the previous position, plus a step uniformly distributed
+/// within `-step .. step`. the first item is one step away from `start`,
+/// not `start` itself. see [`crate::Random::random_walk()`].
+/// 
+/// ```
+/// # use prrng::XorShift64;
+/// use prrng::Random;
+/// let mut rng = XorShift64::new(1);
+/// 
+/// let mut walk = rng.random_walk(100.0, 2.5);
+/// let prices: [f64; 30] = core::array::from_fn(|_| walk.next().unwrap());
+/// 
+/// // bounce off of zero
+/// if walk.position() < 0.0 {
+///     walk.set_position(0.0);
+/// }
+/// ```
+pub struct Walk<'a, R: crate::Random> {
+	inner: &'a mut R,
+	position: f64,
+	step: f64,
+}
+
+impl<'a, R: crate::Random> Walk<'a, R> {
+	/// construct a new `Walk`.
+	/// 
+	/// ## panics
+	/// 
+	/// panics if `step` is negative, or is not finite.
+	#[inline]
+	#[track_caller]
+	pub fn new(inner: &'a mut R, start: f64, step: f64) -> Self {
+		assert!(step >= 0.0 && step.is_finite(), "`step` must be finite and not negative");
+		Self {
+			inner,
+			position: start,
+			step,
+		}
+	}
+
+	/// the current position, which is the last item returned, or `start`
+	/// if there hasn't been one yet.
+	#[inline]
+	pub fn position(&self) -> f64 {
+		self.position
+	}
+
+	/// move to `position`, so the next item is a step away from it.
+	#[inline]
+	pub fn set_position(&mut self, position: f64) {
+		self.position = position;
+	}
+
+	/// the largest distance a single step can move.
+	#[inline]
+	pub fn step(&self) -> f64 {
+		self.step
+	}
+}
+
+impl<R: crate::Random> Iterator for Walk<'_, R> {
+	type Item = f64;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		self.position += self.step * (2.0 * self.inner.random_f64() - 1.0);
+		Some(self.position)
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		(usize::MAX, None)
+	}
+}
+
+impl<R: crate::Random> core::iter::FusedIterator for Walk<'_, R> {}
+
+impl<R: crate::Random + core::fmt::Debug> core::fmt::Debug for Walk<'_, R> {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		write!(f, "Walk<{}>({:?})", self.step, self.inner)
+	}
+}
+
+/// infinite iterator of a random walk over `i64`s.
+/// 
+/// every item is the previous position, plus or minus `1`, with equal
+/// probability. the first item is one step away from `start`, not `start`
+/// itself, so the parity of the position flips on every item. the position
+/// wraps around at the ends of `i64`. see
+/// [`crate::Random::random_walk_i64()`].
+/// 
+/// ```
+/// # use prrng::XorShift64;
+/// use prrng::Random;
+/// let mut rng = XorShift64::new(1);
+/// 
+/// // a drunkard's walk through a corridor
+/// let mut corridor = [false; 64];
+/// let mut walk = rng.random_walk_i64(32);
+/// for _ in 0..100 {
+///     if let Some(x) = walk.next().filter(|x| (0..64).contains(x)) {
+///         corridor[x as usize] = true;
+///     } else {
+///         walk.set_position(32);
+///     }
+/// }
+/// ```
+pub struct WalkI64<'a, R: crate::Random> {
+	inner: &'a mut R,
+	position: i64,
+	// unused bits of the last `u64`, and how many are left
+	bits: u64,
+	left: u32,
+}
+
+impl<'a, R: crate::Random> WalkI64<'a, R> {
+	/// construct a new `WalkI64`.
+	#[inline]
+	pub fn new(inner: &'a mut R, start: i64) -> Self {
+		Self {
+			inner,
+			position: start,
+			bits: 0,
+			left: 0,
+		}
+	}
+
+	/// the current position, which is the last item returned, or `start`
+	/// if there hasn't been one yet.
+	#[inline]
+	pub fn position(&self) -> i64 {
+		self.position
+	}
+
+	/// move to `position`, so the next item is a step away from it.
+	#[inline]
+	pub fn set_position(&mut self, position: i64) {
+		self.position = position;
+	}
+}
+
+impl<R: crate::Random> Iterator for WalkI64<'_, R> {
+	type Item = i64;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		// one bit per step, so draw a whole `u64` every 64 steps
+		if self.left == 0 {
+			self.bits = self.inner.random_u64();
+			self.left = 64;
+		}
+		let up = self.bits & 1 != 0;
+		self.bits >>= 1;
+		self.left -= 1;
+
+		self.position = if up { self.position.wrapping_add(1) } else { self.position.wrapping_sub(1) };
+		Some(self.position)
+	}
+
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		(usize::MAX, None)
+	}
+}
+
+impl<R: crate::Random> core::iter::FusedIterator for WalkI64<'_, R> {}
+
+impl<R: crate::Random + core::fmt::Debug> core::fmt::Debug for WalkI64<'_, R> {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		write!(f, "WalkI64({:?})", self.inner)
+	}
+}
+
+
+#[cfg(test)]
+mod test {
+	use crate::{Random, XorShift64};
+
+	#[test]
+	fn test_deterministic() {
+		let mut a = XorShift64::new(1);
+		let mut b = XorShift64::new(1);
+		assert!(a.random_walk(5.0, 1.5).take(256).eq(b.random_walk(5.0, 1.5).take(256)));
+		assert!(a.random_walk_i64(-3).take(256).eq(b.random_walk_i64(-3).take(256)));
+
+		let mut c = XorShift64::new(2);
+		assert!(!a.random_walk(5.0, 1.5).take(256).eq(c.random_walk(5.0, 1.5).take(256)));
+	}
+
+	#[test]
+	fn test_walk() {
+		let mut rng = XorShift64::new(1);
+
+		for step in [0.0, 0.001, 1.0, 1000.0] {
+			let mut walk = rng.random_walk(10.0, step);
+			assert_eq!(walk.position(), 10.0);
+
+			let mut prev = 10.0;
+			let mut max = 0.0f64;
+			for _ in 0..10000 {
+				let x = walk.next().unwrap();
+				assert_eq!(x, walk.position());
+				let d = (x - prev).abs();
+				assert!(d <= step * (1.0 + 1e-12), "{step}: {d}");
+				max = max.max(d);
+				prev = x;
+			}
+			// and the steps actually reach near `step`
+			assert!(max >= step * 0.99, "{step}: {max}");
+		}
+
+		let mut walk = rng.random_walk(0.0, 1.0);
+		walk.set_position(-50.0);
+		assert!((walk.next().unwrap() + 50.0).abs() <= 1.0);
+	}
+
+	#[test]
+	fn test_walk_i64() {
+		let mut rng = XorShift64::new(1);
+		let mut walk = rng.random_walk_i64(7);
+		assert_eq!(walk.position(), 7);
+
+		let mut prev = 7i64;
+		let mut ups = 0;
+		for i in 0..10000 {
+			let x = walk.next().unwrap();
+			assert_eq!(x, walk.position());
+			assert_eq!((x - prev).abs(), 1);
+			// odd start, so odd after an even number of steps
+			assert_eq!(x.rem_euclid(2), i % 2, "{i}");
+			ups += (x > prev) as u32;
+			prev = x;
+		}
+		assert!((4800..5200).contains(&ups), "{ups}");
+
+		walk.set_position(i64::MAX);
+		let x = walk.next().unwrap();
+		assert!(x == i64::MIN || x == i64::MAX - 1);
+	}
+
+	#[test]
+	#[should_panic = "`step` must be finite and not negative"]
+	fn test_step() {
+		XorShift64::new(1).random_walk(0.0, -1.0);
+	}
+}
@@ -0,0 +1,246 @@
+
+/// transformation applied by [`Whiten`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Whitening {
+	/// reverse the order of all bits in every value.
+	/// 
+	/// this helps generators with weak low bits, such as LCGs with a power of
+	/// two modulus (where bit `k` has a period of at most `2^(k+1)`), as the
+	/// strongest high bits become the low bits.
+	/// 
+	/// this assumes values use every bit. a generator with a modulus below
+	/// `2^32`, like [`crate::lcg::RANDU`] (`2^31`), always has `0` in its top
+	/// bits, which this moves into the lowest bits, leaving bit `0` (and so
+	/// `random_bool()`, and `% 2`) constant. use [`Self::BitReverseN`] for
+	/// those.
+	BitReverse,
+	/// reverse the order of the lowest `n` bits in every `u32`, for generators
+	/// whose values only span `n` bits, like [`crate::lcg::RANDU`] with
+	/// `BitReverseN(31)`.
+	/// 
+	/// the strongest bit `n - 1` becomes bit `0`, and the always `0` top bits
+	/// stay at the top. a `u64` is treated as two `u32` halves, each reversed
+	/// on its own, which matches how 32 bit generators compose their `u64`s.
+	/// 
+	/// `n` must be within `1 ..= 32`. `BitReverseN(32)` is the same as
+	/// [`Self::BitReverse`] for `u32`s.
+	BitReverseN(u32),
+	/// reverse the order of all bytes in every value.
+	/// 
+	/// this is a cheaper version of [`Self::BitReverse`], helping consumers
+	/// that only look at the lowest byte (say, `value as u8`).
+	ByteSwap,
+	/// xor the high half of every value into the low half.
+	/// 
+	/// this helps generators whose low bits are weak but not entirely
+	/// useless, such as the `+` scrambled xorshift variants, while keeping
+	/// the high half intact.
+	XorFold,
+}
+
+impl Whitening {
+	#[inline]
+	const fn apply_u64(self, x: u64) -> u64 {
+		match self {
+			Self::BitReverse => x.reverse_bits(),
+			Self::BitReverseN(_) => {
+				let hi = self.apply_u32((x >> 32) as u32) as u64;
+				let lo = self.apply_u32(x as u32) as u64;
+				hi << 32 | lo
+			}
+			Self::ByteSwap => x.swap_bytes(),
+			Self::XorFold => x ^ (x >> 32),
+		}
+	}
+
+	#[inline]
+	const fn apply_u32(self, x: u32) -> u32 {
+		match self {
+			Self::BitReverse => x.reverse_bits(),
+			Self::BitReverseN(n) => x.reverse_bits() >> (32 - n),
+			Self::ByteSwap => x.swap_bytes(),
+			Self::XorFold => x ^ (x >> 16),
+		}
+	}
+}
+
+/// utility type for reordering the bits of a [`crate::Random`].
+/// 
+/// some generators have weak low bits, which is a problem for consumers
+/// that only look at the low bits (say, `random_u32() % 8`, or
+/// [`crate::Random::random_bool()`]). `Whiten` applies a cheap
+/// [`Whitening`] transformation to every value, moving the stronger
+/// bits of the value into the low bits. see [`Whitening`] for which
+/// transformation helps which failure pattern.
+/// 
+/// the transformation is applied to every value of
+/// [`crate::RandomImpl::random_u64()`] and [`crate::RandomImpl::random_u32()`],
+/// and [`crate::RandomImpl::random_bytes()`] is the little-endian serialization
+/// of the transformed `u64` stream.
+/// 
+/// note that this does not remove any weakness, it only moves it somewhere else.
+/// 
+/// ```
+/// # use prrng::Whiten;
+/// # use prrng::Whitening;
+/// # use prrng::lcg::RANDU;
+/// use prrng::RandomImpl;
+/// 
+/// // RANDU only has 31 bits, so reverse those, not all 32. with
+/// // `Whitening::BitReverse`, the always `0` top bit would become bit `0`.
+/// let mut raw = RANDU::new(1);
+/// let mut rng = Whiten::new(RANDU::new(1), Whitening::BitReverseN(31));
+/// 
+/// assert_eq!(rng.random_u32(), raw.get().reverse_bits() >> 1);
+/// ```
+#[derive(Clone)]
+pub struct Whiten<R: crate::Random> {
+	inner: R,
+	mode: Whitening,
+}
+
+impl<R: crate::Random> Whiten<R> {
+	/// construct a new `Whiten`.
+	/// 
+	/// ## panics
+	/// 
+	/// panics if `mode` is [`Whitening::BitReverseN`] with an `n` that is not
+	/// within `1 ..= 32`.
+	#[inline]
+	#[track_caller]
+	pub const fn new(inner: R, mode: Whitening) -> Self {
+		if let Whitening::BitReverseN(n) = mode {
+			assert!(n >= 1 && n <= 32, "`n` must be within `1 ..= 32`");
+		}
+		Self {
+			inner,
+			mode,
+		}
+	}
+
+	/// consume `self`, returning the inner rng.
+	#[inline]
+	pub fn unwrap(self) -> R {
+		self.inner
+	}
+
+	/// the transformation being applied.
+	#[inline]
+	pub fn mode(&self) -> Whitening {
+		self.mode
+	}
+}
+
+impl<R: crate::Random> crate::RandomImpl for Whiten<R> {
+	#[inline]
+	fn random_u64(&mut self) -> u64 {
+		self.mode.apply_u64(self.inner.random_u64())
+	}
+
+	#[inline]
+	fn random_u32(&mut self) -> u32 {
+		self.mode.apply_u32(self.inner.random_u32())
+	}
+
+	fn random_bytes(&mut self, dst: &mut [u8]) {
+		crate::common::bytes_from_u64(self, dst);
+	}
+}
+
+impl<R: crate::Random + core::fmt::Debug> core::fmt::Debug for Whiten<R> {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		write!(f, "Whiten<{:?}>({:?})", self.mode, self.inner)
+	}
+}
+
+
+#[cfg(test)]
+mod test {
+	use crate::{Random, RandomImpl, Whiten, Whitening, XorShift128p};
+	use crate::lcg::RANDU;
+
+	#[test]
+	fn test_transform() {
+		for mode in [Whitening::BitReverse, Whitening::BitReverseN(31), Whitening::ByteSwap, Whitening::XorFold] {
+			let mut raw = XorShift128p::new([1, 2]);
+			let mut rng = Whiten::new(raw, mode);
+
+			for _ in 0..16 {
+				assert_eq!(rng.random_u64(), mode.apply_u64(raw.get()));
+				assert_eq!(rng.random_u32(), mode.apply_u32(raw.get() as u32));
+			}
+
+			let mut buf = [0u8; 11];
+			rng.random_bytes(&mut buf);
+			assert_eq!(buf[..8], mode.apply_u64(raw.get()).to_le_bytes());
+			assert_eq!(buf[8..], mode.apply_u64(raw.get()).to_le_bytes()[..3]);
+		}
+	}
+
+	#[test]
+	fn test_randu() {
+		const COUNT: usize = 4096;
+
+		let mut raw = RANDU::new(1);
+		let mut rng = Whiten::new(RANDU::new(1), Whitening::BitReverseN(31));
+		let mut all = Whiten::new(RANDU::new(1), Whitening::BitReverse);
+
+		let mut raw_bits = [[false; COUNT]; 3];
+		let mut rng_bits = [[false; COUNT]; 8];
+		let mut bools = [false; COUNT];
+
+		for i in 0..COUNT {
+			let x = raw.random_u32();
+			for (b, bits) in raw_bits.iter_mut().enumerate() {
+				bits[i] = x >> b & 1 == 1;
+			}
+			let x = rng.random_u32();
+			for (b, bits) in rng_bits.iter_mut().enumerate() {
+				bits[i] = x >> b & 1 == 1;
+			}
+			// RANDU's modulus is `2^31`, so the top bit is always `0`
+			assert_eq!(x >> 31, 0);
+			bools[i] = rng.random_bool();
+
+			// reversing all 32 bits moves that `0` to the bottom
+			assert_eq!(all.random_u32() & 1, 0);
+		}
+
+		fn period(bits: &[bool]) -> Option<usize> {
+			(1..=64).find(|p| (0..bits.len() - p).all(|i| bits[i] == bits[i + p]))
+		}
+
+		// the raw low bits are either constant or have a tiny period
+		assert_eq!(raw_bits[0].iter().filter(|x| **x).count(), COUNT);
+		for bits in &raw_bits {
+			assert!(period(bits).is_some());
+		}
+
+		for bits in rng_bits.iter().chain([&bools]) {
+			let ones = bits.iter().filter(|x| **x).count();
+			assert!(ones > COUNT * 45 / 100 && ones < COUNT * 55 / 100);
+			assert_eq!(period(bits), None);
+		}
+	}
+
+	#[test]
+	fn test_reverse_n() {
+		let mode = Whitening::BitReverseN(31);
+		assert_eq!(mode.apply_u32(1 << 30), 1);
+		assert_eq!(mode.apply_u32(1), 1 << 30);
+		assert_eq!(mode.apply_u64(1 << 62 | 1 << 30), 1 << 32 | 1);
+		assert_eq!(Whitening::BitReverseN(32).apply_u32(0x1234), 0x1234u32.reverse_bits());
+
+		let mut rng = Whiten::new(XorShift128p::new([1, 2]), Whitening::BitReverseN(8));
+		for _ in 0..64 {
+			assert!(rng.random_u32() < 256);
+			assert_eq!(rng.random_u64() & !0xff_0000_00ff, 0);
+		}
+	}
+
+	#[test]
+	#[should_panic = "`n` must be within `1 ..= 32`"]
+	fn test_reverse_zero() {
+		Whiten::new(XorShift128p::new([1, 2]), Whitening::BitReverseN(0));
+	}
+}
@@ -0,0 +1,95 @@
+use prrng::{FromRandom, Random, StaticSeqU64, XorShift64};
+
+#[derive(FromRandom, Debug, PartialEq)]
+struct Named {
+	a: u64,
+	b: u32,
+}
+
+#[derive(FromRandom, Debug, PartialEq)]
+struct Tuple(u64, (u8, bool));
+
+#[derive(FromRandom, Debug, PartialEq)]
+struct Unit;
+
+#[derive(FromRandom, Debug, PartialEq)]
+struct Generic<T>([T; 2]);
+
+#[derive(FromRandom, Debug, PartialEq)]
+enum Shape {
+	Empty,
+	Circle(u64),
+	Rect { w: u64, h: u64 },
+}
+
+#[derive(FromRandom, Debug, PartialEq)]
+enum Coin {
+	#[from_random(weight = 3)]
+	Heads,
+	Tails,
+}
+
+#[derive(FromRandom, Debug, PartialEq)]
+enum Only {
+	One(u64),
+}
+
+#[test]
+fn test_struct() {
+	let mut a = XorShift64::new(1);
+	let mut b = XorShift64::new(1);
+
+	// fields are filled in order, as if by hand
+	assert_eq!(a.random::<Named>(), Named { a: b.random(), b: b.random() });
+	assert_eq!(a.random::<Tuple>(), Tuple(b.random(), b.random()));
+	assert_eq!(a.random::<Generic<u16>>(), Generic(b.random()));
+
+	// without drawing anything at all
+	assert_eq!(a.random::<Unit>(), Unit);
+	assert_eq!(a.random::<u64>(), b.random::<u64>());
+}
+
+#[test]
+fn test_enum() {
+	// the variant is `random_u64_bound(3)`, so `x % 3` of a single draw
+	// (which rejects `0`), followed by its fields
+	let mut rng = StaticSeqU64::new(&[3, 1, 7, 2, 8, 9]);
+	assert_eq!(rng.random::<Shape>(), Shape::Empty);
+	assert_eq!(rng.random::<Shape>(), Shape::Circle(7));
+	assert_eq!(rng.random::<Shape>(), Shape::Rect { w: 8, h: 9 });
+
+	// one variant needs no draw
+	let mut rng = StaticSeqU64::new(&[5, 6]);
+	assert_eq!(rng.random::<Only>(), Only::One(5));
+	assert_eq!(rng.random::<Only>(), Only::One(6));
+
+	// every variant shows up
+	let mut rng = XorShift64::new(1);
+	let mut seen = [false; 3];
+	for _ in 0..64 {
+		seen[match rng.random() {
+			Shape::Empty => 0,
+			Shape::Circle(_) => 1,
+			Shape::Rect { .. } => 2,
+		}] = true;
+	}
+	assert_eq!(seen, [true; 3]);
+}
+
+#[test]
+fn test_weight() {
+	// `Heads` covers draws `0 .. 3`, of `0 .. 4`
+	let mut rng = StaticSeqU64::new(&[0, 1, 2, 3]);
+	assert_eq!(rng.random::<[Coin; 4]>(), [Coin::Heads, Coin::Heads, Coin::Heads, Coin::Tails]);
+
+	let mut rng = XorShift64::new(1);
+	let n = 100000;
+	let heads = (0..n).filter(|_| rng.random::<Coin>() == Coin::Heads).count();
+	let ratio = heads as f64 / n as f64;
+	assert!((ratio - 0.75).abs() < 0.01, "{ratio}");
+}
+
+#[test]
+fn test_ui() {
+	trybuild::TestCases::new().compile_fail("tests/ui/*.rs");
+}
@@ -0,0 +1,4 @@
+#[derive(prrng::FromRandom)]
+enum Never {}
+
+fn main() {}
@@ -0,0 +1,9 @@
+struct NotRandom;
+
+#[derive(prrng::FromRandom)]
+enum Item {
+	Nothing,
+	Thing(NotRandom),
+}
+
+fn main() {}
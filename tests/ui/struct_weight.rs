@@ -0,0 +1,8 @@
+#[derive(prrng::FromRandom)]
+#[from_random(weight = 3)]
+struct Point {
+	x: u32,
+	y: u32,
+}
+
+fn main() {}
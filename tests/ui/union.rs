@@ -0,0 +1,7 @@
+#[derive(prrng::FromRandom)]
+union Bits {
+	a: u32,
+	b: f32,
+}
+
+fn main() {}
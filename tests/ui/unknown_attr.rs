@@ -0,0 +1,8 @@
+#[derive(prrng::FromRandom)]
+enum Coin {
+	#[from_random(chance = 3)]
+	Heads,
+	Tails,
+}
+
+fn main() {}
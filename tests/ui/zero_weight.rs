@@ -0,0 +1,8 @@
+#[derive(prrng::FromRandom)]
+enum Coin {
+	#[from_random(weight = 0)]
+	Heads,
+	Tails,
+}
+
+fn main() {}